@@ -1,13 +1,13 @@
 /*
  * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
  */
-//use xiapi_sys::XI_IMG_FORMAT;
+//use xiapi::ImageFormat;
 
-fn main() -> Result<(), i32> {
+fn main() -> Result<(), xiapi::XiError> {
     let mut cam = xiapi::open_device(None)?;
 
     cam.set_exposure(10000.0)?;
-//    cam.set_image_data_format(XI_IMG_FORMAT::XI_RGB24)?;
+    //    cam.set_image_data_format(ImageFormat::Rgb24)?;
 
     let buffer = cam.start_acquisition()?;
 
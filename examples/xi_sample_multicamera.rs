@@ -1,15 +1,15 @@
 use xiapi::number_devices;
 use xiapi::open_device;
-use xiapi::XI_RETURN;
-use xiapi::XI_TRG_SOURCE::XI_TRG_SOFTWARE;
+use xiapi::TriggerSource;
+use xiapi::XiError;
 
-fn main() -> Result<(), XI_RETURN> {
+fn main() -> Result<(), XiError> {
     let num_devs = number_devices()?;
     let mut acq_buffers = Vec::with_capacity(num_devs as usize);
     for i in 0..num_devs {
         let mut cam = open_device(Some(i))?;
-        cam.set_exposure(1000 as f32)?;
-        cam.set_trg_source(XI_TRG_SOFTWARE)?;
+        cam.set_exposure(1000.0)?;
+        cam.set_trg_source(TriggerSource::Software)?;
         acq_buffers.push(cam.start_acquisition()?);
     }
     for buf in &mut acq_buffers {
@@ -0,0 +1,301 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+use std::sync::{Arc, RwLock};
+
+use crate::sys::*;
+
+/// Low level interface to an implementation of xiAPI.
+///
+/// Every function mirrors the xiAPI function of the same name and returns the raw xiAPI return
+/// code. [Camera](crate::Camera) and [AcquisitionBuffer](crate::AcquisitionBuffer) perform all
+/// their calls through a Backend, which makes it possible to run code using this crate against
+/// something else than a physical camera (e.g. the [SimBackend](crate::SimBackend)).
+///
+/// [XiApi] is the default implementation which forwards every call to the XIMEA library.
+///
+/// # Safety
+///
+/// Implementors must make sure that the image buffer pointed to by `XI_IMG::bp` after a
+/// successful call to [Backend::get_image] contains at least `bp_size` bytes (or
+/// `width * height` pixels if `bp_size` is 0) and stays valid until the acquisition is stopped or
+/// the device is closed.
+///
+/// All functions taking a `handle` are unsafe to call, because the handle must either be null
+/// (for global parameters) or a handle returned by [Backend::open_device] of the same backend
+/// which has not been closed yet.
+#[allow(clippy::missing_safety_doc)]
+pub unsafe trait Backend: Send + Sync {
+    /// Get the number of discovered devices. See `xiGetNumberDevices`.
+    fn get_number_devices(&self, count: &mut u32) -> XI_RETURN;
+
+    /// Read a string parameter of a device without opening it. See `xiGetDeviceInfoString`.
+    ///
+    /// The string is written to `value` including the terminating null character.
+    fn get_device_info_string(&self, dev_id: u32, prm: &CStr, value: &mut [u8]) -> XI_RETURN;
+
+    /// Initialize a device and return its handle. See `xiOpenDevice`.
+    fn open_device(&self, dev_id: u32, handle: &mut HANDLE) -> XI_RETURN;
+
+    /// Initialize the device identified by `prm` and return its handle. See `xiOpenDeviceBy`.
+    fn open_device_by(&self, sel: XI_OPEN_BY::Type, prm: &CStr, handle: &mut HANDLE) -> XI_RETURN;
+
+    /// Close a device. See `xiCloseDevice`.
+    unsafe fn close_device(&self, handle: HANDLE) -> XI_RETURN;
+
+    /// Start the image acquisition. See `xiStartAcquisition`.
+    unsafe fn start_acquisition(&self, handle: HANDLE) -> XI_RETURN;
+
+    /// Stop the image acquisition. See `xiStopAcquisition`.
+    unsafe fn stop_acquisition(&self, handle: HANDLE) -> XI_RETURN;
+
+    /// Retrieve the next image from the device. See `xiGetImage`.
+    unsafe fn get_image(&self, handle: HANDLE, timeout: u32, img: &mut XI_IMG) -> XI_RETURN;
+
+    /// Read an integer parameter. See `xiGetParamInt`.
+    unsafe fn get_param_int(&self, handle: HANDLE, prm: &CStr, value: &mut i32) -> XI_RETURN;
+
+    /// Write an integer parameter. See `xiSetParamInt`.
+    unsafe fn set_param_int(&self, handle: HANDLE, prm: &CStr, value: i32) -> XI_RETURN;
+
+    /// Read a float parameter. See `xiGetParamFloat`.
+    unsafe fn get_param_float(&self, handle: HANDLE, prm: &CStr, value: &mut f32) -> XI_RETURN;
+
+    /// Write a float parameter. See `xiSetParamFloat`.
+    unsafe fn set_param_float(&self, handle: HANDLE, prm: &CStr, value: f32) -> XI_RETURN;
+
+    /// Read a string or binary parameter into `value`. See `xiGetParamString`.
+    ///
+    /// Strings are written including the terminating null character.
+    unsafe fn get_param_string(&self, handle: HANDLE, prm: &CStr, value: &mut [u8]) -> XI_RETURN;
+
+    /// Write a string or binary parameter. See `xiSetParamString`.
+    ///
+    /// Strings have to include the terminating null character.
+    unsafe fn set_param_string(&self, handle: HANDLE, prm: &CStr, value: &[u8]) -> XI_RETURN;
+
+    /// Read a parameter of any type into a byte buffer. See `xiGetParam`.
+    ///
+    /// `size` is set to the number of bytes written to `value`. `prm_type` contains the requested
+    /// type when called and the actual type of the parameter after the call.
+    unsafe fn get_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &mut [u8],
+        size: &mut u32,
+        prm_type: &mut XI_PRM_TYPE::Type,
+    ) -> XI_RETURN;
+
+    /// Write a parameter of any type from a byte buffer. See `xiSetParam`.
+    unsafe fn set_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &[u8],
+        prm_type: XI_PRM_TYPE::Type,
+    ) -> XI_RETURN;
+}
+
+/// Call a function of the xiAPI library that was linked at build time
+#[cfg(feature = "link")]
+macro_rules! xi_call {
+    ($function:ident($($arg:expr),* $(,)?)) => {
+        $function($($arg),*)
+    };
+}
+
+/// Call a function of the xiAPI library that is loaded at runtime
+#[cfg(feature = "runtime-loading")]
+macro_rules! xi_call {
+    ($function:ident($($arg:expr),* $(,)?)) => {
+        match crate::dynamic::library() {
+            Some(library) => (library.$function)($($arg),*),
+            None => crate::error::XI_LIBRARY_NOT_FOUND,
+        }
+    };
+}
+
+/// Fail every call, because the xiAPI library is neither linked nor loaded at runtime
+#[cfg(not(any(feature = "link", feature = "runtime-loading")))]
+macro_rules! xi_call {
+    ($function:ident($($arg:expr),* $(,)?)) => {{
+        $(let _ = $arg;)*
+        crate::error::XI_LIBRARY_NOT_FOUND
+    }};
+}
+
+/// The xiAPI library installed on this system.
+///
+/// This backend is used by default.
+///
+/// With the `runtime-loading` feature, the library is loaded when it is used for the first time
+/// instead of being linked when the application starts. If the library is not installed, all
+/// calls fail with [XiError::LibraryNotFound](crate::XiError::LibraryNotFound).
+/// If neither the `link` nor the `runtime-loading` feature is enabled, all calls fail with
+/// [XiError::LibraryNotFound](crate::XiError::LibraryNotFound), only the other backends (e.g.
+/// the [SimBackend](crate::SimBackend)) can be used.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct XiApi;
+
+#[cfg_attr(
+    not(any(feature = "link", feature = "runtime-loading")),
+    allow(unused_unsafe)
+)]
+unsafe impl Backend for XiApi {
+    fn get_number_devices(&self, count: &mut u32) -> XI_RETURN {
+        unsafe { xi_call!(xiGetNumberDevices(count)) }
+    }
+
+    fn get_device_info_string(&self, dev_id: u32, prm: &CStr, value: &mut [u8]) -> XI_RETURN {
+        unsafe {
+            xi_call!(xiGetDeviceInfoString(
+                dev_id,
+                prm.as_ptr(),
+                value.as_mut_ptr() as *mut c_char,
+                value.len() as DWORD,
+            ))
+        }
+    }
+
+    fn open_device(&self, dev_id: u32, handle: &mut HANDLE) -> XI_RETURN {
+        unsafe { xi_call!(xiOpenDevice(dev_id, handle)) }
+    }
+
+    fn open_device_by(&self, sel: XI_OPEN_BY::Type, prm: &CStr, handle: &mut HANDLE) -> XI_RETURN {
+        unsafe { xi_call!(xiOpenDeviceBy(sel, prm.as_ptr(), handle)) }
+    }
+
+    unsafe fn close_device(&self, handle: HANDLE) -> XI_RETURN {
+        unsafe { xi_call!(xiCloseDevice(handle)) }
+    }
+
+    unsafe fn start_acquisition(&self, handle: HANDLE) -> XI_RETURN {
+        unsafe { xi_call!(xiStartAcquisition(handle)) }
+    }
+
+    unsafe fn stop_acquisition(&self, handle: HANDLE) -> XI_RETURN {
+        unsafe { xi_call!(xiStopAcquisition(handle)) }
+    }
+
+    unsafe fn get_image(&self, handle: HANDLE, timeout: u32, img: &mut XI_IMG) -> XI_RETURN {
+        unsafe { xi_call!(xiGetImage(handle, timeout, img)) }
+    }
+
+    unsafe fn get_param_int(&self, handle: HANDLE, prm: &CStr, value: &mut i32) -> XI_RETURN {
+        unsafe { xi_call!(xiGetParamInt(handle, prm.as_ptr(), value)) }
+    }
+
+    unsafe fn set_param_int(&self, handle: HANDLE, prm: &CStr, value: i32) -> XI_RETURN {
+        unsafe { xi_call!(xiSetParamInt(handle, prm.as_ptr(), value)) }
+    }
+
+    unsafe fn get_param_float(&self, handle: HANDLE, prm: &CStr, value: &mut f32) -> XI_RETURN {
+        unsafe { xi_call!(xiGetParamFloat(handle, prm.as_ptr(), value)) }
+    }
+
+    unsafe fn set_param_float(&self, handle: HANDLE, prm: &CStr, value: f32) -> XI_RETURN {
+        unsafe { xi_call!(xiSetParamFloat(handle, prm.as_ptr(), value)) }
+    }
+
+    unsafe fn get_param_string(&self, handle: HANDLE, prm: &CStr, value: &mut [u8]) -> XI_RETURN {
+        unsafe {
+            xi_call!(xiGetParamString(
+                handle,
+                prm.as_ptr(),
+                value.as_mut_ptr() as *mut c_void,
+                value.len() as DWORD,
+            ))
+        }
+    }
+
+    unsafe fn set_param_string(&self, handle: HANDLE, prm: &CStr, value: &[u8]) -> XI_RETURN {
+        // xiSetParamString does not modify the value, even though it takes a mutable pointer
+        unsafe {
+            xi_call!(xiSetParamString(
+                handle,
+                prm.as_ptr(),
+                value.as_ptr() as *mut c_void,
+                value.len() as DWORD,
+            ))
+        }
+    }
+
+    unsafe fn get_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &mut [u8],
+        size: &mut u32,
+        prm_type: &mut XI_PRM_TYPE::Type,
+    ) -> XI_RETURN {
+        *size = value.len() as DWORD;
+        unsafe {
+            xi_call!(xiGetParam(
+                handle,
+                prm.as_ptr(),
+                value.as_mut_ptr() as *mut c_void,
+                size,
+                prm_type,
+            ))
+        }
+    }
+
+    unsafe fn set_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &[u8],
+        prm_type: XI_PRM_TYPE::Type,
+    ) -> XI_RETURN {
+        unsafe {
+            xi_call!(xiSetParam(
+                handle,
+                prm.as_ptr(),
+                value.as_ptr() as *mut c_void,
+                value.len() as DWORD,
+                prm_type,
+            ))
+        }
+    }
+}
+
+static DEFAULT_BACKEND: RwLock<Option<Arc<dyn Backend>>> = RwLock::new(None);
+
+/// Replace the backend used by [open_device](crate::open_device) and all other free functions of
+/// this crate.
+///
+/// Cameras that are already open keep using the backend they were opened with.
+/// Returns the previous default backend.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), xiapi::XiError>{
+/// let previous = xiapi::set_default_backend(Arc::new(xiapi::SimBackend::new(1)));
+/// let cam = xiapi::open_device(None)?;
+/// # xiapi::set_default_backend(previous);
+/// # Ok(())
+/// # }
+/// ```
+pub fn set_default_backend(backend: Arc<dyn Backend>) -> Arc<dyn Backend> {
+    let mut default = DEFAULT_BACKEND
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    default.replace(backend).unwrap_or_else(|| Arc::new(XiApi))
+}
+
+/// Get the backend that is currently used by default.
+pub fn default_backend() -> Arc<dyn Backend> {
+    let default = DEFAULT_BACKEND
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match default.as_ref() {
+        Some(backend) => backend.clone(),
+        None => Arc::new(XiApi),
+    }
+}
@@ -2,21 +2,36 @@
  * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
  */
 use std::ffi::CStr;
-use std::marker::PhantomData;
+use std::ffi::CString;
+use std::fmt::Debug;
 use std::mem::size_of;
+use std::mem::ManuallyDrop;
 use std::mem::MaybeUninit;
 use std::ops::Deref;
-use std::os::raw::c_char;
 use std::str::from_utf8;
+use std::sync::Arc;
+use std::sync::OnceLock;
 
+use crate::sys::*;
 use paste::paste;
-use xiapi_sys::*;
 
+use crate::backend::default_backend;
+use crate::enums::*;
+use crate::AcquisitionError;
+use crate::Backend;
+use crate::DynamicFrame;
 use crate::Image;
+use crate::Manifest;
+use crate::ParamOperation;
+use crate::ParamRange;
+use crate::ParamValue;
+use crate::PixelFormat;
 use crate::Roi;
+use crate::XiError;
 
 /// This macro is used to generate getters and setters for xiAPI parameters.
-/// The parameters are specified using the following syntax: \[mut\] <ParamName>: <Type>
+/// The parameters are specified using the following syntax: \[mut \[direct\]\] <ParamName>: <Type>
+/// Parameters marked as `direct` additionally get a setter using the `:direct_update` modifier.
 /// Documentation on the parameter will be added to the getter.
 /// Generic documentation is always added to the setter.
 ///
@@ -30,68 +45,204 @@ use crate::Roi;
 macro_rules! param {
     // This rule follows the Incremental TT muncher pattern.
     () => {};
-    // For mutable parameters:
+    // For mutable parameters that support the `:direct_update` modifier:
+    (
+        $(#[doc = $doc:expr])*
+        mut direct $prm:ident : $type:tt;
+        $($tail:tt)*
+    ) => {
+        param!($(#[doc = $doc])* mut $prm: $type;);
+        param_direct!($prm: $type);
+        param!($($tail)*);
+    };
+    // For mutable string parameters, which have no minimum, maximum or increment:
+    (
+        $(#[doc = $doc:expr])*
+        mut $prm:ident : String;
+        $($tail:tt)*
+    ) => {
+        paste! {
+            // Generate a getter with custom documentation
+            $(#[doc = $doc])*
+            pub fn $prm(&self) -> Result<String, XiError>{
+                self.param([<XI_PRM_ $prm:upper>])
+            }
+
+            // Generate a setter
+            #[doc = "Set the `" $prm "` parameter. See also [Self::" $prm "()]"]
+            pub fn [<set_ $prm>](&mut self, value: &str) -> Result<(), XiError>{
+                self.set_param([<XI_PRM_ $prm:upper>], value.to_string())
+            }
+            param!($($tail)*);
+        }
+    };
+    // For mutable numeric parameters, which additionally get a range and a clamped setter:
+    (
+        $(#[doc = $doc:expr])*
+        mut $prm:ident : f32;
+        $($tail:tt)*
+    ) => {
+        param_mut!($(#[doc = $doc])* $prm: f32);
+        param_range!($prm: f32);
+        param!($($tail)*);
+    };
+    (
+        $(#[doc = $doc:expr])*
+        mut $prm:ident : i32;
+        $($tail:tt)*
+    ) => {
+        param_mut!($(#[doc = $doc])* $prm: i32);
+        param_range!($prm: i32);
+        param!($($tail)*);
+    };
+    (
+        $(#[doc = $doc:expr])*
+        mut $prm:ident : u32;
+        $($tail:tt)*
+    ) => {
+        param_mut!($(#[doc = $doc])* $prm: u32);
+        param_range!($prm: u32);
+        param!($($tail)*);
+    };
+    (
+        $(#[doc = $doc:expr])*
+        mut $prm:ident : u64;
+        $($tail:tt)*
+    ) => {
+        param_mut!($(#[doc = $doc])* $prm: u64);
+        param_range!($prm: u64);
+        param!($($tail)*);
+    };
+    // For other mutable parameters:
     (
         $(#[doc = $doc:expr])*
         mut $prm:ident : $type:ty;
         $($tail:tt)*
+    ) => {
+        param_mut!($(#[doc = $doc])* $prm: $type);
+        param!($($tail)*);
+    };
+    // For immutable parameters
+    (
+        $(#[doc = $doc:expr])*
+        $prm:ident : $type:ty;
+        $($tail:tt)*
+    ) => {
+        paste! {
+            // Generate a getter with custom documentation
+            $(#[doc = $doc])*
+            pub fn $prm( &self) -> Result < $type, XiError >{
+                self.param(paste ! ([ < XI_PRM_ $prm: upper > ]))
+            }
+            param!($($tail)*);
+        }
+    };
+}
+/// Generate the getters and the setter of a mutable parameter. Used by [param].
+macro_rules! param_mut {
+    (
+        $(#[doc = $doc:expr])*
+        $prm:ident : $type:ty
     ) => {
         paste! {
             // Generate a getter with custom documentation
             $(#[doc = $doc])*
-            pub fn $prm(&self) -> Result<$type, XI_RETURN>{
-                unsafe {self.param([<XI_PRM_ $prm:upper>]) }
+            pub fn $prm(&self) -> Result<$type, XiError>{
+                self.param([<XI_PRM_ $prm:upper>])
              }
 
             // Generate a getter for the increment
             #[doc = "Get the increment for the `" $prm "` parameter. See also [Self::" $prm "()]"]
-            pub fn [<$prm _increment>](& self) -> Result<$type, XI_RETURN>{
-                unsafe {self.param_increment([<XI_PRM_ $prm:upper>])}
+            pub fn [<$prm _increment>](& self) -> Result<$type, XiError>{
+                self.param_increment([<XI_PRM_ $prm:upper>])
             }
 
             // Generate getter for the minimum
             #[doc = "Get the minimum for the `" $prm "` parameter. See also [Self::" $prm "()]"]
-            pub fn [<$prm _minimum>](& self) -> Result<$type, XI_RETURN>{
-                unsafe {self.param_min([<XI_PRM_ $prm:upper>])}
+            pub fn [<$prm _minimum>](& self) -> Result<$type, XiError>{
+                self.param_min([<XI_PRM_ $prm:upper>])
             }
 
             // Generate getter for the maximum
             #[doc = "Get the maximum for the `" $prm "` parameter. See also [Self::" $prm "()]"]
-            pub fn [<$prm _maximum>](& self) -> Result<$type, XI_RETURN>{
-                unsafe {self.param_max([<XI_PRM_ $prm:upper>])}
+            pub fn [<$prm _maximum>](& self) -> Result<$type, XiError>{
+                self.param_max([<XI_PRM_ $prm:upper>])
             }
 
             // Generate a setter
             // TODO: Customizable documentation for setters
             #[doc = "Set the `" $prm "` parameter. See also [Self::" $prm "()]"]
-            pub fn [<set_ $prm>](& mut self, value: $type ) -> Result<(), XI_RETURN>{
-                unsafe {self.set_param([<XI_PRM_ $prm:upper>], value)}
+            pub fn [<set_ $prm>](& mut self, value: $type ) -> Result<(), XiError>{
+                self.set_param([<XI_PRM_ $prm:upper>], value)
             }
-            param!($($tail)*);
         }
     };
-    // For immutable parameters
-    (
-        $(#[doc = $doc:expr])*
-        $prm:ident : $type:ty;
-        $($tail:tt)*
-    ) => {
+}
+
+/// Generate the range getter and the clamped setter of a numeric parameter. Used by [param].
+macro_rules! param_range {
+    ($prm:ident : $type:ty) => {
         paste! {
-            // Generate a getter with custom documentation
-            $(#[doc = $doc])*
-            pub fn $prm( &self) -> Result < $type, XI_RETURN >{
-                unsafe {self.param(paste ! ([ < XI_PRM_ $prm: upper > ]))}
+            #[doc = "Get the range of legal values for the `" $prm "` parameter. See also [Self::" $prm "()]"]
+            pub fn [<$prm _range>](&self) -> Result<ParamRange<$type>, XiError>{
+                self.param_range([<XI_PRM_ $prm:upper>])
+            }
+
+            #[doc = "Set the `" $prm "` parameter to the legal value closest to `value`."]
+            ///
+            /// Returns the value that was actually set.
+            #[doc = "See also [Self::" $prm "_range()]"]
+            pub fn [<set_ $prm _clamped>](&mut self, value: $type) -> Result<$type, XiError>{
+                let value = self.[<$prm _range>]()?.snap_nearest(value);
+                self.[<set_ $prm>](value)?;
+                Ok(value)
             }
-            param!($($tail)*);
         }
     };
 }
+
+/// Generate the direct update setter of a mutable parameter. Used by [param].
+macro_rules! param_direct {
+    ($prm:ident : $type:ty) => {
+        paste! {
+            #[doc = "Set the `" $prm "` parameter using the `:direct_update` modifier."]
+            ///
+            /// The value is applied by the camera as soon as possible, usually to the next frame,
+            /// instead of passing through the regular parameter pipeline of xiAPI. This is useful
+            /// for closed-loop control of the parameter during acquisition.
+            #[doc = "See also [Self::set_" $prm "()]"]
+            pub fn [<set_ $prm _direct>](&mut self, value: $type) -> Result<(), XiError>{
+                self.set_param_direct([<XI_PRM_ $prm:upper>], value)
+            }
+        }
+    };
+}
+
+/// Generate the accessors of parameters that can be changed while the acquisition is running.
+///
+/// The accessors are generated for both [Camera] and [AcquisitionBuffer], using the same syntax as
+/// [param].
+macro_rules! acquisition_param {
+    ($($body:tt)*) => {
+        impl Camera {
+            param! { $($body)* }
+        }
+
+        impl AcquisitionBuffer {
+            param! { $($body)* }
+        }
+    };
+}
+
 /// Connected and initialized XIMEA camera.
 ///
 /// Must be mutable to allow changing any parameters. A non-mutable Camera can be used from
 /// multiple threads or processes safely.
 pub struct Camera {
     device_handle: HANDLE,
+    backend: Arc<dyn Backend>,
+    /// Device manifest, read on first use
+    manifest: OnceLock<Manifest>,
 }
 
 /// Buffer that is used by the camera to transfer images to the host system.
@@ -106,8 +257,46 @@ pub struct Camera {
 /// interactions that may change parameters that are fixed while the image acquisition is running.
 /// Trying to change an parameter that is not changeable during acquisition is therefore an error at
 /// compile time (as opposed to runtime in C/C++).
+///
+/// If the AcquisitionBuffer is dropped without calling
+/// [stop_acquisition](AcquisitionBuffer::stop_acquisition), e.g. because the thread that owns it
+/// panics, the acquisition is stopped before the camera is closed. Errors while stopping the
+/// acquisition are logged using the [log] crate.
+///
+/// Parameters that can be changed during acquisition, e.g. the exposure time, gain, white balance
+/// or the GPO and LED modes, are available on the AcquisitionBuffer as well.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), xiapi::XiError> {
+///     let cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+///     let mut buffer = cam.start_acquisition()?;
+///     buffer.set_exposure(2000.0)?;
+///     buffer.set_gain(6.0)?;
+///     let image = buffer.next_image::<u8>(None)?;
+/// #   drop(image);
+///     let cam = buffer.stop_acquisition()?;
+///     assert_eq!(cam.exposure()?, 2000.0);
+/// #   Ok(())
+/// # }
+/// ```
+///
+/// Parameters like the region of interest or the image format are not available:
+///
+/// ```compile_fail
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), xiapi::XiError> {
+///     let cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+///     let mut buffer = cam.start_acquisition()?;
+///     buffer.set_width(640)?;
+/// #   Ok(())
+/// # }
+/// ```
 pub struct AcquisitionBuffer {
-    camera: Camera,
+    /// Only dropped manually, so that it can be moved out in [AcquisitionBuffer::stop_acquisition]
+    camera: ManuallyDrop<Camera>,
 }
 
 /// Initializes a camera and returns it.
@@ -125,26 +314,49 @@ pub struct AcquisitionBuffer {
 /// # Arguments
 ///
 /// * `dev_id`: The device ID for the device to be initialized. Usually device IDs are sequential
-/// and start at 0 for the first device in the system. Default value: 0
+///   and start at 0 for the first device in the system. Default value: 0
 ///
 /// # Examples
 ///
 /// ```
 /// # #[serial_test::file_serial]
-/// # fn main() -> Result<(), xiapi_sys::XI_RETURN>{
+/// # fn main() -> Result<(), xiapi::XiError>{
 ///     let mut cam = xiapi::open_device(None)?;
 ///     cam.set_exposure(10000 as f32);
 ///     // Do more stuff with the camera ...
 /// #   Ok(())
 /// # }
 /// ```
-pub fn open_device(dev_id: Option<u32>) -> Result<Camera, XI_RETURN> {
+pub fn open_device(dev_id: Option<u32>) -> Result<Camera, XiError> {
+    open_device_with_backend(default_backend(), dev_id)
+}
+
+/// Initializes a camera using the given backend and returns it.
+///
+/// This works exactly like [open_device], but the camera is opened through `backend` instead of
+/// the default backend (see [set_default_backend](crate::set_default_backend)).
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), xiapi::XiError>{
+///     let backend = Arc::new(xiapi::SimBackend::new(1));
+///     let mut cam = xiapi::open_device_with_backend(backend, None)?;
+///     cam.set_exposure(10000 as f32)?;
+/// #   Ok(())
+/// # }
+/// ```
+pub fn open_device_with_backend(
+    backend: Arc<dyn Backend>,
+    dev_id: Option<u32>,
+) -> Result<Camera, XiError> {
     let mut device_handle: HANDLE = std::ptr::null_mut();
     let dev_id = dev_id.unwrap_or(0);
-    let err = unsafe { xiapi_sys::xiOpenDevice(dev_id, &mut device_handle) };
+    let err = backend.open_device(dev_id, &mut device_handle);
     match err as XI_RET::Type {
-        XI_RET::XI_OK => Ok(Camera { device_handle }),
-        _ => Err(err),
+        XI_RET::XI_OK => Ok(Camera::new(device_handle, backend)),
+        _ => Err(XiError::from(err)),
     }
 }
 
@@ -159,14 +371,14 @@ pub fn open_device(dev_id: Option<u32>) -> Result<Camera, XI_RETURN> {
 /// # Arguments
 ///
 /// *`dev_id`: The device ID for the device to be initialized. Usually device IDs are sequential
-/// and start at 0 for the first device in the system. Default value: 0
+///   and start at 0 for the first device in the system. Default value: 0
 /// *`bandwidth`: Transport layer bandwidth for this camera in MBit/s
 ///
 /// # Examples
 ///
 /// ```
 /// # #[serial_test::file_serial]
-/// # fn main() -> Result<(), xiapi_sys::XI_RETURN>{
+/// # fn main() -> Result<(), xiapi::XiError>{
 ///     let mut cam = xiapi::open_device_manual_bandwidth(None, 1000)?;
 ///     cam.set_exposure(10000 as f32);
 ///     // Do more stuff with the camera ...
@@ -176,31 +388,152 @@ pub fn open_device(dev_id: Option<u32>) -> Result<Camera, XI_RETURN> {
 pub fn open_device_manual_bandwidth(
     dev_id: Option<u32>,
     bandwidth: i32,
-) -> Result<Camera, XI_RETURN> {
-    let cam = unsafe {
+) -> Result<Camera, XiError> {
+    open_manual_bandwidth(default_backend(), bandwidth, |backend| {
+        open_device_with_backend(backend, dev_id)
+    })
+}
+
+/// Selects the camera to be opened by [open_device_by].
+///
+/// Unlike the device index, these identifiers do not change when cameras are reconnected or the
+/// system is rebooted. The values can be obtained from [enumerate_devices](crate::enumerate_devices).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenBy<'a> {
+    /// Serial number of the camera
+    Serial(&'a str),
+    /// User defined ID stored in the camera
+    UserId(&'a str),
+    /// Instance path of the camera in the operating system
+    InstancePath(&'a str),
+    /// Location path of the port the camera is connected to
+    LocationPath(&'a str),
+}
+
+impl OpenBy<'_> {
+    /// Returns the `XI_OPEN_BY` selector and the identifier value
+    fn selector(&self) -> (XI_OPEN_BY::Type, &str) {
+        match *self {
+            OpenBy::Serial(value) => (XI_OPEN_BY::XI_OPEN_BY_SN, value),
+            OpenBy::UserId(value) => (XI_OPEN_BY::XI_OPEN_BY_USER_ID, value),
+            OpenBy::InstancePath(value) => (XI_OPEN_BY::XI_OPEN_BY_INST_PATH, value),
+            OpenBy::LocationPath(value) => (XI_OPEN_BY::XI_OPEN_BY_LOC_PATH, value),
+        }
+    }
+}
+
+/// Initializes the camera identified by `by` and returns it.
+///
+/// This works like [open_device], but selects the camera by a stable identifier instead of its
+/// index.
+/// The automatic bandwidth calculation is enabled by default when using this method.
+///
+/// # Examples
+///
+/// ```
+/// # #[serial_test::file_serial]
+/// # fn main() -> Result<(), xiapi::XiError>{
+///     let mut cam = xiapi::open_device_by(xiapi::OpenBy::Serial("12345678"))?;
+///     cam.set_exposure(10000 as f32);
+///     // Do more stuff with the camera ...
+/// #   Ok(())
+/// # }
+/// ```
+pub fn open_device_by(by: OpenBy) -> Result<Camera, XiError> {
+    open_device_by_with_backend(default_backend(), by)
+}
+
+/// Initializes the camera identified by `by` using the given backend and returns it.
+///
+/// This works exactly like [open_device_by], but the camera is opened through `backend` instead of
+/// the default backend (see [set_default_backend](crate::set_default_backend)).
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), xiapi::XiError>{
+///     let backend = Arc::new(xiapi::SimBackend::new(2));
+///     let cam = xiapi::open_device_by_with_backend(backend, xiapi::OpenBy::Serial("SIM00002"))?;
+/// #   Ok(())
+/// # }
+/// ```
+pub fn open_device_by_with_backend(
+    backend: Arc<dyn Backend>,
+    by: OpenBy,
+) -> Result<Camera, XiError> {
+    let (sel, value) = by.selector();
+    let value = CString::new(value).map_err(|_| XiError::InvalidArg)?;
+    let mut device_handle: HANDLE = std::ptr::null_mut();
+    let err = backend.open_device_by(sel, &value, &mut device_handle);
+    match err as XI_RET::Type {
+        XI_RET::XI_OK => Ok(Camera::new(device_handle, backend)),
+        _ => Err(XiError::from(err)),
+    }
+}
+
+/// Initialize the camera identified by `by` with the given bandwidth and return it.
+///
+/// This works like [open_device_manual_bandwidth], but selects the camera by a stable identifier
+/// instead of its index.
+/// The automatic bandwidth measurement is disabled when using this method.
+///
+/// # Arguments
+///
+/// *`by`: The identifier of the device to be initialized
+/// *`bandwidth`: Transport layer bandwidth for this camera in MBit/s
+///
+/// # Examples
+///
+/// ```
+/// # #[serial_test::file_serial]
+/// # fn main() -> Result<(), xiapi::XiError>{
+///     let by = xiapi::OpenBy::UserId("left");
+///     let mut cam = xiapi::open_device_by_manual_bandwidth(by, 1000)?;
+///     cam.set_exposure(10000 as f32);
+///     // Do more stuff with the camera ...
+/// #   Ok(())
+/// # }
+/// ```
+pub fn open_device_by_manual_bandwidth(by: OpenBy, bandwidth: i32) -> Result<Camera, XiError> {
+    open_manual_bandwidth(default_backend(), bandwidth, |backend| {
+        open_device_by_with_backend(backend, by)
+    })
+}
+
+/// Open a camera with `open` while the automatic bandwidth calculation is disabled and set its
+/// bandwidth limit afterwards.
+fn open_manual_bandwidth(
+    backend: Arc<dyn Backend>,
+    bandwidth: i32,
+    open: impl FnOnce(Arc<dyn Backend>) -> Result<Camera, XiError>,
+) -> Result<Camera, XiError> {
+    let cam = {
         let bandwidth_param_c = match CStr::from_bytes_with_nul(XI_PRM_AUTO_BANDWIDTH_CALCULATION) {
             Ok(c) => c,
-            Err(_) => return Err(XI_RET::XI_INVALID_ARG as XI_RETURN),
+            Err(_) => return Err(XiError::InvalidArg),
         };
-        match i32::set_param(
-            std::ptr::null_mut(),
-            bandwidth_param_c.as_ptr(),
-            XI_SWITCH::XI_OFF as i32,
-        ) as XI_RET::Type
-        {
-            XI_RET::XI_OK => {}
-            err => return Err(err as i32),
+        unsafe {
+            bool::set_param(
+                backend.as_ref(),
+                std::ptr::null_mut(),
+                bandwidth_param_c,
+                false,
+            )?
         };
 
-        let cam = open_device(dev_id);
-        match i32::set_param(
-            std::ptr::null_mut(),
-            bandwidth_param_c.as_ptr(),
-            XI_SWITCH::XI_ON as i32,
-        ) as XI_RET::Type
+        let cam = open(backend.clone());
+        if unsafe {
+            bool::set_param(
+                backend.as_ref(),
+                std::ptr::null_mut(),
+                bandwidth_param_c,
+                true,
+            )
+        }
+        .is_err()
         {
-            XI_RET::XI_OK => {}
-            _ => panic!("Could not enable auto bandwidth calculation!"),
+            panic!("Could not enable auto bandwidth calculation!");
         }
         cam
     };
@@ -219,7 +552,7 @@ pub fn open_device_manual_bandwidth(
 ///
 /// ```
 /// # #[serial_test::file_serial]
-/// # fn main() -> Result<(), xiapi::XI_RETURN>{
+/// # fn main() -> Result<(), xiapi::XiError>{
 ///     let number_devices = xiapi::number_devices()?;
 ///     let mut cameras = Vec::with_capacity(number_devices as usize);
 ///     for i in 0..number_devices {
@@ -227,94 +560,233 @@ pub fn open_device_manual_bandwidth(
 ///     }
 /// # Ok(())
 /// # }
-pub fn number_devices() -> Result<u32, XI_RETURN> {
-    unsafe {
-        let mut value = 0u32;
-        let res = xiapi_sys::xiGetNumberDevices(&mut value);
-        match res as XI_RET::Type {
-            XI_RET::XI_OK => Ok(value),
-            _ => Err(res),
-        }
+pub fn number_devices() -> Result<u32, XiError> {
+    let mut value = 0u32;
+    let res = default_backend().get_number_devices(&mut value);
+    match res as XI_RET::Type {
+        XI_RET::XI_OK => Ok(value),
+        _ => Err(XiError::from(res)),
     }
 }
 
 impl Drop for Camera {
     fn drop(&mut self) {
         unsafe {
-            xiapi_sys::xiCloseDevice(self.device_handle);
+            self.backend.close_device(self.device_handle);
         }
     }
 }
 
-trait ParamType: Default {
-    unsafe fn get_param(
-        handle: xiapi_sys::HANDLE,
-        prm: *const std::os::raw::c_char,
-        value: &mut Self,
-    ) -> XI_RETURN;
+/// Conversion between Rust types and the xiAPI parameter functions
+pub(crate) trait ParamType: Sized + Clone + Debug {
+    unsafe fn get_param(backend: &dyn Backend, handle: HANDLE, prm: &CStr)
+        -> Result<Self, XiError>;
     unsafe fn set_param(
-        handle: xiapi_sys::HANDLE,
-        prm: *const std::os::raw::c_char,
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
         value: Self,
-    ) -> XI_RETURN;
+    ) -> Result<(), XiError>;
+}
+
+/// Convert an xiAPI return code to a Result
+fn check(ret: XI_RETURN) -> Result<(), XiError> {
+    match ret as XI_RET::Type {
+        XI_RET::XI_OK => Ok(()),
+        _ => Err(XiError::from(ret)),
+    }
 }
 
 impl ParamType for f32 {
-    unsafe fn get_param(handle: HANDLE, prm: *const c_char, value: &mut Self) -> XI_RETURN {
-        xiapi_sys::xiGetParamFloat(handle, prm, value)
+    unsafe fn get_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+    ) -> Result<Self, XiError> {
+        let mut value = 0.0;
+        check(backend.get_param_float(handle, prm, &mut value))?;
+        Ok(value)
     }
 
-    unsafe fn set_param(handle: HANDLE, prm: *const c_char, value: Self) -> XI_RETURN {
-        xiapi_sys::xiSetParamFloat(handle, prm, value)
+    unsafe fn set_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+        value: Self,
+    ) -> Result<(), XiError> {
+        check(backend.set_param_float(handle, prm, value))
     }
 }
 
 impl ParamType for i32 {
-    unsafe fn get_param(handle: HANDLE, prm: *const c_char, value: &mut Self) -> XI_RETURN {
-        xiapi_sys::xiGetParamInt(handle, prm, value)
+    unsafe fn get_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+    ) -> Result<Self, XiError> {
+        let mut value = 0;
+        check(backend.get_param_int(handle, prm, &mut value))?;
+        Ok(value)
     }
 
-    unsafe fn set_param(handle: HANDLE, prm: *const c_char, value: Self) -> XI_RETURN {
-        xiapi_sys::xiSetParamInt(handle, prm, value)
+    unsafe fn set_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+        value: Self,
+    ) -> Result<(), XiError> {
+        check(backend.set_param_int(handle, prm, value))
     }
 }
 
 impl ParamType for u32 {
     // Selectors in xiAPI are defined as unsigned int, but treated as if they were signed
-    unsafe fn get_param(handle: HANDLE, prm: *const c_char, value: &mut Self) -> XI_RETURN {
-        xiapi_sys::xiGetParamInt(handle, prm, value as *mut u32 as *mut i32)
+    unsafe fn get_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+    ) -> Result<Self, XiError> {
+        i32::get_param(backend, handle, prm).map(|value| value as u32)
     }
 
-    unsafe fn set_param(handle: HANDLE, prm: *const c_char, value: Self) -> XI_RETURN {
-        xiapi_sys::xiSetParamInt(handle, prm, value as i32)
+    unsafe fn set_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+        value: Self,
+    ) -> Result<(), XiError> {
+        i32::set_param(backend, handle, prm, value as i32)
+    }
+}
+
+impl ParamType for bool {
+    // Switches in xiAPI are integers with the values XI_OFF and XI_ON
+    unsafe fn get_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+    ) -> Result<Self, XiError> {
+        i32::get_param(backend, handle, prm).map(|value| value != XI_SWITCH::XI_OFF as i32)
+    }
+
+    unsafe fn set_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+        value: Self,
+    ) -> Result<(), XiError> {
+        let value = if value {
+            XI_SWITCH::XI_ON
+        } else {
+            XI_SWITCH::XI_OFF
+        };
+        i32::set_param(backend, handle, prm, value as i32)
     }
 }
 
 impl ParamType for u64 {
-    // Selectors in xiAPI are defined as unsigned int, but treated as if they were signed
-    unsafe fn get_param(handle: HANDLE, prm: *const c_char, value: &mut Self) -> XI_RETURN {
-        let mut size: DWORD = std::mem::size_of::<Self>() as DWORD;
-        let mut xi_type_integer64: u32 = XI_PRM_TYPE::xiTypeInteger64 as u32;
-        xiapi_sys::xiGetParam(
-            handle,
-            prm,
-            value as *mut _ as *mut std::os::raw::c_void,
-            &mut size,
-            &mut xi_type_integer64
-        )
+    unsafe fn get_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+    ) -> Result<Self, XiError> {
+        let mut bytes = [0u8; size_of::<Self>()];
+        let mut size: DWORD = size_of::<Self>() as DWORD;
+        let mut xi_type_integer64 = XI_PRM_TYPE::xiTypeInteger64;
+        check(backend.get_param(handle, prm, &mut bytes, &mut size, &mut xi_type_integer64))?;
+        Ok(u64::from_ne_bytes(bytes))
     }
 
-    unsafe fn set_param(handle: HANDLE, prm: *const c_char, value: Self) -> XI_RETURN {
-        xiapi_sys::xiSetParam(
+    unsafe fn set_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+        value: Self,
+    ) -> Result<(), XiError> {
+        check(backend.set_param(
             handle,
             prm,
-            &value as *const _ as *mut std::os::raw::c_void,
-            std::mem::size_of::<Self>() as DWORD, XI_PRM_TYPE::xiTypeInteger64
-        )
+            &value.to_ne_bytes(),
+            XI_PRM_TYPE::xiTypeInteger64,
+        ))
+    }
+}
+
+/// Buffer size used for string parameters if the required size cannot be queried
+const STRING_BUFFER_SIZE: usize = 1024;
+
+/// Get the size of the buffer that is required to read the string parameter `prm`
+unsafe fn string_buffer_size(backend: &dyn Backend, handle: HANDLE, prm: &CStr) -> usize {
+    let mut name = prm.to_bytes().to_vec();
+    name.extend_from_slice(XI_PRMM_REQ_VAL_BUFFER_SIZE);
+    let name = match CString::from_vec_with_nul(name) {
+        Ok(name) => name,
+        Err(_) => return STRING_BUFFER_SIZE,
+    };
+    match i32::get_param(backend, handle, &name) {
+        Ok(size) if size > 0 => size as usize,
+        _ => STRING_BUFFER_SIZE,
+    }
+}
+
+impl ParamType for Vec<u8> {
+    // Binary parameters are transferred like strings, but are not null terminated.
+    // xiGetParam reports the size of the value, so the buffer can be truncated to it.
+    unsafe fn get_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+    ) -> Result<Self, XiError> {
+        let mut buffer = vec![0u8; string_buffer_size(backend, handle, prm)];
+        let mut size = buffer.len() as DWORD;
+        let mut xi_type_string = XI_PRM_TYPE::xiTypeString;
+        check(backend.get_param(handle, prm, &mut buffer, &mut size, &mut xi_type_string))?;
+        buffer.truncate(size as usize);
+        Ok(buffer)
+    }
+
+    unsafe fn set_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+        value: Self,
+    ) -> Result<(), XiError> {
+        check(backend.set_param_string(handle, prm, &value))
+    }
+}
+
+impl ParamType for String {
+    unsafe fn get_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+    ) -> Result<Self, XiError> {
+        let mut bytes = vec![0u8; string_buffer_size(backend, handle, prm)];
+        check(backend.get_param_string(handle, prm, &mut bytes))?;
+        let length = bytes.iter().position(|c| *c == 0).unwrap_or(bytes.len());
+        Ok(String::from_utf8_lossy(&bytes[..length]).into_owned())
+    }
+
+    unsafe fn set_param(
+        backend: &dyn Backend,
+        handle: HANDLE,
+        prm: &CStr,
+        value: Self,
+    ) -> Result<(), XiError> {
+        let value = CString::new(value).or(Err(XiError::InvalidArg))?;
+        check(backend.set_param_string(handle, prm, value.as_bytes_with_nul()))
     }
 }
 
 impl Camera {
+    fn new(device_handle: HANDLE, backend: Arc<dyn Backend>) -> Self {
+        Camera {
+            device_handle,
+            backend,
+            manifest: OnceLock::new(),
+        }
+    }
+
     /// Starts the image acquisition on this camera
     ///
     /// This function creates the AcquisitionBuffer and tells the camera to start streaming data
@@ -322,10 +794,12 @@ impl Camera {
     /// The camera is temporarily consumed by the AcquisitionBuffer, so you can only interact with
     /// it through the AcquisitionBuffer.
     ///
+    /// If the acquisition can not be started, the camera is returned in the [AcquisitionError].
+    ///
     /// # Examples
     /// ```
     /// # #[serial_test::file_serial]
-    /// # fn main() -> Result<(), xiapi_sys::XI_RETURN> {
+    /// # fn main() -> Result<(), xiapi::XiError> {
     ///     let cam = xiapi::open_device(None)?;
     ///     let buffer = cam.start_acquisition()?;
     ///     let image = buffer.next_image::<u8>(None)?;
@@ -333,58 +807,142 @@ impl Camera {
     ///     let cam = buffer.stop_acquisition()?;
     /// #   Ok(())
     /// # }
-    pub fn start_acquisition(self) -> Result<AcquisitionBuffer, XI_RETURN> {
-        let err = unsafe { xiapi_sys::xiStartAcquisition(self.device_handle) };
+    /// ```
+    ///
+    /// Retry with a larger acquisition buffer if the acquisition can not be started:
+    /// ```
+    /// # use std::sync::Arc;
+    /// # fn main() -> Result<(), xiapi::XiError> {
+    ///     let mut cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+    ///     cam.set_acq_buffer_size(1 << 20)?;
+    ///     let buffer = match cam.start_acquisition() {
+    ///         Ok(buffer) => buffer,
+    ///         Err(err) => {
+    ///             let mut cam = err.into_inner();
+    ///             cam.set_acq_buffer_size(64 << 20)?;
+    ///             cam.start_acquisition()?
+    ///         }
+    ///     };
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn start_acquisition(self) -> Result<AcquisitionBuffer, AcquisitionError<Camera>> {
+        let err = unsafe { self.backend.start_acquisition(self.device_handle) };
         match err as XI_RET::Type {
-            XI_RET::XI_OK => Ok(AcquisitionBuffer { camera: self }),
-            _ => Err(err),
+            XI_RET::XI_OK => Ok(AcquisitionBuffer {
+                camera: ManuallyDrop::new(self),
+            }),
+            _ => Err(AcquisitionError::new(XiError::from(err), self)),
         }
     }
 
-    unsafe fn set_param<T: ParamType>(&mut self, param: &[u8], value: T) -> Result<(), XI_RETURN> {
+    /// Start the image acquisition, call `f` with the AcquisitionBuffer and stop the acquisition
+    /// again.
+    ///
+    /// Returns the result of `f` and the camera, which is in the same state as before.
+    /// If `f` panics, the acquisition is stopped while unwinding, so the camera is never left in
+    /// an acquiring state (see [AcquisitionBuffer]).
+    ///
+    /// If starting or stopping the acquisition fails, the camera is returned as part of the
+    /// [AcquisitionError]. When stopping failed, the result of `f` is discarded and the camera
+    /// may still be acquiring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # fn main() -> Result<(), xiapi::XiError> {
+    ///     let cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+    ///     let (nframe, mut cam) = cam.with_acquisition(|buffer| {
+    ///         buffer.next_image::<u8>(None).map(|image| image.nframe())
+    ///     })?;
+    ///     let nframe = nframe?;
+    ///     cam.set_width(640)?;
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn with_acquisition<R>(
+        self,
+        f: impl FnOnce(&mut AcquisitionBuffer) -> R,
+    ) -> Result<(R, Camera), AcquisitionError<Camera>> {
+        let mut buffer = self.start_acquisition()?;
+        let result = f(&mut buffer);
+        match buffer.stop_acquisition() {
+            Ok(camera) => Ok((result, camera)),
+            Err(err) => {
+                let (error, buffer) = err.into_parts();
+                Err(AcquisitionError::new(error, buffer.into_camera()))
+            }
+        }
+    }
+
+    fn set_param<T: ParamType>(&mut self, param: &[u8], value: T) -> Result<(), XiError> {
         let param_c = match CStr::from_bytes_with_nul(param) {
             Ok(c) => c,
-            Err(_) => return Err(XI_RET::XI_INVALID_ARG as XI_RETURN),
+            Err(_) => return Err(XiError::InvalidArg),
         };
-        let err = T::set_param(self.device_handle, param_c.as_ptr(), value);
-        match err as XI_RET::Type {
-            XI_RET::XI_OK => Ok(()),
-            _ => Err(err),
-        }
+        let result = unsafe {
+            T::set_param(
+                self.backend.as_ref(),
+                self.device_handle,
+                param_c,
+                value.clone(),
+            )
+        };
+        result.map_err(|err| err.with_param(param, ParamOperation::Set, Some(&value)))
+    }
+
+    fn set_param_direct<T: ParamType>(
+        &mut self,
+        param: &'static [u8],
+        value: T,
+    ) -> Result<(), XiError> {
+        let mut modified_param = param.strip_suffix(&[0]).unwrap_or(param).to_vec();
+        modified_param.extend_from_slice(XI_PRMM_DIRECT_UPDATE);
+        self.set_param(&modified_param, value)
     }
 
-    unsafe fn param<T: ParamType>(&self, param: &[u8]) -> Result<T, XI_RETURN> {
-        let mut value = T::default();
+    fn param<T: ParamType>(&self, param: &[u8]) -> Result<T, XiError> {
+        self.param_raw(param)
+            .map_err(|err| err.with_param(param, ParamOperation::Get, None::<&T>))
+    }
+
+    fn param_raw<T: ParamType>(&self, param: &[u8]) -> Result<T, XiError> {
         let param_c = match CStr::from_bytes_with_nul(param) {
             Ok(c) => c,
-            Err(_) => return Err(XI_RET::XI_INVALID_ARG as XI_RETURN),
+            Err(_) => return Err(XiError::InvalidArg),
         };
-        let err = T::get_param(self.device_handle, param_c.as_ptr(), &mut value);
-        match err as XI_RET::Type {
-            XI_RET::XI_OK => Ok(value),
-            _ => Err(err),
-        }
+        unsafe { T::get_param(self.backend.as_ref(), self.device_handle, param_c) }
+    }
+
+    fn param_increment<T: ParamType>(&self, param: &'static [u8]) -> Result<T, XiError> {
+        self.param_info(param, XI_PRM_INFO_INCREMENT, ParamOperation::Increment)
     }
 
-    unsafe fn param_increment<T: ParamType>(&self, param: &'static [u8]) -> Result<T, XI_RETURN> {
-        self.param_info(param, XI_PRM_INFO_INCREMENT)
+    fn param_min<T: ParamType>(&self, param: &'static [u8]) -> Result<T, XiError> {
+        self.param_info(param, XI_PRM_INFO_MIN, ParamOperation::Minimum)
     }
 
-    unsafe fn param_min<T: ParamType>(&self, param: &'static [u8]) -> Result<T, XI_RETURN> {
-        self.param_info(param, XI_PRM_INFO_MIN)
+    fn param_max<T: ParamType>(&self, param: &'static [u8]) -> Result<T, XiError> {
+        self.param_info(param, XI_PRM_INFO_MAX, ParamOperation::Maximum)
     }
 
-    unsafe fn param_max<T: ParamType>(&self, param: &'static [u8]) -> Result<T, XI_RETURN> {
-        self.param_info(param, XI_PRM_INFO_MAX)
+    fn param_range<T: ParamType>(&self, param: &'static [u8]) -> Result<ParamRange<T>, XiError> {
+        Ok(ParamRange {
+            minimum: self.param_min(param)?,
+            maximum: self.param_max(param)?,
+            increment: self.param_increment(param)?,
+        })
     }
 
-    unsafe fn param_info<T: ParamType>(
+    fn param_info<T: ParamType>(
         &self,
         param: &'static [u8],
         info_modifier: &'static [u8],
-    ) -> Result<T, XI_RETURN> {
+        operation: ParamOperation,
+    ) -> Result<T, XiError> {
         // Strings need to be sanitized and then concatenated
-        let param_utf8 = from_utf8(param).or(Err(XI_RET::XI_INVALID_ARG as i32))?;
+        let param_utf8 = from_utf8(param).or(Err(XiError::InvalidArg))?;
         let modifier_utf8 =
             from_utf8(info_modifier).expect("UTF8 error on API constant -> Unreachable");
         // We have to specifically trim the null character from the first string
@@ -393,18 +951,20 @@ impl Camera {
             param_utf8.trim_matches(char::from(0)),
             modifier_utf8
         );
-        self.param(modified_param.as_bytes())
+        self.param_raw(modified_param.as_bytes())
+            .map_err(|err| err.with_param(param, operation, None::<&T>))
     }
 
     /// Set the region of interest on this camera.
     ///
+    /// Each value is rounded down to the next legal value of the respective parameter.
     /// Return the region of interest that was actually set to the camera.
     ///
     /// # Examples
     ///
     /// ```
     /// # #[serial_test::file_serial]
-    /// # fn main() -> Result<(), xiapi_sys::XI_RETURN> {
+    /// # fn main() -> Result<(), xiapi::XiError> {
     ///     let mut cam = xiapi::open_device(None)?;
     ///     let roi = xiapi::Roi{
     ///         offset_x: 100,
@@ -416,24 +976,20 @@ impl Camera {
     /// # Ok(())
     /// # }
     ///
-    pub fn set_roi(&mut self, roi: &Roi) -> Result<Roi, XI_RETURN> {
+    pub fn set_roi(&mut self, roi: &Roi) -> Result<Roi, XiError> {
         self.set_offset_x(0)?;
         self.set_offset_y(0)?;
 
-        let width_inc = self.width_increment()?;
-        let width = roi.width - (roi.width % width_inc);
+        let width = self.width_range()?.snap_down(roi.width);
         self.set_width(width)?;
 
-        let height_inc = self.height_increment()?;
-        let height = roi.height - (roi.height % height_inc);
+        let height = self.height_range()?.snap_down(roi.height);
         self.set_height(height)?;
 
-        let offset_x_inc = self.offset_x_increment()?;
-        let offset_x = roi.offset_x - (roi.offset_x % offset_x_inc);
+        let offset_x = self.offset_x_range()?.snap_down(roi.offset_x);
         self.set_offset_x(offset_x)?;
 
-        let offset_y_inc = self.offset_y_increment()?;
-        let offset_y = roi.offset_y - (roi.offset_y % offset_y_inc);
+        let offset_y = self.offset_y_range()?.snap_down(roi.offset_y);
         self.set_offset_y(offset_y)?;
 
         let actual_roi = Roi {
@@ -446,7 +1002,7 @@ impl Camera {
     }
 
     /// Returns the current roi from this camera
-    pub fn roi(&self) -> Result<Roi, XI_RETURN> {
+    pub fn roi(&self) -> Result<Roi, XiError> {
         let width = self.width()?;
         let height = self.height()?;
         let offset_x = self.offset_x()?;
@@ -460,48 +1016,151 @@ impl Camera {
         Ok(result)
     }
 
+    /// The device manifest, which describes the parameters supported by this camera.
+    ///
+    /// The manifest is read from the camera on the first call and kept for the lifetime of the
+    /// Camera.
+    pub fn manifest(&self) -> Result<&Manifest, XiError> {
+        if let Some(manifest) = self.manifest.get() {
+            return Ok(manifest);
+        }
+        let xml: String = self.param(XI_PRM_DEVICE_MANIFEST)?;
+        let manifest = Manifest::parse(&xml)?;
+        Ok(self.manifest.get_or_init(|| manifest))
+    }
+
+    /// Returns true if this camera supports the parameter `name`, according to its
+    /// [manifest](Camera::manifest).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # fn main() -> Result<(), xiapi::XiError> {
+    ///     let mut cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+    ///     if cam.is_supported("exposure_burst_count")? {
+    ///         cam.set_exposure_burst_count(1)?;
+    ///     }
+    ///     assert!(!cam.is_supported("hdr")?);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn is_supported(&self, name: &str) -> Result<bool, XiError> {
+        Ok(self.manifest()?.contains(name))
+    }
+
+    /// Read a parameter by its name, without knowing its type at compile time.
+    ///
+    /// `name` is the xiAPI name of the parameter (see `XI_PRM_*`), optionally followed by a
+    /// modifier like `:min`. The value is returned in the type that xiAPI reports for the
+    /// parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use xiapi::ParamValue;
+    /// # fn main() -> Result<(), xiapi::XiError> {
+    ///     let cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+    ///     assert_eq!(cam.get_dynamic("exposure")?, ParamValue::Float(10000.0));
+    ///     assert_eq!(cam.get_dynamic("device_type")?, ParamValue::String("SIM".into()));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn get_dynamic(&self, name: &str) -> Result<ParamValue, XiError> {
+        self.get_dynamic_raw(name).map_err(|err| {
+            err.with_param(name.as_bytes(), ParamOperation::Get, None::<&ParamValue>)
+        })
+    }
+
+    fn get_dynamic_raw(&self, name: &str) -> Result<ParamValue, XiError> {
+        let prm = CString::new(name).or(Err(XiError::InvalidArg))?;
+        let backend = self.backend.as_ref();
+        let buffer_size = unsafe { string_buffer_size(backend, self.device_handle, &prm) };
+        let mut buffer = vec![0u8; buffer_size.max(size_of::<u64>())];
+        let mut size = 0;
+        let mut prm_type = XI_PRM_TYPE::xiTypeInteger;
+        let mut ret = XI_RET::XI_OK as XI_RETURN;
+        // The actual type of the parameter is reported by the first call. If it differs from the
+        // requested type, the value is read again in its actual type.
+        for _ in 0..2 {
+            let requested = prm_type;
+            size = buffer.len() as u32;
+            ret = unsafe {
+                backend.get_param(
+                    self.device_handle,
+                    &prm,
+                    &mut buffer,
+                    &mut size,
+                    &mut prm_type,
+                )
+            };
+            if prm_type == requested {
+                break;
+            }
+        }
+        check(ret)?;
+        let size = buffer.len().min(size as usize);
+        ParamValue::decode(prm_type, &buffer[..size]).ok_or(XiError::WrongParamType)
+    }
+
+    /// Set a parameter by its name, without knowing its type at compile time.
+    ///
+    /// `name` is the xiAPI name of the parameter (see `XI_PRM_*`). The value is passed to xiAPI in
+    /// the type of the given [ParamValue], xiAPI converts it to the type of the parameter if
+    /// possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use xiapi::ParamValue;
+    /// # fn main() -> Result<(), xiapi::XiError> {
+    ///     let mut cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+    ///     cam.set_dynamic("gain", ParamValue::Float(3.0))?;
+    ///     cam.set_dynamic("width", 640)?;
+    ///     assert_eq!(cam.gain()?, 3.0);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn set_dynamic(&mut self, name: &str, value: impl Into<ParamValue>) -> Result<(), XiError> {
+        let value = value.into();
+        let result = CString::new(name)
+            .or(Err(XiError::InvalidArg))
+            .and_then(|prm| {
+                let (prm_type, bytes) = value.encode();
+                check(unsafe {
+                    self.backend
+                        .set_param(self.device_handle, &prm, &bytes, prm_type)
+                })
+            });
+        result.map_err(|err| err.with_param(name.as_bytes(), ParamOperation::Set, Some(&value)))
+    }
+
     /// Convenience method to read counters from the camera with a single call
     /// See also [Self.counter_selector] and [Self.counter_value]
-    pub fn counter(
-        &mut self,
-        counter_selector: XI_COUNTER_SELECTOR::Type,
-    ) -> Result<i32, XI_RETURN> {
-        let prev_selector = self.counter_selector()?;
-        self.set_counter_selector(counter_selector)?;
-        let result = self.counter_value()?;
-        self.set_counter_selector(prev_selector)?;
-        Ok(result)
+    pub fn counter(&mut self, counter_selector: CounterSelector) -> Result<i32, XiError> {
+        self.with_selected(counter_selector, |cam| cam.counter_value())
     }
 
     param! {
-        /// Current exposure time in microseconds.
-        mut exposure: f32;
-
         /// Sets the number of times of exposure in one frame.
         mut exposure_burst_count: i32;
 
-        /// Set the gain in dB.
-        /// If the camera has more than one type of gain, you can use [Self::set_gain_selector()] to
-        /// select a gain.
-        mut gain: f32;
-
-        /// The currently selected type of gain for [Self::gain()] and [Self::set_gain()]
-        mut gain_selector: XI_GAIN_SELECTOR_TYPE::Type;
-
         /// Changes image resolution by binning or skipping
-        mut downsampling: XI_DOWNSAMPLING_VALUE::Type;
+        mut downsampling: Downsampling;
 
         /// Changes the downsampling type between binning and skipping
-        mut downsampling_type: XI_DOWNSAMPLING_TYPE::Type;
+        mut downsampling_type: DownsamplingType;
 
         /// Format of the image data
-        mut image_data_format: XI_IMG_FORMAT::Type;
+        mut image_data_format: ImageFormat;
 
         /// Selects the Test Pattern Generator Engine
-        mut test_pattern_generator_selector: XI_TEST_PATTERN_GENERATOR::Type;
+        mut test_pattern_generator_selector: TestPatternGenerator;
 
         /// Selects the Test Pattern to be generated by selected Generator Engine
-        mut test_pattern: XI_TEST_PATTERN::Type;
+        mut test_pattern: TestPattern;
 
         /// Immage ROI height (number of lines)
         mut height: u32;
@@ -516,10 +1175,10 @@ impl Camera {
         mut offset_y: u32;
 
         /// Activates horizontal flip if available in camera.
-        mut horizontal_flip: XI_SWITCH::Type;
+        mut horizontal_flip: bool;
 
         /// Activates vertical flip if available in camera.
-        mut vertical_flip: XI_SWITCH::Type;
+        mut vertical_flip: bool;
 
         /// Camera acquisition data-rate limit on transport layer in Megabits per second.
         mut limit_bandwidth: i32;
@@ -528,96 +1187,75 @@ impl Camera {
         available_bandwidth: i32;
 
         /// Defines the source of trigger
-        mut trg_source: XI_TRG_SOURCE::Type;
+        mut trg_source: TriggerSource;
 
         /// Selects the type of trigger
-        mut trg_selector: XI_TRG_SELECTOR::Type;
+        mut trg_selector: TriggerSelector;
 
         /// Selects the type of trigger overlap
-        mut trg_overlap: XI_TRG_OVERLAP::Type;
+        mut trg_overlap: TriggerOverlap;
 
         /// Sets the number of frames to be triggered for each trigger signal.
-        /// This setting is only valid if the trigger selector is set to XI_TRG_SEL_FRAME_BURST_START
+        /// This setting is only valid if the trigger selector is set to
+        /// [TriggerSelector::FrameBurstStart]
         mut acq_frame_burst_count: u32;
 
         /// Defines the acquisition timing mode
-        mut acq_timing_mode: XI_ACQ_TIMING_MODE::Type;
+        mut acq_timing_mode: AcqTimingMode;
 
         /// Defines frames per second of sensor
         mut framerate: f32;
 
         /// Selects a GPI
-        mut gpi_selector: XI_GPI_SELECTOR::Type;
+        mut gpi_selector: GpiSelector;
 
         /// Defines functionality for the selected GPI
-        mut gpi_mode: XI_GPI_MODE::Type;
-
-        /// Selects a GPO
-        mut gpo_selector: XI_GPO_SELECTOR::Type;
-
-        /// Defines functionality for the selected GPO
-        mut gpo_mode: XI_GPO_MODE::Type;
-
-        /// Selects a LED
-        mut led_selector: XI_LED_SELECTOR::Type;
-
-        /// Defines functionality for the selected LED
-        mut led_mode: XI_LED_MODE::Type;
+        mut gpi_mode: GpiMode;
 
         /// Enable or disable signal debounce for selected GPI
-        mut debounce_en: XI_SWITCH::Type;
-
-        /// Set user data to be stored in the image header
-        mut image_user_data: u32;
+        mut debounce_en: bool;
 
         /// Set the bit depth for the ADCs on the sensor
         /// # Examples
         /// ```
         /// # #[serial_test::file_serial()]
-        /// # fn main() -> Result<(), xiapi::XI_RETURN>{
-        /// # use xiapi_sys::XI_IMG_FORMAT::XI_RAW16;
-        /// # use xiapi::XI_BIT_DEPTH::XI_BPP_12;
+        /// # fn main() -> Result<(), xiapi::XiError>{
+        /// # use xiapi::{BitDepth, ImageFormat};
         /// let mut cam = xiapi::open_device(None)?;
-        /// cam.set_image_data_format(XI_RAW16)?;
-        /// cam.set_sensor_data_bit_depth(XI_BPP_12)?;
-        /// cam.set_output_data_bit_depth(XI_BPP_12)?;
-        /// cam.set_image_data_bit_depth(XI_BPP_12)?;
-        /// # assert_eq!(cam.sensor_data_bit_depth()?, XI_BPP_12);
-        /// # assert_eq!(cam.output_data_bit_depth()?, XI_BPP_12);
-        /// # assert_eq!(cam.image_data_bit_depth()?, XI_BPP_12);
+        /// cam.set_image_data_format(ImageFormat::Raw16)?;
+        /// cam.set_sensor_data_bit_depth(BitDepth::Bpp12)?;
+        /// cam.set_output_data_bit_depth(BitDepth::Bpp12)?;
+        /// cam.set_image_data_bit_depth(BitDepth::Bpp12)?;
+        /// # assert_eq!(cam.sensor_data_bit_depth()?, BitDepth::Bpp12);
+        /// # assert_eq!(cam.output_data_bit_depth()?, BitDepth::Bpp12);
+        /// # assert_eq!(cam.image_data_bit_depth()?, BitDepth::Bpp12);
         /// # Ok(())
         /// }
-        mut sensor_data_bit_depth: XI_BIT_DEPTH::Type;
+        mut sensor_data_bit_depth: BitDepth;
 
         /// Set the bit depth send from the camera to the PC
-        mut output_data_bit_depth: XI_BIT_DEPTH::Type;
+        mut output_data_bit_depth: BitDepth;
 
         /// Bit depth of the image returned by [Self::next_image()]
-        mut image_data_bit_depth: XI_BIT_DEPTH::Type;
+        mut image_data_bit_depth: BitDepth;
 
         /// Enable column fpn correction in camera
-        mut column_fpn_correction: XI_SWITCH::Type;
+        mut column_fpn_correction: bool;
 
         /// Enable row fpn correction in camera
-        mut row_fpn_correction: XI_SWITCH::Type;
+        mut row_fpn_correction: bool;
 
         /// Enable column black offset correction
-        mut column_black_offset_correction: XI_SWITCH::Type;
+        mut column_black_offset_correction: bool;
 
         /// Enable row black offset correction
-        mut row_black_offset_correction: XI_SWITCH::Type;
-
-        /// Select the frame counter to read
-        mut counter_selector: XI_COUNTER_SELECTOR::Type;
-
-        /// Read the value of a frame counter selected with [Self::set_counter_selector]
-        counter_value: i32;
+        mut row_black_offset_correction: bool;
 
         /// Select a sensor specific feature
-        mut sensor_feature_selector: XI_SENSOR_FEATURE_SELECTOR::Type;
+        mut sensor_feature_selector: SensorFeatureSelector;
 
         /// Read color filter array type of RAW data.
-        color_filter_array: XI_COLOR_FILTER_ARRAY::Type;
+        color_filter_array: ColorFilterArray;
 
         /// Set a value for the feature selected with [Self::set_sensor_feature_selector]
         mut sensor_feature_value: i32;
@@ -625,32 +1263,168 @@ impl Camera {
         /// Read the sensor clock frequency in Hz
         sensor_clock_freq_hz: f32;
 
-        /// Reads the current timestamp value from camera in nanoseconds (only valid for xiB, xiC, xiX camera families).
-        timestamp: u64;
-
         /// Data move policy
-        mut buffer_policy: i32;
+        mut buffer_policy: BufferPolicy;
 
         /// buffers_queue_size - 1 is the maximum number of images which can be stored in the buffers queue.
         mut buffers_queue_size: i32;
 
-        /// Auto white balance mode.
-        mut auto_wb: XI_SWITCH::Type;
+        /// Size of the acquisition buffer in bytes.
+        mut acq_buffer_size: i32;
 
-        /// White balance Red coefficient.
-        mut wb_kr: f32;
+        /// Recent Frame mode.
+        mut recent_frame: bool;
 
-        /// White balance Green coefficient.
-        mut wb_kg: f32;
+        /// Configures image data delivery target to CPU RAM (default) or GPU RAM.
+        mut transport_data_target: TransportDataTarget;
+    }
+}
 
-        /// White balance Blue coefficient.
-        mut wb_kb: f32;
+// Device identification and version parameters
+impl Camera {
+    param! {
+        /// Model name of the camera.
+        device_name: String;
 
-        /// Recent Frame mode.
-        mut recent_frame: XI_SWITCH::Type;
+        /// Type of the camera.
+        device_type: String;
 
-        /// Configures image data delivery target to CPU RAM (default) or GPU RAM.
-        mut transport_data_target: XI_TRANSPORT_DATA_TARGET_MODE::Type;
+        /// Serial number of the camera.
+        device_sn: String;
+
+        /// Serial number of the camera sensor.
+        device_sens_sn: String;
+
+        /// Instance path of the camera in the operating system.
+        device_instance_path: String;
+
+        /// Location path of the port the camera is connected to.
+        device_location_path: String;
+
+        /// User defined ID of the camera, which is stored in its non-volatile memory.
+        mut device_user_id: String;
+
+        /// Version of the xiAPI library.
+        api_version: String;
+
+        /// Version of the camera driver.
+        drv_version: String;
+
+        /// Firmware version of the first microcontroller in the camera.
+        mcu1_version: String;
+
+        /// Firmware version of the second microcontroller in the camera.
+        mcu2_version: String;
+
+        /// Firmware version of the third microcontroller in the camera.
+        mcu3_version: String;
+
+        /// Firmware version of the FPGA in the camera.
+        fpga1_version: String;
+
+        /// Version of the XML manifest of the camera.
+        xmlman_version: String;
+    }
+}
+
+// Parameters that can be accessed while the acquisition is running
+acquisition_param! {
+    /// Current exposure time in microseconds.
+    mut direct exposure: f32;
+
+    /// Set the gain in dB.
+    /// If the camera has more than one type of gain, you can use [Self::set_gain_selector()] to
+    /// select a gain.
+    mut direct gain: f32;
+
+    /// The currently selected type of gain for [Self::gain()] and [Self::set_gain()]
+    mut gain_selector: GainSelector;
+
+    /// Selects a GPO
+    mut gpo_selector: GpoSelector;
+
+    /// Defines functionality for the selected GPO
+    mut gpo_mode: GpoMode;
+
+    /// Selects a LED
+    mut led_selector: LedSelector;
+
+    /// Defines functionality for the selected LED
+    mut led_mode: LedMode;
+
+    /// Set user data to be stored in the image header
+    mut image_user_data: u32;
+
+    /// Select the frame counter to read
+    mut counter_selector: CounterSelector;
+
+    /// Read the value of a frame counter selected with [Self::set_counter_selector]
+    counter_value: i32;
+
+    /// Reads the current timestamp value from camera in nanoseconds (only valid for xiB, xiC, xiX camera families).
+    timestamp: u64;
+
+    /// Auto white balance mode.
+    mut auto_wb: bool;
+
+    /// White balance Red coefficient.
+    mut wb_kr: f32;
+
+    /// White balance Green coefficient.
+    mut wb_kg: f32;
+
+    /// White balance Blue coefficient.
+    mut wb_kb: f32;
+
+    /// Selects the thermometer that is read by [Self::temp()].
+    mut temp_selector: TempSelector;
+
+    /// Temperature at the location selected by [Self::set_temp_selector()] in degrees Celsius.
+    temp: f32;
+}
+
+// User sets
+impl Camera {
+    param! {
+        /// Selects the user set that is loaded by [load_user_set](Camera::load_user_set).
+        mut user_set_selector: UserSetSelector;
+
+        /// User set that is loaded when the camera is powered on or reset.
+        ///
+        /// The setting is stored in the camera, so it also affects the default mode in other
+        /// applications, e.g. CamTool. [UserSetSelector::None] keeps the factory defaults.
+        mut user_set_default: UserSetSelector;
+    }
+
+    /// Load the predefined user set `user_set` and make it active.
+    ///
+    /// The user sets of xiAPI are configurations that are predefined for the camera model, the
+    /// current configuration can not be saved to them. To store and restore a configuration on the
+    /// host, use [snapshot_settings](Camera::snapshot_settings) and
+    /// [apply_settings](Camera::apply_settings). To make a user set the power-on default, use
+    /// [set_user_set_default](Camera::set_user_set_default).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use xiapi::UserSetSelector;
+    /// # fn main() -> Result<(), xiapi::XiError> {
+    ///     let backend = Arc::new(xiapi::SimBackend::new(1));
+    ///     let mut cam = xiapi::open_device_with_backend(backend.clone(), None)?;
+    ///     cam.load_user_set(UserSetSelector::Std12High)?;
+    ///     assert_eq!(cam.gain()?, 12.0);
+    ///
+    ///     cam.set_user_set_default(UserSetSelector::Std12Low)?;
+    ///     drop(cam);
+    ///     let cam = xiapi::open_device_with_backend(backend, None)?;
+    ///     assert_eq!(cam.user_set_default()?, UserSetSelector::Std12Low);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn load_user_set(&mut self, user_set: UserSetSelector) -> Result<(), XiError> {
+        self.set_user_set_selector(user_set)?;
+        self.set_param(XI_PRM_USER_SET_LOAD, true)
     }
 }
 
@@ -662,13 +1436,13 @@ impl Deref for Camera {
     /// While getting the handle itself is safe, everything that can practically be done with it
     /// should be considered unsafe. Especially operations that change the state of the camera
     /// (e.g. setting parameters) are undefined behavior.
+    /// The handle can only be passed to the [Backend] that was used to open this camera.
     fn deref(&self) -> &Self::Target {
         &self.device_handle
     }
 }
 
-unsafe impl Send for Camera {
-}
+unsafe impl Send for Camera {}
 
 impl AcquisitionBuffer {
     /// Stop the image acquisition.
@@ -679,66 +1453,161 @@ impl AcquisitionBuffer {
     ///
     /// When this is called, the camera will stop acquiring images and images previously acquired
     /// but not retrieved from the acquisition buffer can no longer be accessed.
-    pub fn stop_acquisition(self) -> Result<Camera, XI_RETURN> {
-        let err = unsafe { xiapi_sys::xiStopAcquisition(self.camera.device_handle) };
+    ///
+    /// If the acquisition can not be stopped, the AcquisitionBuffer is returned in the
+    /// [AcquisitionError].
+    pub fn stop_acquisition(self) -> Result<Camera, AcquisitionError<AcquisitionBuffer>> {
+        let err = unsafe {
+            self.camera
+                .backend
+                .stop_acquisition(self.camera.device_handle)
+        };
         match err as XI_RET::Type {
-            XI_RET::XI_OK => Ok(self.camera),
-            _ => Err(err),
+            XI_RET::XI_OK => Ok(self.into_camera()),
+            _ => Err(AcquisitionError::new(XiError::from(err), self)),
         }
     }
 
+    /// Move the camera out of this buffer without stopping the acquisition
+    fn into_camera(self) -> Camera {
+        let mut buffer = ManuallyDrop::new(self);
+        // Safety: The buffer is never dropped, so the camera is not used again
+        unsafe { ManuallyDrop::take(&mut buffer.camera) }
+    }
+
     /// Get the next image.
     ///
     /// Returns an [Image] which refers to memory in this [AcquisitionBuffer].
     /// The image will have a reference with the same lifetime as the AcquisitionBuffer making sure
     /// that it is always "safe" to use (However, it may still be overwritten in unsafe buffer mode).
-    pub fn next_image<'a, T>(&'a self, timeout: Option<u32>) -> Result<Image<'a, T>, XI_RETURN> {
+    ///
+    /// The pixel type `T` has to match the format of the image (see [PixelFormat]), otherwise
+    /// [XiError::PixelFormatMismatch] is returned and the image is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use xiapi::{ImageFormat, XiError};
+    /// # fn main() -> Result<(), xiapi::XiError> {
+    ///     let mut cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+    ///     cam.set_image_data_format(ImageFormat::Mono8)?;
+    ///     let buffer = cam.start_acquisition()?;
+    ///     let err = buffer.next_image::<u16>(None).err().unwrap();
+    ///     assert!(matches!(err, XiError::PixelFormatMismatch { format: ImageFormat::Mono8, .. }));
+    ///     let image = buffer.next_image::<u8>(None)?;
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn next_image<'a, T: PixelFormat>(
+        &'a self,
+        timeout: Option<u32>,
+    ) -> Result<Image<'a, T>, XiError> {
+        Image::from_xi_img(self.next_xi_img(timeout)?)
+    }
+
+    /// Get the next image, with a pixel type that depends on its format.
+    ///
+    /// This works like [next_image](AcquisitionBuffer::next_image), but is useful if the image
+    /// format is changed at runtime. See [DynamicFrame] for details.
+    pub fn next_image_dynamic(&self, timeout: Option<u32>) -> Result<DynamicFrame<'_>, XiError> {
+        DynamicFrame::from_xi_img(self.next_xi_img(timeout)?)
+    }
+
+    fn next_xi_img(&self, timeout: Option<u32>) -> Result<XI_IMG, XiError> {
         let timeout = timeout.unwrap_or(u32::MAX);
-        let xi_img = unsafe {
+        let mut xi_img = unsafe {
             let mut img = MaybeUninit::<XI_IMG>::zeroed().assume_init();
             img.size = size_of::<XI_IMG>() as u32;
             img
         };
-        let mut image = Image::<'a, T> {
-            xi_img,
-            pix_type: PhantomData::default(),
-        };
         let ret = unsafe {
-            xiapi_sys::xiGetImage(self.camera.device_handle, timeout, &mut image.xi_img)
+            self.camera
+                .backend
+                .get_image(self.camera.device_handle, timeout, &mut xi_img)
         };
 
-        match ret as XI_RET::Type{
-            XI_RET::XI_OK => {
-                Ok(image)
-            }
-            x => {
-               Err(x as XI_RETURN)
-            }
+        match ret as XI_RET::Type {
+            XI_RET::XI_OK => Ok(xi_img),
+            x => Err(XiError::from(x as XI_RETURN)),
         }
-
     }
 
     /// Send a software trigger signal to the camera.
     ///
-    /// Trigger source has to be set to XI_TRG_SOFTWARE for this to take effect
+    /// Trigger source has to be set to [TriggerSource::Software] for this to take effect
     ///
     /// # Examples
     /// ```
     /// # #[serial_test::file_serial]
-    /// # fn main() -> Result<(), xiapi_sys::XI_RETURN> {
+    /// # fn main() -> Result<(), xiapi::XiError> {
     ///     let mut cam = xiapi::open_device(None)?;
-    ///     cam.set_trg_source(xiapi_sys::XI_TRG_SOURCE::XI_TRG_SOFTWARE)?;
+    ///     cam.set_trg_source(xiapi::TriggerSource::Software)?;
     ///     let mut acq_buffer = cam.start_acquisition()?;
     ///     acq_buffer.software_trigger()?;
     ///     let img = acq_buffer.next_image::<u8>(None)?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn software_trigger(&mut self) -> Result<(), XI_RETURN> {
-        unsafe { self.camera.set_param(XI_PRM_TRG_SOFTWARE, XI_SWITCH::XI_ON) }
+    pub fn software_trigger(&mut self) -> Result<(), XiError> {
+        self.camera.set_param(XI_PRM_TRG_SOFTWARE, true)
+    }
+
+    /// Convenience method to read counters from the camera with a single call
+    /// See also [Camera::counter]
+    pub fn counter(&mut self, counter_selector: CounterSelector) -> Result<i32, XiError> {
+        self.camera.counter(counter_selector)
     }
-}
 
-unsafe impl Send for AcquisitionBuffer{
+    fn set_param<T: ParamType>(&mut self, param: &[u8], value: T) -> Result<(), XiError> {
+        self.camera.set_param(param, value)
+    }
+
+    fn set_param_direct<T: ParamType>(
+        &mut self,
+        param: &'static [u8],
+        value: T,
+    ) -> Result<(), XiError> {
+        self.camera.set_param_direct(param, value)
+    }
+
+    fn param<T: ParamType>(&self, param: &[u8]) -> Result<T, XiError> {
+        self.camera.param(param)
+    }
+
+    fn param_increment<T: ParamType>(&self, param: &'static [u8]) -> Result<T, XiError> {
+        self.camera.param_increment(param)
+    }
 
+    fn param_min<T: ParamType>(&self, param: &'static [u8]) -> Result<T, XiError> {
+        self.camera.param_min(param)
+    }
+
+    fn param_max<T: ParamType>(&self, param: &'static [u8]) -> Result<T, XiError> {
+        self.camera.param_max(param)
+    }
+
+    fn param_range<T: ParamType>(&self, param: &'static [u8]) -> Result<ParamRange<T>, XiError> {
+        self.camera.param_range(param)
+    }
+}
+
+impl Drop for AcquisitionBuffer {
+    fn drop(&mut self) {
+        let err = unsafe {
+            self.camera
+                .backend
+                .stop_acquisition(self.camera.device_handle)
+        };
+        if let Err(err) = check(err) {
+            log::warn!(
+                "Failed to stop the acquisition of a dropped AcquisitionBuffer: {}",
+                err
+            );
+        }
+        // Safety: The camera is not used after this
+        unsafe { ManuallyDrop::drop(&mut self.camera) }
+    }
 }
+
+unsafe impl Send for AcquisitionBuffer {}
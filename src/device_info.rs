@@ -0,0 +1,119 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::ffi::CStr;
+
+use crate::sys::*;
+
+use crate::backend::default_backend;
+use crate::Backend;
+use crate::XiError;
+
+/// Size of the buffer used to read device information strings
+const INFO_BUFFER_SIZE: usize = 512;
+
+/// Information about a connected camera that is available without opening it.
+///
+/// The [dev_id](DeviceInfo::dev_id) can be passed to [open_device](crate::open_device) to open
+/// the described camera.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// Index of the device as used by [open_device](crate::open_device)
+    pub dev_id: u32,
+    /// Serial number of the camera
+    pub serial_number: String,
+    /// Model name of the camera
+    pub name: String,
+    /// Type of the camera (e.g. the interface it is connected through)
+    pub device_type: String,
+    /// Instance path of the camera in the operating system
+    pub instance_path: String,
+    /// Sensor model ID, if the camera reports it
+    pub sensor_id: Option<String>,
+}
+
+impl DeviceInfo {
+    /// Read the information of device `dev_id` from `backend`
+    fn read(backend: &dyn Backend, dev_id: u32) -> Result<Self, XiError> {
+        let sensor_id = match info_string(backend, dev_id, XI_PRM_SENSOR_MODEL_ID) {
+            Ok(sensor_id) => Some(sensor_id),
+            Err(
+                XiError::NotSupported
+                | XiError::NotImplemented
+                | XiError::NotSupportedParam
+                | XiError::NotSupportedParamInfo
+                | XiError::UnknownParam
+                | XiError::ParamConditionallyNotAvailable,
+            ) => None,
+            Err(err) => return Err(err),
+        };
+        Ok(DeviceInfo {
+            dev_id,
+            serial_number: info_string(backend, dev_id, XI_PRM_DEVICE_SN)?,
+            name: info_string(backend, dev_id, XI_PRM_DEVICE_NAME)?,
+            device_type: info_string(backend, dev_id, XI_PRM_DEVICE_TYPE)?,
+            instance_path: info_string(backend, dev_id, XI_PRM_DEVICE_INSTANCE_PATH)?,
+            sensor_id,
+        })
+    }
+}
+
+/// Read the device information string `prm` of device `dev_id`
+fn info_string(backend: &dyn Backend, dev_id: u32, prm: &[u8]) -> Result<String, XiError> {
+    let prm = CStr::from_bytes_with_nul(prm).unwrap();
+    let mut value = [0u8; INFO_BUFFER_SIZE];
+    let res = backend.get_device_info_string(dev_id, prm, &mut value);
+    match res as XI_RET::Type {
+        XI_RET::XI_OK => {
+            let length = value.iter().position(|c| *c == 0).unwrap_or(value.len());
+            Ok(String::from_utf8_lossy(&value[..length]).into_owned())
+        }
+        _ => Err(XiError::from(res)),
+    }
+}
+
+/// Returns information about all available cameras without opening them.
+///
+/// The position of each entry in the returned list is its device index.
+///
+/// # Examples
+///
+/// ```
+/// # #[serial_test::file_serial]
+/// # fn main() -> Result<(), xiapi::XiError>{
+///     for device in xiapi::enumerate_devices()? {
+///         println!("{}: {} ({})", device.dev_id, device.name, device.serial_number);
+///     }
+/// # Ok(())
+/// # }
+/// ```
+pub fn enumerate_devices() -> Result<Vec<DeviceInfo>, XiError> {
+    enumerate_devices_with_backend(default_backend().as_ref())
+}
+
+/// Returns information about all cameras available through `backend` without opening them.
+///
+/// This works exactly like [enumerate_devices], but queries `backend` instead of the default
+/// backend.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), xiapi::XiError>{
+///     let backend = xiapi::SimBackend::new(2);
+///     let devices = xiapi::enumerate_devices_with_backend(&backend)?;
+///     assert_eq!(devices.len(), 2);
+/// #   Ok(())
+/// # }
+/// ```
+pub fn enumerate_devices_with_backend(backend: &dyn Backend) -> Result<Vec<DeviceInfo>, XiError> {
+    let mut count = 0u32;
+    let res = backend.get_number_devices(&mut count);
+    if res != XI_RET::XI_OK as XI_RETURN {
+        return Err(XiError::from(res));
+    }
+    (0..count)
+        .map(|dev_id| DeviceInfo::read(backend, dev_id))
+        .collect()
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::OnceLock;
+
+use crate::sys::*;
+use libloading::Library;
+
+/// Names under which the xiAPI library is searched on the current platform
+#[cfg(target_os = "windows")]
+const LIBRARY_NAMES: &[&str] = &["xiapi64.dll"];
+#[cfg(target_os = "macos")]
+const LIBRARY_NAMES: &[&str] = &["/Library/Frameworks/m3api.framework/m3api", "m3api"];
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const LIBRARY_NAMES: &[&str] = &["libm3api.so.2", "libm3api.so"];
+
+/// This macro generates the [XiApiLibrary] struct from a list of xiAPI function signatures.
+/// Each function is resolved when the library is loaded and stored as a function pointer.
+macro_rules! xiapi_library {
+    ($($name:ident: fn($($arg:ty),* $(,)?) -> $ret:ty;)*) => {
+        /// Function pointers into the xiAPI library that was loaded at runtime
+        #[allow(non_snake_case)]
+        pub(crate) struct XiApiLibrary {
+            $(pub(crate) $name: unsafe extern "C" fn($($arg),*) -> $ret,)*
+            _library: Library,
+        }
+
+        impl XiApiLibrary {
+            /// Resolve all functions from `library`
+            unsafe fn load(library: Library) -> Result<Self, libloading::Error> {
+                Ok(XiApiLibrary {
+                    $($name: *library.get(concat!(stringify!($name), "\0").as_bytes())?,)*
+                    _library: library,
+                })
+            }
+        }
+    };
+}
+
+xiapi_library! {
+    xiGetNumberDevices: fn(PDWORD) -> XI_RETURN;
+    xiGetDeviceInfoString: fn(DWORD, *const c_char, *mut c_char, DWORD) -> XI_RETURN;
+    xiOpenDevice: fn(DWORD, PHANDLE) -> XI_RETURN;
+    xiOpenDeviceBy: fn(XI_OPEN_BY::Type, *const c_char, PHANDLE) -> XI_RETURN;
+    xiCloseDevice: fn(HANDLE) -> XI_RETURN;
+    xiStartAcquisition: fn(HANDLE) -> XI_RETURN;
+    xiStopAcquisition: fn(HANDLE) -> XI_RETURN;
+    xiGetImage: fn(HANDLE, DWORD, *mut XI_IMG) -> XI_RETURN;
+    xiGetParamInt: fn(HANDLE, *const c_char, *mut c_int) -> XI_RETURN;
+    xiSetParamInt: fn(HANDLE, *const c_char, c_int) -> XI_RETURN;
+    xiGetParamFloat: fn(HANDLE, *const c_char, *mut f32) -> XI_RETURN;
+    xiSetParamFloat: fn(HANDLE, *const c_char, f32) -> XI_RETURN;
+    xiGetParamString: fn(HANDLE, *const c_char, *mut c_void, DWORD) -> XI_RETURN;
+    xiSetParamString: fn(HANDLE, *const c_char, *mut c_void, DWORD) -> XI_RETURN;
+    xiGetParam: fn(HANDLE, *const c_char, *mut c_void, *mut DWORD, *mut XI_PRM_TYPE::Type) -> XI_RETURN;
+    xiSetParam: fn(HANDLE, *const c_char, *mut c_void, DWORD, XI_PRM_TYPE::Type) -> XI_RETURN;
+}
+
+static LIBRARY: OnceLock<Option<XiApiLibrary>> = OnceLock::new();
+
+/// Get the xiAPI library, loading it on first use.
+///
+/// Returns None if the library is not installed on this system.
+pub(crate) fn library() -> Option<&'static XiApiLibrary> {
+    LIBRARY
+        .get_or_init(|| {
+            LIBRARY_NAMES.iter().find_map(|name| unsafe {
+                let library = Library::new(name).ok()?;
+                XiApiLibrary::load(library).ok()
+            })
+        })
+        .as_ref()
+}
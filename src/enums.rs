@@ -0,0 +1,399 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::ffi::CStr;
+
+use crate::sys::*;
+
+use crate::camera::ParamType;
+use crate::Backend;
+use crate::XiError;
+
+/// This macro generates a Rust enum for a set of xiAPI constants.
+/// Every enum can be converted from and to the raw `u32` value used by xiAPI and can be used as
+/// parameter type in the `param!` macro.
+macro_rules! xi_enum {
+    (
+        $(
+            $(#[doc = $doc:expr])*
+            $name:ident: $module:ident {
+                $($variant:ident = $value:ident,)*
+            }
+        )*
+    ) => {
+        $(
+            $(#[doc = $doc])*
+            #[doc = ""]
+            #[doc = concat!("Corresponds to `", stringify!($module), "` in xiAPI.")]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #[non_exhaustive]
+            #[repr(u32)]
+            pub enum $name {
+                $(
+                    #[doc = concat!("`", stringify!($value), "`")]
+                    $variant = $module::$value,
+                )*
+            }
+
+            impl $name {
+                /// All values that are known to these bindings
+                pub const VALUES: &'static [$name] = &[$($name::$variant,)*];
+            }
+
+            impl TryFrom<u32> for $name {
+                type Error = XiError;
+
+                /// Convert a raw xiAPI value.
+                ///
+                /// Fails with [XiError::UnknownValue] for values that are unknown to this version
+                /// of the bindings (e.g. when they are reported by a newer camera firmware).
+                fn try_from(value: u32) -> Result<Self, XiError> {
+                    match value {
+                        $(x if x == $module::$value => Ok($name::$variant),)*
+                        _ => Err(XiError::UnknownValue(value)),
+                    }
+                }
+            }
+
+            impl From<$name> for u32 {
+                fn from(value: $name) -> u32 {
+                    value as u32
+                }
+            }
+
+            impl ParamType for $name {
+                unsafe fn get_param(
+                    backend: &dyn Backend,
+                    handle: HANDLE,
+                    prm: &CStr,
+                ) -> Result<Self, XiError> {
+                    u32::get_param(backend, handle, prm).and_then($name::try_from)
+                }
+
+                unsafe fn set_param(
+                    backend: &dyn Backend,
+                    handle: HANDLE,
+                    prm: &CStr,
+                    value: Self,
+                ) -> Result<(), XiError> {
+                    u32::set_param(backend, handle, prm, value.into())
+                }
+            }
+        )*
+    };
+}
+
+xi_enum! {
+    /// Type of gain that is accessed by [gain](crate::Camera::gain)
+    GainSelector: XI_GAIN_SELECTOR_TYPE {
+        All = XI_GAIN_SELECTOR_ALL,
+        AnalogAll = XI_GAIN_SELECTOR_ANALOG_ALL,
+        DigitalAll = XI_GAIN_SELECTOR_DIGITAL_ALL,
+        AnalogTap1 = XI_GAIN_SELECTOR_ANALOG_TAP1,
+        AnalogTap2 = XI_GAIN_SELECTOR_ANALOG_TAP2,
+        AnalogTap3 = XI_GAIN_SELECTOR_ANALOG_TAP3,
+        AnalogTap4 = XI_GAIN_SELECTOR_ANALOG_TAP4,
+        AnalogN = XI_GAIN_SELECTOR_ANALOG_N,
+        AnalogS = XI_GAIN_SELECTOR_ANALOG_S,
+    }
+
+    /// Downsampling factor of the image
+    Downsampling: XI_DOWNSAMPLING_VALUE {
+        Dwn1x1 = XI_DWN_1x1,
+        Dwn2x2 = XI_DWN_2x2,
+        Dwn3x3 = XI_DWN_3x3,
+        Dwn4x4 = XI_DWN_4x4,
+        Dwn5x5 = XI_DWN_5x5,
+        Dwn6x6 = XI_DWN_6x6,
+        Dwn7x7 = XI_DWN_7x7,
+        Dwn8x8 = XI_DWN_8x8,
+        Dwn9x9 = XI_DWN_9x9,
+        Dwn10x10 = XI_DWN_10x10,
+        Dwn16x16 = XI_DWN_16x16,
+    }
+
+    /// Method used for downsampling
+    DownsamplingType: XI_DOWNSAMPLING_TYPE {
+        Binning = XI_BINNING,
+        Skipping = XI_SKIPPING,
+    }
+
+    /// Format of the image data delivered to the application
+    ImageFormat: XI_IMG_FORMAT {
+        Mono8 = XI_MONO8,
+        Mono16 = XI_MONO16,
+        Rgb24 = XI_RGB24,
+        Rgb32 = XI_RGB32,
+        RgbPlanar = XI_RGB_PLANAR,
+        Raw8 = XI_RAW8,
+        Raw16 = XI_RAW16,
+        TransportData = XI_FRM_TRANSPORT_DATA,
+        Rgb48 = XI_RGB48,
+        Rgb64 = XI_RGB64,
+        Rgb16Planar = XI_RGB16_PLANAR,
+        Raw8x2 = XI_RAW8X2,
+        Raw8x4 = XI_RAW8X4,
+        Raw16x2 = XI_RAW16X2,
+        Raw16x4 = XI_RAW16X4,
+        Raw32 = XI_RAW32,
+        Raw32Float = XI_RAW32FLOAT,
+    }
+
+    /// Source of the test pattern
+    TestPatternGenerator: XI_TEST_PATTERN_GENERATOR {
+        Sensor = XI_TESTPAT_GEN_SENSOR,
+        Fpga = XI_TESTPAT_GEN_FPGA,
+    }
+
+    /// Test pattern that replaces the image data
+    TestPattern: XI_TEST_PATTERN {
+        Off = XI_TESTPAT_OFF,
+        Black = XI_TESTPAT_BLACK,
+        White = XI_TESTPAT_WHITE,
+        GreyHorizRamp = XI_TESTPAT_GREY_HORIZ_RAMP,
+        GreyVertRamp = XI_TESTPAT_GREY_VERT_RAMP,
+        GreyHorizRampMoving = XI_TESTPAT_GREY_HORIZ_RAMP_MOVING,
+        GreyVertRampMoving = XI_TESTPAT_GREY_VERT_RAMP_MOVING,
+        HorizLineMoving = XI_TESTPAT_HORIZ_LINE_MOVING,
+        VertLineMoving = XI_TESTPAT_VERT_LINE_MOVING,
+        ColorBar = XI_TESTPAT_COLOR_BAR,
+        FrameCounter = XI_TESTPAT_FRAME_COUNTER,
+        DeviceSpecCounter = XI_TESTPAT_DEVICE_SPEC_COUNTER,
+    }
+
+    /// Source of the trigger signal
+    TriggerSource: XI_TRG_SOURCE {
+        Off = XI_TRG_OFF,
+        EdgeRising = XI_TRG_EDGE_RISING,
+        EdgeFalling = XI_TRG_EDGE_FALLING,
+        Software = XI_TRG_SOFTWARE,
+        LevelHigh = XI_TRG_LEVEL_HIGH,
+        LevelLow = XI_TRG_LEVEL_LOW,
+    }
+
+    /// Type of trigger
+    TriggerSelector: XI_TRG_SELECTOR {
+        FrameStart = XI_TRG_SEL_FRAME_START,
+        ExposureActive = XI_TRG_SEL_EXPOSURE_ACTIVE,
+        FrameBurstStart = XI_TRG_SEL_FRAME_BURST_START,
+        FrameBurstActive = XI_TRG_SEL_FRAME_BURST_ACTIVE,
+        MultipleExposures = XI_TRG_SEL_MULTIPLE_EXPOSURES,
+        ExposureStart = XI_TRG_SEL_EXPOSURE_START,
+        MultiSlopePhaseChange = XI_TRG_SEL_MULTI_SLOPE_PHASE_CHANGE,
+        AcquisitionStart = XI_TRG_SEL_ACQUISITION_START,
+    }
+
+    /// Behavior of triggers that arrive during exposure or readout
+    TriggerOverlap: XI_TRG_OVERLAP {
+        Off = XI_TRG_OVERLAP_OFF,
+        ReadOut = XI_TRG_OVERLAP_READ_OUT,
+        PrevFrame = XI_TRG_OVERLAP_PREV_FRAME,
+    }
+
+    /// Timing of the image acquisition
+    AcqTimingMode: XI_ACQ_TIMING_MODE {
+        FreeRun = XI_ACQ_TIMING_MODE_FREE_RUN,
+        FrameRate = XI_ACQ_TIMING_MODE_FRAME_RATE,
+        FrameRateLimit = XI_ACQ_TIMING_MODE_FRAME_RATE_LIMIT,
+    }
+
+    /// General purpose input port
+    GpiSelector: XI_GPI_SELECTOR {
+        Port1 = XI_GPI_PORT1,
+        Port2 = XI_GPI_PORT2,
+        Port3 = XI_GPI_PORT3,
+        Port4 = XI_GPI_PORT4,
+        Port5 = XI_GPI_PORT5,
+        Port6 = XI_GPI_PORT6,
+        Port7 = XI_GPI_PORT7,
+        Port8 = XI_GPI_PORT8,
+        Port9 = XI_GPI_PORT9,
+        Port10 = XI_GPI_PORT10,
+        Port11 = XI_GPI_PORT11,
+        Port12 = XI_GPI_PORT12,
+    }
+
+    /// Function of a general purpose input
+    GpiMode: XI_GPI_MODE {
+        Off = XI_GPI_OFF,
+        Trigger = XI_GPI_TRIGGER,
+        ExtEvent = XI_GPI_EXT_EVENT,
+    }
+
+    /// General purpose output port
+    GpoSelector: XI_GPO_SELECTOR {
+        Port1 = XI_GPO_PORT1,
+        Port2 = XI_GPO_PORT2,
+        Port3 = XI_GPO_PORT3,
+        Port4 = XI_GPO_PORT4,
+        Port5 = XI_GPO_PORT5,
+        Port6 = XI_GPO_PORT6,
+        Port7 = XI_GPO_PORT7,
+        Port8 = XI_GPO_PORT8,
+        Port9 = XI_GPO_PORT9,
+        Port10 = XI_GPO_PORT10,
+        Port11 = XI_GPO_PORT11,
+        Port12 = XI_GPO_PORT12,
+    }
+
+    /// Function of a general purpose output
+    GpoMode: XI_GPO_MODE {
+        Off = XI_GPO_OFF,
+        On = XI_GPO_ON,
+        FrameActive = XI_GPO_FRAME_ACTIVE,
+        FrameActiveNeg = XI_GPO_FRAME_ACTIVE_NEG,
+        ExposureActive = XI_GPO_EXPOSURE_ACTIVE,
+        ExposureActiveNeg = XI_GPO_EXPOSURE_ACTIVE_NEG,
+        FrameTriggerWait = XI_GPO_FRAME_TRIGGER_WAIT,
+        FrameTriggerWaitNeg = XI_GPO_FRAME_TRIGGER_WAIT_NEG,
+        ExposurePulse = XI_GPO_EXPOSURE_PULSE,
+        ExposurePulseNeg = XI_GPO_EXPOSURE_PULSE_NEG,
+        Busy = XI_GPO_BUSY,
+        BusyNeg = XI_GPO_BUSY_NEG,
+        HighImpedance = XI_GPO_HIGH_IMPEDANCE,
+        FrameBufferOverflow = XI_GPO_FRAME_BUFFER_OVERFLOW,
+        ExposureActiveFirstRow = XI_GPO_EXPOSURE_ACTIVE_FIRST_ROW,
+        ExposureActiveFirstRowNeg = XI_GPO_EXPOSURE_ACTIVE_FIRST_ROW_NEG,
+        ExposureActiveAllRows = XI_GPO_EXPOSURE_ACTIVE_ALL_ROWS,
+        ExposureActiveAllRowsNeg = XI_GPO_EXPOSURE_ACTIVE_ALL_ROWS_NEG,
+        Txd = XI_GPO_TXD,
+    }
+
+    /// LED of the camera
+    LedSelector: XI_LED_SELECTOR {
+        Led1 = XI_LED_SEL1,
+        Led2 = XI_LED_SEL2,
+        Led3 = XI_LED_SEL3,
+        Led4 = XI_LED_SEL4,
+        Led5 = XI_LED_SEL5,
+    }
+
+    /// Function of a LED
+    LedMode: XI_LED_MODE {
+        Heartbeat = XI_LED_HEARTBEAT,
+        TriggerActive = XI_LED_TRIGGER_ACTIVE,
+        ExtEventActive = XI_LED_EXT_EVENT_ACTIVE,
+        Link = XI_LED_LINK,
+        Acquisition = XI_LED_ACQUISITION,
+        ExposureActive = XI_LED_EXPOSURE_ACTIVE,
+        FrameActive = XI_LED_FRAME_ACTIVE,
+        Off = XI_LED_OFF,
+        On = XI_LED_ON,
+        Blink = XI_LED_BLINK,
+    }
+
+    /// Number of bits per pixel
+    BitDepth: XI_BIT_DEPTH {
+        Bpp8 = XI_BPP_8,
+        Bpp9 = XI_BPP_9,
+        Bpp10 = XI_BPP_10,
+        Bpp11 = XI_BPP_11,
+        Bpp12 = XI_BPP_12,
+        Bpp14 = XI_BPP_14,
+        Bpp16 = XI_BPP_16,
+        Bpp24 = XI_BPP_24,
+        Bpp32 = XI_BPP_32,
+    }
+
+    /// Frame counter that is read by [counter_value](crate::Camera::counter_value)
+    CounterSelector: XI_COUNTER_SELECTOR {
+        TransportSkippedFrames = XI_CNT_SEL_TRANSPORT_SKIPPED_FRAMES,
+        ApiSkippedFrames = XI_CNT_SEL_API_SKIPPED_FRAMES,
+        TransportTransferredFrames = XI_CNT_SEL_TRANSPORT_TRANSFERRED_FRAMES,
+        FrameMissedTriggerDueToOverlap = XI_CNT_SEL_FRAME_MISSED_TRIGGER_DUETO_OVERLAP,
+        FrameMissedTriggerDueToFrameBufferOverflow = XI_CNT_SEL_FRAME_MISSED_TRIGGER_DUETO_FRAME_BUFFER_OVR,
+        FrameBufferOverflow = XI_CNT_SEL_FRAME_BUFFER_OVERFLOW,
+    }
+
+    /// Sensor specific feature that is accessed by
+    /// [sensor_feature_value](crate::Camera::sensor_feature_value)
+    SensorFeatureSelector: XI_SENSOR_FEATURE_SELECTOR {
+        ZeroRotEnable = XI_SENSOR_FEATURE_ZEROROT_ENABLE,
+        BlackLevelClamp = XI_SENSOR_FEATURE_BLACK_LEVEL_CLAMP,
+        MdFpgaDigitalGainDisable = XI_SENSOR_FEATURE_MD_FPGA_DIGITAL_GAIN_DISABLE,
+        AcquisitionRunning = XI_SENSOR_FEATURE_ACQUISITION_RUNNING,
+        TimingMode = XI_SENSOR_FEATURE_TIMING_MODE,
+        ParallelAdc = XI_SENSOR_FEATURE_PARALLEL_ADC,
+        BlackLevelOffsetRaw = XI_SENSOR_FEATURE_BLACK_LEVEL_OFFSET_RAW,
+        ShortIntervalShutter = XI_SENSOR_FEATURE_SHORT_INTERVAL_SHUTTER,
+        AutoLowPowerModeAuto = XI_SENSOR_FEATURE_AUTO_LOW_POWER_MODE_AUTO,
+        HighConversionGain = XI_SENSOR_FEATURE_HIGH_CONVERSION_GAIN,
+    }
+
+    /// Arrangement of the color filters on the sensor
+    ColorFilterArray: XI_COLOR_FILTER_ARRAY {
+        None = XI_CFA_NONE,
+        BayerRggb = XI_CFA_BAYER_RGGB,
+        Cmyg = XI_CFA_CMYG,
+        Rgr = XI_CFA_RGR,
+        BayerBggr = XI_CFA_BAYER_BGGR,
+        BayerGrbg = XI_CFA_BAYER_GRBG,
+        BayerGbrg = XI_CFA_BAYER_GBRG,
+        PolarABayerBggr = XI_CFA_POLAR_A_BAYER_BGGR,
+        PolarA = XI_CFA_POLAR_A,
+    }
+
+    /// Memory that receives the image data
+    TransportDataTarget: XI_TRANSPORT_DATA_TARGET_MODE {
+        CpuRam = XI_TRANSPORT_DATA_TARGET_CPU_RAM,
+        GpuRam = XI_TRANSPORT_DATA_TARGET_GPU_RAM,
+        Unified = XI_TRANSPORT_DATA_TARGET_UNIFIED,
+        ZeroCopy = XI_TRANSPORT_DATA_TARGET_ZEROCOPY,
+    }
+
+    /// Data move policy of the acquisition buffers, see
+    /// [buffer_policy](crate::Camera::buffer_policy)
+    BufferPolicy: XI_BP {
+        Unsafe = XI_BP_UNSAFE,
+        Safe = XI_BP_SAFE,
+    }
+
+    /// Amount of debug output of xiAPI, see [set_debug_level](crate::set_debug_level)
+    DebugLevel: XI_DEBUG_LEVEL {
+        Detail = XI_DL_DETAIL,
+        Trace = XI_DL_TRACE,
+        Warning = XI_DL_WARNING,
+        Error = XI_DL_ERROR,
+        Fatal = XI_DL_FATAL,
+        Disabled = XI_DL_DISABLED,
+    }
+
+    /// Location of a thermometer in the camera, selects the value of [temp](crate::Camera::temp)
+    TempSelector: XI_TEMP_SELECTOR {
+        ImageSensorDieRaw = XI_TEMP_IMAGE_SENSOR_DIE_RAW,
+        ImageSensorDie = XI_TEMP_IMAGE_SENSOR_DIE,
+        SensorBoard = XI_TEMP_SENSOR_BOARD,
+        InterfaceBoard = XI_TEMP_INTERFACE_BOARD,
+        FrontHousing = XI_TEMP_FRONT_HOUSING,
+        RearHousing = XI_TEMP_REAR_HOUSING,
+        Tec1Cold = XI_TEMP_TEC1_COLD,
+        Tec1Hot = XI_TEMP_TEC1_HOT,
+    }
+
+    /// Predefined configuration of the camera, see [load_user_set](crate::Camera::load_user_set)
+    UserSetSelector: XI_USER_SET_SELECTOR {
+        Std12Low = XI_US_12_STD_L,
+        Std12High = XI_US_12_STD_H,
+        Std14Low = XI_US_14_STD_L,
+        Std14High = XI_US_14_STD_H,
+        CmsSum2x12Low = XI_US_2_12_CMS_S_L,
+        CmsSum2x12High = XI_US_2_12_CMS_S_H,
+        CmsSum2x14Low = XI_US_2_14_CMS_S_L,
+        CmsSum2x14High = XI_US_2_14_CMS_S_H,
+        CmsSum4x12Low = XI_US_4_12_CMS_S_L,
+        CmsSum4x12High = XI_US_4_12_CMS_S_H,
+        CmsSum4x14Low = XI_US_4_14_CMS_S_L,
+        CmsSum4x14High = XI_US_4_14_CMS_S_H,
+        Hdr2x12HighLow = XI_US_2_12_HDR_HL,
+        Hdr2x12Low = XI_US_2_12_HDR_L,
+        Hdr2x12High = XI_US_2_12_HDR_H,
+        CmsHdr4x12HighLow = XI_US_4_12_CMS_HDR_HL,
+        Hdr2x14Low = XI_US_2_14_HDR_L,
+        Hdr2x14High = XI_US_2_14_HDR_H,
+        CmsAvg2x12Low = XI_US_2_12_CMS_A_L,
+        CmsAvg2x12High = XI_US_2_12_CMS_A_H,
+        None = XI_US_NONE,
+    }
+}
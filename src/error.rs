@@ -0,0 +1,364 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::sys::XI_RET::*;
+use crate::sys::{XI_RET, XI_RETURN};
+
+use crate::ImageFormat;
+
+/// Return code used by the bindings if the xiAPI library could not be loaded at runtime.
+/// This value is never returned by xiAPI itself.
+pub(crate) const XI_LIBRARY_NOT_FOUND: XI_RETURN = -1;
+
+/// This macro generates the [XiError] enum from a list of xiAPI return codes.
+/// The description of each code is used both as documentation and as [Display] output.
+macro_rules! xi_error {
+    (
+        $($variant:ident = $code:ident => $desc:literal,)*
+    ) => {
+        /// Error returned by xiAPI.
+        ///
+        /// Every error code defined in `XI_RET` is mapped to a named variant.
+        /// Codes that are unknown to this version of the bindings (e.g. when using a newer xiAPI)
+        /// are reported as [XiError::Unknown].
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum XiError {
+            $(
+                #[doc = $desc]
+                $variant,
+            )*
+            /// The xiAPI library could not be loaded (only with the `runtime-loading` feature)
+            LibraryNotFound,
+            /// An error code that is not known to these bindings
+            Unknown(XI_RETURN),
+            /// A parameter value that is not known to these bindings (e.g. when it is reported by
+            /// a newer camera firmware). Its code is `XI_WRONG_PARAM_VALUE`.
+            UnknownValue(u32),
+            /// An error that occurred while accessing a parameter.
+            /// Contains the parameter name and the attempted value in addition to the error itself.
+            Param(Box<ParamError>),
+            /// The format of an image can not be read as the requested pixel type (see
+            /// [PixelFormat](crate::PixelFormat)). Its code is `XI_NOT_SUPPORTED_DATA_FORMAT`.
+            PixelFormatMismatch {
+                /// Format of the image
+                format: ImageFormat,
+                /// Name of the requested pixel type
+                pixel_type: &'static str,
+            },
+        }
+
+        impl XiError {
+            /// Convert a raw xiAPI return code to an error.
+            ///
+            /// `XI_OK` is not an error and is reported as [XiError::Unknown].
+            pub fn from_code(code: XI_RETURN) -> Self {
+                if code == XI_LIBRARY_NOT_FOUND {
+                    return XiError::LibraryNotFound;
+                }
+                match code as XI_RET::Type {
+                    $($code => XiError::$variant,)*
+                    _ => XiError::Unknown(code),
+                }
+            }
+
+            /// The raw xiAPI return code of this error
+            pub fn code(&self) -> XI_RETURN {
+                match self {
+                    $(XiError::$variant => $code as XI_RETURN,)*
+                    XiError::LibraryNotFound => XI_LIBRARY_NOT_FOUND,
+                    XiError::Unknown(code) => *code,
+                    XiError::UnknownValue(_) => XI_WRONG_PARAM_VALUE as XI_RETURN,
+                    XiError::Param(err) => err.error.code(),
+                    XiError::PixelFormatMismatch { .. } => XI_NOT_SUPPORTED_DATA_FORMAT as XI_RETURN,
+                }
+            }
+
+            /// Description of this error as given in the xiAPI documentation
+            pub fn description(&self) -> &'static str {
+                match self {
+                    $(XiError::$variant => $desc,)*
+                    XiError::LibraryNotFound => "xiAPI library could not be loaded",
+                    XiError::Unknown(_) => "Unknown error",
+                    XiError::UnknownValue(_) => "Value is not known to these bindings",
+                    XiError::Param(err) => err.error.description(),
+                    XiError::PixelFormatMismatch { .. } => {
+                        "Image format does not match the requested pixel type"
+                    }
+                }
+            }
+
+            /// The underlying error without any parameter context
+            ///
+            /// This is useful to match on the reason for an error, regardless of which parameter
+            /// caused it.
+            pub fn root(&self) -> &XiError {
+                match self {
+                    XiError::Param(err) => err.error.root(),
+                    _ => self,
+                }
+            }
+        }
+    };
+}
+
+xi_error! {
+    InvalidHandle = XI_INVALID_HANDLE => "Invalid handle",
+    ReadReg = XI_READREG => "Register read error",
+    WriteReg = XI_WRITEREG => "Register write error",
+    FreeResources = XI_FREE_RESOURCES => "Freeing resources error",
+    FreeChannel = XI_FREE_CHANNEL => "Freeing channel error",
+    FreeBandwidth = XI_FREE_BANDWIDTH => "Freeing bandwidth error",
+    ReadBlock = XI_READBLK => "Read block error",
+    WriteBlock = XI_WRITEBLK => "Write block error",
+    NoImage = XI_NO_IMAGE => "No image",
+    Timeout = XI_TIMEOUT => "Timeout",
+    InvalidArg = XI_INVALID_ARG => "Invalid arguments supplied",
+    NotSupported = XI_NOT_SUPPORTED => "Not supported",
+    IsochAttachBuffers = XI_ISOCH_ATTACH_BUFFERS => "Attach buffers error",
+    GetOverlappedResult = XI_GET_OVERLAPPED_RESULT => "Overlapped result",
+    MemoryAllocation = XI_MEMORY_ALLOCATION => "Memory allocation error",
+    DllContextIsNull = XI_DLLCONTEXTISNULL => "DLL context is NULL",
+    DllContextIsNonZero = XI_DLLCONTEXTISNONZERO => "DLL context is non zero",
+    DllContextExist = XI_DLLCONTEXTEXIST => "DLL context exists",
+    TooManyDevices = XI_TOOMANYDEVICES => "Too many devices connected",
+    CameraContext = XI_ERRORCAMCONTEXT => "Camera context error",
+    UnknownHardware = XI_UNKNOWN_HARDWARE => "Unknown hardware",
+    InvalidTmFile = XI_INVALID_TM_FILE => "Invalid TM file",
+    InvalidTmTag = XI_INVALID_TM_TAG => "Invalid TM tag",
+    IncompleteTm = XI_INCOMPLETE_TM => "Incomplete TM",
+    BusResetFailed = XI_BUS_RESET_FAILED => "Bus reset error",
+    NotImplemented = XI_NOT_IMPLEMENTED => "Not implemented",
+    ShadingTooBright = XI_SHADING_TOOBRIGHT => "Shading is too bright",
+    ShadingTooDark = XI_SHADING_TOODARK => "Shading is too dark",
+    TooLowGain = XI_TOO_LOW_GAIN => "Gain is too low",
+    InvalidBpl = XI_INVALID_BPL => "Invalid sensor defect correction list",
+    BplRealloc = XI_BPL_REALLOC => "Error while sensor defect correction list reallocation",
+    InvalidPixelList = XI_INVALID_PIXEL_LIST => "Invalid pixel list",
+    InvalidFfs = XI_INVALID_FFS => "Invalid Flash File System",
+    InvalidProfile = XI_INVALID_PROFILE => "Invalid profile",
+    InvalidCalibration = XI_INVALID_CALIBRATION => "Invalid calibration",
+    InvalidBuffer = XI_INVALID_BUFFER => "Invalid buffer",
+    InvalidData = XI_INVALID_DATA => "Invalid data",
+    TimingGeneratorBusy = XI_TGBUSY => "Timing generator is busy",
+    IoWrong = XI_IO_WRONG => "Wrong operation open/write/read/close",
+    AcquisitionAlreadyUp = XI_ACQUISITION_ALREADY_UP => "Acquisition already started",
+    OldDriverVersion = XI_OLD_DRIVER_VERSION => "Old version of device driver installed to the system",
+    GetLastError = XI_GET_LAST_ERROR => "To get error code please call GetLastError function",
+    CantProcess = XI_CANT_PROCESS => "Data cannot be processed",
+    AcquisitionStopped = XI_ACQUISITION_STOPED => "Acquisition is stopped. It needs to be started to perform operation",
+    AcquisitionStoppedWithError = XI_ACQUISITION_STOPED_WERR => "Acquisition has been stopped with an error",
+    InvalidInputIccProfile = XI_INVALID_INPUT_ICC_PROFILE => "Input ICC profile missing or corrupted",
+    InvalidOutputIccProfile = XI_INVALID_OUTPUT_ICC_PROFILE => "Output ICC profile missing or corrupted",
+    DeviceNotReady = XI_DEVICE_NOT_READY => "Device not ready to operate",
+    ShadingTooContrast = XI_SHADING_TOOCONTRAST => "Shading is too contrast",
+    AlreadyInitialized = XI_ALREADY_INITIALIZED => "Module already initialized",
+    NotEnoughPrivileges = XI_NOT_ENOUGH_PRIVILEGES => "Application does not have enough privileges (one or more app)",
+    NotCompatibleDriver = XI_NOT_COMPATIBLE_DRIVER => "Installed driver is not compatible with current software",
+    TmInvalidResource = XI_TM_INVALID_RESOURCE => "TM file was not loaded successfully from resources",
+    DeviceHasBeenReset = XI_DEVICE_HAS_BEEN_RESETED => "Device has been reset, abnormal initial state",
+    NoDevicesFound = XI_NO_DEVICES_FOUND => "No devices found",
+    ResourceOrFunctionLocked = XI_RESOURCE_OR_FUNCTION_LOCKED => "Resource (device) or function locked by mutex",
+    BufferSizeTooSmall = XI_BUFFER_SIZE_TOO_SMALL => "Buffer provided by user is too small",
+    CouldNotInitProcessor = XI_COULDNT_INIT_PROCESSOR => "Could not initialize processor",
+    NotInitialized = XI_NOT_INITIALIZED => "The object/module/procedure/process being referred to has not been started",
+    ResourceNotFound = XI_RESOURCE_NOT_FOUND => "Resource not found (could be processor, file, item...)",
+    UnknownParam = XI_UNKNOWN_PARAM => "Unknown parameter",
+    WrongParamValue = XI_WRONG_PARAM_VALUE => "Wrong parameter value",
+    WrongParamType = XI_WRONG_PARAM_TYPE => "Wrong parameter type",
+    WrongParamSize = XI_WRONG_PARAM_SIZE => "Wrong parameter size",
+    BufferTooSmall = XI_BUFFER_TOO_SMALL => "Input buffer is too small",
+    NotSupportedParam = XI_NOT_SUPPORTED_PARAM => "Parameter is not supported",
+    NotSupportedParamInfo = XI_NOT_SUPPORTED_PARAM_INFO => "Parameter info not supported",
+    NotSupportedDataFormat = XI_NOT_SUPPORTED_DATA_FORMAT => "Data format is not supported",
+    ReadOnlyParam = XI_READ_ONLY_PARAM => "Read only parameter",
+    BandwidthNotSupported = XI_BANDWIDTH_NOT_SUPPORTED => "This camera does not support currently available bandwidth",
+    InvalidFfsFileName = XI_INVALID_FFS_FILE_NAME => "FFS file selector is invalid or NULL",
+    FfsFileNotFound = XI_FFS_FILE_NOT_FOUND => "FFS file not found",
+    ParamNotSettable = XI_PARAM_NOT_SETTABLE => "Parameter value cannot be set (might be out of range or invalid)",
+    SafePolicyNotSupported = XI_SAFE_POLICY_NOT_SUPPORTED => "Safe buffer policy is not supported. E.g. when transport target is set to GPU (GPUDirect)",
+    GpuDirectNotAvailable = XI_GPUDIRECT_NOT_AVAILABLE => "GPUDirect is not available. E.g. platform isn't supported or CUDA toolkit isn't installed",
+    IncorrectSensorIdCheck = XI_INCORRECT_SENS_ID_CHECK => "Incorrect sensor board unique identifier checksum",
+    IncorrectFpgaType = XI_INCORRECT_FPGA_TYPE => "Incorrect or unknown FPGA firmware type used for camera",
+    ParamConditionallyNotAvailable = XI_PARAM_CONDITIONALLY_NOT_AVAILABLE => "Parameter is not available in current context. Available only if another feature is turned on",
+    FrameBufferRamInit = XI_ERR_FRAME_BUFFER_RAM_INIT => "Frame buffer RAM initialization error",
+    ProcOtherError = XI_PROC_OTHER_ERROR => "Processing error - other",
+    ProcProcessingError = XI_PROC_PROCESSING_ERROR => "Error while image processing",
+    ProcInputFormatUnsupported = XI_PROC_INPUT_FORMAT_UNSUPPORTED => "Input format is not supported for processing",
+    ProcOutputFormatUnsupported = XI_PROC_OUTPUT_FORMAT_UNSUPPORTED => "Output format is not supported for processing",
+    OutOfRange = XI_OUT_OF_RANGE => "Parameter value is out of range",
+}
+
+impl From<XI_RETURN> for XiError {
+    fn from(code: XI_RETURN) -> Self {
+        XiError::from_code(code)
+    }
+}
+
+impl From<XiError> for XI_RETURN {
+    fn from(err: XiError) -> Self {
+        err.code()
+    }
+}
+
+impl XiError {
+    /// Add the parameter context to this error
+    pub(crate) fn with_param<T: Debug>(
+        self,
+        param: &[u8],
+        operation: ParamOperation,
+        value: Option<&T>,
+    ) -> XiError {
+        let param = String::from_utf8_lossy(param)
+            .trim_matches(char::from(0))
+            .to_string();
+        XiError::Param(Box::new(ParamError {
+            param,
+            operation,
+            value: value.map(|v| format!("{:?}", v)),
+            error: self,
+        }))
+    }
+}
+
+impl Display for XiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XiError::Param(err) => Display::fmt(err, f),
+            XiError::UnknownValue(value) => write!(f, "{} ({})", self.description(), value),
+            XiError::PixelFormatMismatch { format, pixel_type } => write!(
+                f,
+                "{} ({:?} can not be read as {})",
+                self.description(),
+                format,
+                pixel_type
+            ),
+            _ => write!(f, "{} (xiAPI error {})", self.description(), self.code()),
+        }
+    }
+}
+
+impl std::error::Error for XiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            XiError::Param(err) => Some(&err.error),
+            _ => None,
+        }
+    }
+}
+
+/// Error of an operation that consumes `T`, e.g. [Camera::start_acquisition](crate::Camera::start_acquisition).
+///
+/// The consumed object is returned together with the error, so it can still be used, e.g. to
+/// retry starting the acquisition after changing the bandwidth or buffer settings.
+/// The error converts into an [XiError] (dropping the object), so it can be propagated with `?`.
+pub struct AcquisitionError<T> {
+    error: XiError,
+    inner: T,
+}
+
+impl<T> AcquisitionError<T> {
+    pub(crate) fn new(error: XiError, inner: T) -> Self {
+        Self { error, inner }
+    }
+
+    /// The error that occurred
+    pub fn error(&self) -> &XiError {
+        &self.error
+    }
+
+    /// Returns the object that was consumed by the failed operation, discarding the error
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns the error and the object that was consumed by the failed operation
+    pub fn into_parts(self) -> (XiError, T) {
+        (self.error, self.inner)
+    }
+}
+
+impl<T> From<AcquisitionError<T>> for XiError {
+    fn from(err: AcquisitionError<T>) -> Self {
+        err.error
+    }
+}
+
+impl<T> Debug for AcquisitionError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AcquisitionError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for AcquisitionError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<T> std::error::Error for AcquisitionError<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Operation on a parameter that caused a [ParamError]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamOperation {
+    /// Reading the parameter value
+    Get,
+    /// Writing the parameter value
+    Set,
+    /// Reading the minimum of the parameter
+    Minimum,
+    /// Reading the maximum of the parameter
+    Maximum,
+    /// Reading the increment of the parameter
+    Increment,
+}
+
+impl Display for ParamOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ParamOperation::Get => "get",
+            ParamOperation::Set => "set",
+            ParamOperation::Minimum => "min",
+            ParamOperation::Maximum => "max",
+            ParamOperation::Increment => "inc",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Context of an error that occurred while accessing a parameter.
+///
+/// The error is displayed as e.g. `set offsetY=1033 failed: Parameter value is out of range`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParamError {
+    /// Name of the parameter as used by xiAPI (see `XI_PRM_*`)
+    pub param: String,
+
+    /// The operation that failed
+    pub operation: ParamOperation,
+
+    /// The value that was rejected by the camera (only for [ParamOperation::Set])
+    pub value: Option<String>,
+
+    /// The error returned by xiAPI
+    pub error: XiError,
+}
+
+impl Display for ParamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{} {}={} failed: ", self.operation, self.param, value)?,
+            None => write!(f, "{} {} failed: ", self.operation, self.param)?,
+        }
+        Display::fmt(&self.error, f)
+    }
+}
@@ -0,0 +1,225 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use crate::sys::XI_IMG;
+#[cfg(feature = "image")]
+use image::{DynamicImage, ImageBuffer};
+
+use crate::ColorFilterArray;
+use crate::Image;
+use crate::ImageFormat;
+use crate::PixelFormat;
+use crate::XiError;
+use crate::{Bgr16, Bgr8, Bgra16, Bgra8};
+
+/// Image with a pixel type that is determined at runtime from its format.
+///
+/// A DynamicFrame is returned by
+/// [AcquisitionBuffer::next_image_dynamic](crate::AcquisitionBuffer::next_image_dynamic) and is
+/// useful if the [image_data_format](crate::Camera::image_data_format) changes at runtime,
+/// similar to the `DynamicImage` of the `image` crate.
+/// Raw sensor data additionally contains the color filter array of the sensor.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use xiapi::{DynamicFrame, ImageFormat};
+/// # fn main() -> Result<(), xiapi::XiError> {
+///     let mut cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+///     cam.set_image_data_format(ImageFormat::Mono16)?;
+///     let buffer = cam.start_acquisition()?;
+///     match buffer.next_image_dynamic(None)? {
+///         DynamicFrame::Mono8(image) => println!("8 bit image {}", image.nframe()),
+///         DynamicFrame::Mono16(image) => println!("16 bit image {}", image.nframe()),
+///         frame => println!("Image in format {:?}", frame.format()),
+///     }
+/// #   Ok(())
+/// # }
+/// ```
+#[non_exhaustive]
+pub enum DynamicFrame<'a> {
+    /// [ImageFormat::Mono8]
+    Mono8(Image<'a, u8>),
+    /// [ImageFormat::Mono16]
+    Mono16(Image<'a, u16>),
+    /// [ImageFormat::Raw8]
+    Raw8 {
+        /// Raw sensor data
+        image: Image<'a, u8>,
+        /// Color filter array of the sensor, `None` if it is unknown to these bindings
+        cfa: Option<ColorFilterArray>,
+    },
+    /// [ImageFormat::Raw16]
+    Raw16 {
+        /// Raw sensor data
+        image: Image<'a, u16>,
+        /// Color filter array of the sensor, `None` if it is unknown to these bindings
+        cfa: Option<ColorFilterArray>,
+    },
+    /// [ImageFormat::Raw32]
+    Raw32 {
+        /// Raw sensor data
+        image: Image<'a, u32>,
+        /// Color filter array of the sensor, `None` if it is unknown to these bindings
+        cfa: Option<ColorFilterArray>,
+    },
+    /// [ImageFormat::Raw32Float]
+    Float32 {
+        /// Raw sensor data
+        image: Image<'a, f32>,
+        /// Color filter array of the sensor, `None` if it is unknown to these bindings
+        cfa: Option<ColorFilterArray>,
+    },
+    /// [ImageFormat::Rgb24]
+    Rgb24(Image<'a, Bgr8>),
+    /// [ImageFormat::Rgb32]
+    Rgb32(Image<'a, Bgra8>),
+    /// [ImageFormat::Rgb48]
+    Rgb48(Image<'a, Bgr16>),
+    /// [ImageFormat::Rgb64]
+    Rgb64(Image<'a, Bgra16>),
+}
+
+/// Evaluate `$body` with `$image` bound to the image of any variant of a [DynamicFrame]
+macro_rules! with_image {
+    ($frame:expr, $image:ident => $body:expr) => {
+        match $frame {
+            DynamicFrame::Mono8($image) => $body,
+            DynamicFrame::Mono16($image) => $body,
+            DynamicFrame::Raw8 { image: $image, .. } => $body,
+            DynamicFrame::Raw16 { image: $image, .. } => $body,
+            DynamicFrame::Raw32 { image: $image, .. } => $body,
+            DynamicFrame::Float32 { image: $image, .. } => $body,
+            DynamicFrame::Rgb24($image) => $body,
+            DynamicFrame::Rgb32($image) => $body,
+            DynamicFrame::Rgb48($image) => $body,
+            DynamicFrame::Rgb64($image) => $body,
+        }
+    };
+}
+
+impl<'a> DynamicFrame<'a> {
+    /// Wrap an image returned by xiAPI according to its format
+    pub(crate) fn from_xi_img(xi_img: XI_IMG) -> Result<Self, XiError> {
+        let cfa = || ColorFilterArray::try_from(xi_img.color_filter_array).ok();
+        let frame = match ImageFormat::try_from(xi_img.frm)? {
+            ImageFormat::Mono8 => DynamicFrame::Mono8(Image::from_xi_img(xi_img)?),
+            ImageFormat::Mono16 => DynamicFrame::Mono16(Image::from_xi_img(xi_img)?),
+            ImageFormat::Raw8 => DynamicFrame::Raw8 {
+                image: Image::from_xi_img(xi_img)?,
+                cfa: cfa(),
+            },
+            ImageFormat::Raw16 => DynamicFrame::Raw16 {
+                image: Image::from_xi_img(xi_img)?,
+                cfa: cfa(),
+            },
+            ImageFormat::Raw32 => DynamicFrame::Raw32 {
+                image: Image::from_xi_img(xi_img)?,
+                cfa: cfa(),
+            },
+            ImageFormat::Raw32Float => DynamicFrame::Float32 {
+                image: Image::from_xi_img(xi_img)?,
+                cfa: cfa(),
+            },
+            ImageFormat::Rgb24 => DynamicFrame::Rgb24(Image::from_xi_img(xi_img)?),
+            ImageFormat::Rgb32 => DynamicFrame::Rgb32(Image::from_xi_img(xi_img)?),
+            ImageFormat::Rgb48 => DynamicFrame::Rgb48(Image::from_xi_img(xi_img)?),
+            ImageFormat::Rgb64 => DynamicFrame::Rgb64(Image::from_xi_img(xi_img)?),
+            _ => return Err(XiError::NotSupportedDataFormat),
+        };
+        Ok(frame)
+    }
+
+    fn xi_img(&self) -> &XI_IMG {
+        with_image!(self, image => &image.xi_img)
+    }
+
+    /// Format of the image data
+    pub fn format(&self) -> ImageFormat {
+        match self {
+            DynamicFrame::Mono8(_) => ImageFormat::Mono8,
+            DynamicFrame::Mono16(_) => ImageFormat::Mono16,
+            DynamicFrame::Raw8 { .. } => ImageFormat::Raw8,
+            DynamicFrame::Raw16 { .. } => ImageFormat::Raw16,
+            DynamicFrame::Raw32 { .. } => ImageFormat::Raw32,
+            DynamicFrame::Float32 { .. } => ImageFormat::Raw32Float,
+            DynamicFrame::Rgb24(_) => ImageFormat::Rgb24,
+            DynamicFrame::Rgb32(_) => ImageFormat::Rgb32,
+            DynamicFrame::Rgb48(_) => ImageFormat::Rgb48,
+            DynamicFrame::Rgb64(_) => ImageFormat::Rgb64,
+        }
+    }
+
+    /// Color filter array of the sensor, only for raw sensor data with a known color filter array
+    pub fn color_filter_array(&self) -> Option<ColorFilterArray> {
+        match self {
+            DynamicFrame::Raw8 { cfa, .. }
+            | DynamicFrame::Raw16 { cfa, .. }
+            | DynamicFrame::Raw32 { cfa, .. }
+            | DynamicFrame::Float32 { cfa, .. } => *cfa,
+            _ => None,
+        }
+    }
+
+    /// Get the width of this image in pixels
+    pub fn width(&self) -> u32 {
+        self.xi_img().width
+    }
+
+    /// Get the height of this image
+    pub fn height(&self) -> u32 {
+        self.xi_img().height
+    }
+
+    /// Frame number
+    pub fn nframe(&self) -> u32 {
+        self.xi_img().nframe
+    }
+
+    /// Raw 64-bit timestamp from the camera. See [Image::timestamp_raw]
+    pub fn timestamp_raw(&self) -> u64 {
+        with_image!(self, image => image.timestamp_raw())
+    }
+
+    /// Convert to an [Image] with the pixel type `T`.
+    ///
+    /// Fails with [XiError::PixelFormatMismatch] if the format can not be read as `T` (see
+    /// [PixelFormat]).
+    pub fn into_image<T: PixelFormat>(self) -> Result<Image<'a, T>, XiError> {
+        Image::from_xi_img(*self.xi_img())
+    }
+
+    /// Convert to a `DynamicImage` of the `image` crate.
+    ///
+    /// Monochrome images and raw sensor data with 8 or 16 bits are converted to grayscale images,
+    /// color images are converted to RGB(A) images.
+    /// Returns `None` for formats that have no equivalent in the `image` crate and for
+    /// uninitialized images.
+    #[cfg(feature = "image")]
+    pub fn into_dynamic_image(self) -> Option<DynamicImage> {
+        let image = match self {
+            DynamicFrame::Mono8(image) | DynamicFrame::Raw8 { image, .. } => {
+                DynamicImage::ImageLuma8(ImageBuffer::try_from(image).ok()?)
+            }
+            DynamicFrame::Mono16(image) | DynamicFrame::Raw16 { image, .. } => {
+                DynamicImage::ImageLuma16(ImageBuffer::try_from(image).ok()?)
+            }
+            DynamicFrame::Rgb24(image) => {
+                DynamicImage::ImageRgb8(ImageBuffer::try_from(image).ok()?)
+            }
+            DynamicFrame::Rgb32(image) => {
+                DynamicImage::ImageRgba8(ImageBuffer::try_from(image).ok()?)
+            }
+            DynamicFrame::Rgb48(image) => {
+                DynamicImage::ImageRgb16(ImageBuffer::try_from(image).ok()?)
+            }
+            DynamicFrame::Rgb64(image) => {
+                DynamicImage::ImageRgba16(ImageBuffer::try_from(image).ok()?)
+            }
+            _ => return None,
+        };
+        Some(image)
+    }
+}
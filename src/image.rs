@@ -2,21 +2,132 @@
  * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
  */
 
+use std::any::type_name;
+use std::marker::PhantomData;
 use std::mem::size_of;
 use std::slice::from_raw_parts;
 
 #[cfg(feature = "image")]
-use image::{ImageBuffer, Pixel};
+use image::{ImageBuffer, Luma, Pixel, Rgb, Rgba};
 
-use xiapi_sys::XI_IMG;
+use crate::sys::XI_IMG;
+
+use crate::ImageFormat;
+use crate::PixelFormat;
+use crate::XiError;
+#[cfg(feature = "image")]
+use crate::{Bgr16, Bgr8, Bgra16, Bgra8};
+
+/// This macro generates the accessors for the metadata in the `xi_img` header of an image
+macro_rules! header_accessors {
+    () => {
+        /// Get the width of this image in pixels
+        pub fn width(&self) -> u32 {
+            self.xi_img.width
+        }
+
+        /// Get the height of this image
+        pub fn height(&self) -> u32 {
+            self.xi_img.height
+        }
+
+        /// Format of image data
+        pub fn format(&self) -> crate::sys::XI_IMG_FORMAT::Type {
+            self.xi_img.frm
+        }
+
+        /// Frame number
+        pub fn nframe(&self) -> u32 {
+            self.xi_img.nframe
+        }
+
+        /// Image black level
+        pub fn black_level(&self) -> u32 {
+            self.xi_img.black_level
+        }
+
+        /// Number of extra bytes provided at the end of each line for alignment
+        pub fn padding_x(&self) -> u32 {
+            self.xi_img.padding_x
+        }
+
+        /// Horizontal offset from the origin of the sensor to the first pixel in this image
+        pub fn absolute_offset_x(&self) -> u32 {
+            self.xi_img.AbsoluteOffsetX
+        }
+
+        /// Vertical offset from the origin of the sensor to the first line in this image
+        pub fn absolute_offset_y(&self) -> u32 {
+            self.xi_img.AbsoluteOffsetY
+        }
+
+        /// Current format of the pixels on transport layer
+        pub fn transport_format(&self) -> crate::sys::XI_IMG_FORMAT::Type {
+            self.xi_img.transport_frm
+        }
+
+        /// Horizontal downsampling
+        pub fn downsampling_x(&self) -> u32 {
+            self.xi_img.DownsamplingX
+        }
+
+        /// Vertical downsampling
+        pub fn downsampling_y(&self) -> u32 {
+            self.xi_img.DownsamplingY
+        }
+
+        /// Exposure time for this image in us
+        pub fn exposure_time_us(&self) -> u32 {
+            self.xi_img.exposure_time_us
+        }
+
+        /// Aquisition Frame Number. Reset only on acquisition start.
+        pub fn acq_nframe(&self) -> u32 {
+            self.xi_img.acq_nframe
+        }
+
+        /// Image user data which can be set using
+        /// [Camera::set_image_user_data](crate::Camera::set_image_user_data)
+        pub fn image_user_data(&self) -> u32 {
+            self.xi_img.image_user_data
+        }
+
+        /// Raw 64-bit timestamp from the camera. Interpretation of this value differs between camera series.
+        /// xiQ, xiD: 40-bit microsecond number - (overlaps after 305 hours)
+        /// xiC, xiB, xiT, xiX: 64-bit 4 nanosecond number (overlaps after 2339 years)
+        pub fn timestamp_raw(&self) -> u64 {
+            let high = self.xi_img.tsSec as u64;
+            let low = self.xi_img.tsUSec as u64;
+            (high << 32) | low
+        }
+    };
+}
 
 /// An Image as it is captured by the camera.
 pub struct Image<'a, T> {
     pub(crate) xi_img: XI_IMG,
-    pub(crate) pix_type: std::marker::PhantomData<&'a T>,
+    pub(crate) pix_type: PhantomData<&'a T>,
+    /// Number of values of type `T` per pixel, see [PixelFormat::channels]
+    pub(crate) channels: usize,
 }
 
-impl<'a, T> Image<'a, T> {
+impl<'a, T: PixelFormat> Image<'a, T> {
+    /// Wrap an image returned by xiAPI, if its format can be read as `T`
+    pub(crate) fn from_xi_img(xi_img: XI_IMG) -> Result<Self, XiError> {
+        let format = ImageFormat::try_from(xi_img.frm)?;
+        match T::channels(format) {
+            Some(channels) => Ok(Image {
+                xi_img,
+                pix_type: PhantomData,
+                channels,
+            }),
+            None => Err(XiError::PixelFormatMismatch {
+                format,
+                pixel_type: type_name::<T>(),
+            }),
+        }
+    }
+
     /// Get a Pixel from the image.
     ///
     /// # Arguments
@@ -25,6 +136,9 @@ impl<'a, T> Image<'a, T> {
     /// * `y`: Vertical coordinate of the requested pixel.
     ///
     /// returns: Option<&T> A reference to the pixel
+    ///
+    /// For color images read as a pixel type like [Bgr8](crate::Bgr8), this is the whole pixel.
+    /// For color images read as `u8` or `u16`, this is the first (blue) channel of the pixel.
     pub fn pixel(&self, x: usize, y: usize) -> Option<&T> {
         let buffer = self.xi_img.bp as *const u8;
         // Check if uninitialized
@@ -35,154 +149,319 @@ impl<'a, T> Image<'a, T> {
         if x >= self.xi_img.width as usize || y >= self.xi_img.height as usize {
             return None;
         }
-        // stride is the total length of a row in bytes
-        let nb_channels = self.nb_channels();
-        let stride = self.xi_img.width as usize * size_of::<T>() * nb_channels
-            + self.xi_img.padding_x as usize;
-        let offset = (stride * y) + (x * size_of::<T>() * nb_channels);
+        let offset = (self.stride() * y) + (x * size_of::<T>() * self.nb_channels());
         unsafe {
             let pixel_pointer = buffer.add(offset) as *const T;
             pixel_pointer.as_ref()
         }
     }
 
-    /// Get the width of this image in pixels
-    pub fn width(&self) -> u32 {
-        self.xi_img.width
+    header_accessors!();
+
+    /// Get a line of the image, without the padding at its end.
+    ///
+    /// For formats with multiple channels per pixel (e.g. [ImageFormat::Rgb24] as `u8`), the
+    /// line contains all channels of each pixel.
+    /// Returns `None` if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if self.xi_img.bp.is_null() || y >= self.xi_img.height as usize {
+            return None;
+        }
+        Some(self.row_unchecked(y))
     }
 
-    /// Get the height of this image
-    pub fn height(&self) -> u32 {
-        self.xi_img.height
+    /// Iterate over the lines of the image, without the padding at their end. See [Self::row]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        let height = match self.xi_img.bp.is_null() {
+            true => 0,
+            false => self.xi_img.height as usize,
+        };
+        (0..height).map(move |y| self.row_unchecked(y))
     }
 
-    /// Format of image data
-    pub fn format(&self) -> xiapi_sys::XI_IMG_FORMAT::Type {
-        self.xi_img.frm
+    /// Iterate over all pixels of the image, line by line.
+    ///
+    /// Like [Self::pixel], each item refers to the first channel of the pixel for formats with
+    /// multiple channels per value of `T`. Use a pixel type like [Bgr8](crate::Bgr8) to get
+    /// the whole pixels of color images.
+    pub fn pixels(&self) -> impl Iterator<Item = &T> + '_ {
+        let nb_channels = self.nb_channels();
+        self.rows()
+            .flat_map(move |row| row.iter().step_by(nb_channels))
     }
 
-    /// Frame number
-    pub fn nframe(&self) -> u32 {
-        self.xi_img.nframe
+    /// Iterate over all pixels of the image together with their coordinates `(x, y)`.
+    ///
+    /// See [Self::pixels]
+    pub fn enumerate_pixels(&self) -> impl Iterator<Item = (u32, u32, &T)> + '_ {
+        let nb_channels = self.nb_channels();
+        self.rows().enumerate().flat_map(move |(y, row)| {
+            row.iter()
+                .step_by(nb_channels)
+                .enumerate()
+                .map(move |(x, pixel)| (x as u32, y as u32, pixel))
+        })
     }
 
-    /// Image black level
-    pub fn black_level(&self) -> u32 {
-        self.xi_img.black_level
+    /// Length of a line in bytes, including the padding
+    fn stride(&self) -> usize {
+        self.xi_img.width as usize * size_of::<T>() * self.nb_channels()
+            + self.xi_img.padding_x as usize
     }
 
-    /// Number of extra bytes provided at the end of each line for alignment
-    pub fn padding_x(&self) -> u32 {
-        self.xi_img.padding_x
+    /// Get a line of the image, `y` has to be in bounds and the buffer must not be null
+    fn row_unchecked(&self, y: usize) -> &[T] {
+        let length = self.xi_img.width as usize * self.nb_channels();
+        unsafe {
+            let line = (self.xi_img.bp as *const u8).add(self.stride() * y);
+            from_raw_parts(line as *const T, length)
+        }
     }
 
-    /// Horizontal offset from the origin of the sensor to the first pixel in this image
-    pub fn absolute_offset_x(&self) -> u32 {
-        self.xi_img.AbsoluteOffsetX
+    /// Get the raw image data as a slice.
+    ///
+    /// The data includes the padding at the end of each line (see [Self::padding_x]), use
+    /// [Self::rows] to access the lines without padding.
+    /// The data is empty if the image is uninitialized.
+    pub fn data(&'a self) -> &'a [T] {
+        if self.xi_img.bp.is_null() {
+            return &[];
+        }
+        unsafe {
+            if self.xi_img.bp_size != 0 {
+                let length = self.xi_img.bp_size as usize / size_of::<T>();
+                from_raw_parts(self.xi_img.bp as *const T, length)
+            } else {
+                let length =
+                    self.xi_img.width as usize * self.xi_img.height as usize * self.nb_channels();
+                from_raw_parts(self.xi_img.bp as *const T, length)
+            }
+        }
     }
 
-    /// Vertical offset from the origin of the sensor to the first line in this image
-    pub fn absolute_offset_y(&self) -> u32 {
-        self.xi_img.AbsoluteOffsetY
+    /// Copy the image data and its metadata into an [OwnedImage].
+    ///
+    /// The owned image does not borrow the acquisition buffer, so it can be kept or sent to other
+    /// threads while new images are acquired. The padding at the end of each line is removed.
+    pub fn to_owned(&self) -> OwnedImage<T> {
+        let data: Vec<T> = self.rows().flatten().copied().collect();
+        let mut xi_img = self.xi_img;
+        xi_img.bp = std::ptr::null_mut();
+        xi_img.bp_size = (data.len() * size_of::<T>()) as u32;
+        xi_img.padding_x = 0;
+        OwnedImage {
+            xi_img,
+            data,
+            channels: self.channels,
+        }
     }
 
-    /// Current format of the pixels on transport layer
-    pub fn transport_format(&self) -> xiapi_sys::XI_IMG_FORMAT::Type {
-        self.xi_img.transport_frm
+    /// Number of values of type `T` per pixel, see [PixelFormat::channels]
+    fn nb_channels(&self) -> usize {
+        self.channels
     }
+}
 
-    /// Horizontal downsampling
-    pub fn downsampling_x(&self) -> u32 {
-        self.xi_img.DownsamplingX
+unsafe impl<'a, T> Send for Image<'a, T> {}
+
+/// An image that owns a copy of its data, created by [Image::to_owned].
+///
+/// Unlike [Image], an OwnedImage is independent of the
+/// [AcquisitionBuffer](crate::AcquisitionBuffer), it can be cloned and shared between threads.
+/// It contains the same metadata as the image it was copied from, except that its lines have no
+/// padding, so [Self::padding_x] is always 0.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), xiapi::XiError> {
+///     let cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+///     let buffer = cam.start_acquisition()?;
+///     let image = buffer.next_image::<u8>(None)?.to_owned();
+///     let worker = std::thread::spawn(move || image.pixels().map(|&p| p as u64).sum::<u64>());
+///     let next = buffer.next_image::<u8>(None)?;
+///     println!("Sum of the first image: {}", worker.join().unwrap());
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct OwnedImage<T> {
+    /// Metadata of the image, the buffer pointer is always null
+    xi_img: XI_IMG,
+    data: Vec<T>,
+    /// Number of values of type `T` per pixel, see [PixelFormat::channels]
+    channels: usize,
+}
+
+impl<T: PixelFormat> OwnedImage<T> {
+    /// Get a Pixel from the image. See [Image::pixel]
+    pub fn pixel(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= self.xi_img.width as usize {
+            return None;
+        }
+        self.row(y)?.get(x * self.channels)
     }
 
-    /// Vertical downsampling
-    pub fn downsampling_y(&self) -> u32 {
-        self.xi_img.DownsamplingY
+    header_accessors!();
+
+    /// Get a line of the image. See [Image::row]
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        let length = self.xi_img.width as usize * self.channels;
+        self.data.get(y * length..(y + 1) * length)
     }
 
-    /// Exposure time for this image in us
-    pub fn exposure_time_us(&self) -> u32 {
-        self.xi_img.exposure_time_us
+    /// Iterate over the lines of the image. See [Image::rows]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        let length = self.xi_img.width as usize * self.channels;
+        self.data.chunks_exact(length.max(1))
     }
 
-    /// Aquisition Frame Number. Reset only on acquisition start.
-    pub fn acq_nframe(&self) -> u32 {
-        self.xi_img.acq_nframe
+    /// Iterate over all pixels of the image, line by line. See [Image::pixels]
+    pub fn pixels(&self) -> impl Iterator<Item = &T> + '_ {
+        self.data.iter().step_by(self.channels)
     }
 
-    /// Image user data which can be set using [Camera::set_image_user_data]
-    pub fn image_user_data(&self) -> u32 {
-        self.xi_img.image_user_data
+    /// Iterate over all pixels of the image together with their coordinates `(x, y)`.
+    ///
+    /// See [Image::pixels]
+    pub fn enumerate_pixels(&self) -> impl Iterator<Item = (u32, u32, &T)> + '_ {
+        let width = self.xi_img.width.max(1);
+        self.pixels()
+            .enumerate()
+            .map(move |(i, pixel)| (i as u32 % width, i as u32 / width, pixel))
     }
 
-    /// Raw 64-bit timestamp from the camera. Interpretation of this value differs between camera series.
-    /// xiQ, xiD: 40-bit microsecond number - (overlaps after 305 hours)
-    /// xiC, xiB, xiT, xiX: 64-bit 4 nanosecond number (overlaps after 2339 years)
-    pub fn timestamp_raw(&self) -> u64 {
-        let high = self.xi_img.tsSec as u64;
-        let low = self.xi_img.tsUSec as u64;
-        (high << 32) | low
+    /// Get the image data as a slice, without padding
+    pub fn data(&self) -> &[T] {
+        &self.data
     }
 
-    /// Get the raw image data as a slice.
-    pub fn data(&'a self) -> &'a [T] {
-        unsafe {
-            if self.xi_img.bp_size != 0 {
-                let length = self.xi_img.bp_size as usize / size_of::<T>();
-                from_raw_parts(self.xi_img.bp as *const T, length)
-            }
-            else {
-                let length = self.xi_img.width as usize * self.xi_img.height as usize * self.nb_channels();
-                from_raw_parts(self.xi_img.bp as *const T, length)
-            }
+    /// Borrow the owned data as an [Image], e.g. to convert it to an `ImageBuffer`
+    pub fn as_image(&self) -> Image<'_, T> {
+        let mut xi_img = self.xi_img;
+        // The pointer of an empty Vec is dangling, but still non-null and aligned
+        xi_img.bp = self.data.as_ptr() as *mut _;
+        Image {
+            xi_img,
+            pix_type: PhantomData,
+            channels: self.channels,
         }
     }
+}
 
-    fn nb_channels(&self) -> usize
-    {
-        match self.xi_img.frm {
-            xiapi_sys::XI_IMG_FORMAT::XI_MONO8  => 1,
-            xiapi_sys::XI_IMG_FORMAT::XI_MONO16 => 1,
-            xiapi_sys::XI_IMG_FORMAT::XI_RAW8   => 1,
-            xiapi_sys::XI_IMG_FORMAT::XI_RAW16  => 1,
-            xiapi_sys::XI_IMG_FORMAT::XI_RGB24  => 3,
-            xiapi_sys::XI_IMG_FORMAT::XI_RGB32  => 4,
+// The buffer pointer in the metadata is always null, the data is owned by the Vec.
+unsafe impl<T: Send> Send for OwnedImage<T> {}
 
-            _ => 0,
-        }
-    }
+unsafe impl<T: Sync> Sync for OwnedImage<T> {}
+
+/// Conversion of images with this pixel type to an [ImageBuffer] with pixels of type `P`.
+///
+/// Single channel images (e.g. [ImageFormat::Mono8] or [ImageFormat::Raw16] read as `u8` or `u16`)
+/// are converted to [Luma] pixels.
+/// Color images have to be read as a color pixel type (e.g. [Bgr8]) to be converted to the
+/// RGB(A) pixels of the `image` crate, the channels are swapped from the order of the camera to
+/// the order of the `image` crate.
+///
+/// This trait is implemented for all types that implement [PixelFormat].
+#[cfg(feature = "image")]
+pub trait IntoImageBuffer<P: Pixel>: PixelFormat {
+    #[doc(hidden)]
+    fn into_image_buffer(
+        image: &Image<'_, Self>,
+    ) -> Result<ImageBuffer<P, Vec<P::Subpixel>>, XiError>;
+}
+
+/// This macro implements [IntoImageBuffer] for images with a single channel per pixel
+#[cfg(feature = "image")]
+macro_rules! channel_conversion {
+    ($($type:ty,)*) => {
+        $(
+            impl IntoImageBuffer<Luma<$type>> for $type {
+                fn into_image_buffer(
+                    image: &Image<'_, Self>,
+                ) -> Result<ImageBuffer<Luma<$type>, Vec<$type>>, XiError> {
+                    if image.nb_channels() != 1 {
+                        return Err(XiError::PixelFormatMismatch {
+                            format: ImageFormat::try_from(image.format())?,
+                            pixel_type: type_name::<Luma<$type>>(),
+                        });
+                    }
+                    let data: Vec<_> = image.rows().flatten().copied().collect();
+                    ImageBuffer::from_raw(image.width(), image.height(), data)
+                        .ok_or(XiError::NoImage)
+                }
+            }
+        )*
+    };
+}
 
+/// This macro implements [IntoImageBuffer] for color pixels, which are converted with [From]
+#[cfg(feature = "image")]
+macro_rules! color_conversion {
+    ($($type:ty => $pixel:ty,)*) => {
+        $(
+            impl IntoImageBuffer<$pixel> for $type {
+                fn into_image_buffer(
+                    image: &Image<'_, Self>,
+                ) -> Result<ImageBuffer<$pixel, Vec<<$pixel as Pixel>::Subpixel>>, XiError> {
+                    if image.xi_img.bp.is_null() {
+                        return Err(XiError::NoImage);
+                    }
+                    let mut buffer = ImageBuffer::new(image.width(), image.height());
+                    for (x, y, pixel) in image.enumerate_pixels() {
+                        buffer.put_pixel(x, y, (*pixel).into());
+                    }
+                    Ok(buffer)
+                }
+            }
+        )*
+    };
 }
 
-unsafe impl<'a,T> Send for Image<'a, T>{
+#[cfg(feature = "image")]
+channel_conversion! {
+    u8,
+    u16,
+    u32,
+    f32,
+}
 
+#[cfg(feature = "image")]
+color_conversion! {
+    Bgr8 => Rgb<u8>,
+    Bgra8 => Rgba<u8>,
+    Bgr16 => Rgb<u16>,
+    Bgra16 => Rgba<u16>,
 }
 
 #[cfg(feature = "image")]
-impl<P> From<Image<'_, P::Subpixel>> for ImageBuffer<P, Vec<P::Subpixel>>
+impl<P, T> TryFrom<Image<'_, T>> for ImageBuffer<P, Vec<P::Subpixel>>
 where
     P: Pixel,
+    T: IntoImageBuffer<P>,
 {
+    type Error = XiError;
+
     /// Converts the image to an [ImageBuffer]
+    ///
+    /// The padding at the end of each line is removed. Fails with
+    /// [XiError::PixelFormatMismatch] if the image has multiple channels per value of `T` (e.g.
+    /// a color image read as `u8`) and with [XiError::NoImage] if the image is uninitialized.
+    /// See [IntoImageBuffer] for the supported conversions.
     /// ```
     /// # #[serial_test::file_serial]
-    /// # fn main() -> Result<(), xiapi_sys::XI_RETURN>{
+    /// # fn main() -> Result<(), xiapi::XiError>{
     /// # use image::{ImageBuffer, Luma};
     /// # let cam = xiapi::open_device(None)?;
     /// # let buffer = cam.start_acquisition()?;
     /// let image = buffer.next_image::<u8>(None)?;
-    /// let image_buffer = ImageBuffer::<Luma<u8>,_>::from(image);
+    /// let image_buffer = ImageBuffer::<Luma<u8>,_>::try_from(image)?;
     /// image_buffer.save("test.jpg");
     /// # Ok(())
     /// # }
     /// ```
-
-    fn from(image: Image<P::Subpixel>) -> Self {
-        let data = Vec::from(image.data());
-        match Self::from_raw(image.width(), image.height(), data) {
-            None => panic!("Failed to create image from raw pointer"),
-            Some(buffer) => buffer,
-        }
+    fn try_from(image: Image<T>) -> Result<Self, XiError> {
+        T::into_image_buffer(&image)
     }
 }
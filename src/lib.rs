@@ -10,58 +10,1080 @@
 
 #![warn(missing_docs)]
 
+pub use self::backend::default_backend;
+pub use self::backend::set_default_backend;
+pub use self::backend::Backend;
+pub use self::backend::XiApi;
 pub use self::camera::number_devices;
 pub use self::camera::open_device;
+pub use self::camera::open_device_by;
+pub use self::camera::open_device_by_manual_bandwidth;
+pub use self::camera::open_device_by_with_backend;
 pub use self::camera::open_device_manual_bandwidth;
+pub use self::camera::open_device_with_backend;
 pub use self::camera::AcquisitionBuffer;
 pub use self::camera::Camera;
+pub use self::camera::OpenBy;
+pub use self::device_info::enumerate_devices;
+pub use self::device_info::enumerate_devices_with_backend;
+pub use self::device_info::DeviceInfo;
+pub use self::enums::AcqTimingMode;
+pub use self::enums::BitDepth;
+pub use self::enums::BufferPolicy;
+pub use self::enums::ColorFilterArray;
+pub use self::enums::CounterSelector;
+pub use self::enums::DebugLevel;
+pub use self::enums::Downsampling;
+pub use self::enums::DownsamplingType;
+pub use self::enums::GainSelector;
+pub use self::enums::GpiMode;
+pub use self::enums::GpiSelector;
+pub use self::enums::GpoMode;
+pub use self::enums::GpoSelector;
+pub use self::enums::ImageFormat;
+pub use self::enums::LedMode;
+pub use self::enums::LedSelector;
+pub use self::enums::SensorFeatureSelector;
+pub use self::enums::TempSelector;
+pub use self::enums::TestPattern;
+pub use self::enums::TestPatternGenerator;
+pub use self::enums::TransportDataTarget;
+pub use self::enums::TriggerOverlap;
+pub use self::enums::TriggerSelector;
+pub use self::enums::TriggerSource;
+pub use self::enums::UserSetSelector;
+pub use self::error::AcquisitionError;
+pub use self::error::ParamError;
+pub use self::error::ParamOperation;
+pub use self::error::XiError;
+pub use self::frame::DynamicFrame;
 pub use self::image::Image;
+#[cfg(feature = "image")]
+pub use self::image::IntoImageBuffer;
+pub use self::image::OwnedImage;
+pub use self::manifest::Manifest;
+pub use self::manifest::ParamInfo;
+pub use self::manifest::ParamKind;
+pub use self::param_value::ParamValue;
+pub use self::pixel::Bgr16;
+pub use self::pixel::Bgr8;
+pub use self::pixel::Bgra16;
+pub use self::pixel::Bgra8;
+pub use self::pixel::PixelFormat;
+pub use self::range::ParamRange;
+pub use self::range::ParamRangeIter;
+pub use self::range::RangeValue;
 pub use self::roi::Roi;
-pub use xiapi_sys::*;
+pub use self::selected::Selected;
+pub use self::selected::Selector;
+pub use self::settings::CameraSettings;
+pub use self::sim::SensorModel;
+pub use self::sim::SimBackend;
+pub use self::sys::*;
+pub use self::trace::RecordingBackend;
+pub use self::trace::ReplayBackend;
 
+mod backend;
 mod camera;
+mod device_info;
+#[cfg(feature = "runtime-loading")]
+mod dynamic;
+mod enums;
+mod error;
+mod frame;
 mod image;
+mod manifest;
+mod param_value;
+mod pixel;
+mod range;
 mod roi;
+mod selected;
+mod settings;
+mod sim;
+#[cfg(not(feature = "link"))]
+mod sys;
+mod trace;
+/// The vendored definitions are compiled with the `link` feature to compare them to xiapi-sys
+#[cfg(all(test, feature = "link"))]
+#[path = "sys.rs"]
+mod vendored_sys;
+
+#[cfg(feature = "link")]
+use xiapi_sys as sys;
+
+#[cfg(all(feature = "link", feature = "runtime-loading"))]
+compile_error!(
+    "The `link` and `runtime-loading` features are mutually exclusive. To load xiAPI at runtime, \
+     disable the default features: `default-features = false, features = [\"runtime-loading\"]`"
+);
 
 /// Set the debug output level for the whole application
-pub fn set_debug_level(level: XI_DEBUG_LEVEL::Type) -> Result<(), XI_RETURN> {
-    unsafe {
-        use std::ffi::CString;
-        let debug_param_string = CString::new("debug_level").unwrap();
-        match xiSetParamInt(
-            std::ptr::null_mut(),
-            debug_param_string.as_ptr(),
-            level as i32,
-        ) as XI_RET::Type
-        {
-            XI_RET::XI_OK => Ok(()),
-            x => Err(x as XI_RETURN),
-        }
+pub fn set_debug_level(level: DebugLevel) -> Result<(), XiError> {
+    use std::ffi::CString;
+    let debug_param_string = CString::new("debug_level").unwrap();
+    match unsafe {
+        let level = u32::from(level) as i32;
+        default_backend().set_param_int(std::ptr::null_mut(), &debug_param_string, level)
+    } as XI_RET::Type
+    {
+        XI_RET::XI_OK => Ok(()),
+        x => Err(XiError::from(x as XI_RETURN)),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::os::raw::c_char;
+    use crate::Roi;
     use crate::*;
     use approx::assert_abs_diff_eq;
     use serial_test::serial;
     use std::ptr::read_volatile;
-    use XI_DOWNSAMPLING_TYPE::*;
-    use XI_DOWNSAMPLING_VALUE::XI_DWN_1x1;
-    use XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ALL;
-    //use xiapi_sys::XI_TEST_PATTERN_GENERATOR::*;
-    use crate::Roi;
-    use xiapi_sys::XI_IMG_FORMAT::{XI_MONO8, XI_RAW16};
-    use XI_LED_MODE::*;
-    use XI_LED_SELECTOR::*;
-    use XI_TEST_PATTERN::*;
 
     use crate::open_device;
 
+    #[test]
+    #[cfg(not(feature = "link"))]
+    fn library_not_found() {
+        #[cfg(feature = "runtime-loading")]
+        if dynamic::library().is_some() {
+            // The XIMEA software package is installed on this system
+            return;
+        }
+        assert_eq!(number_devices().err(), Some(XiError::LibraryNotFound));
+        assert_eq!(open_device(None).err(), Some(XiError::LibraryNotFound));
+    }
+
+    #[test]
+    fn error_codes() {
+        let err = XiError::from(XI_RET::XI_TIMEOUT as XI_RETURN);
+        assert_eq!(err, XiError::Timeout);
+        assert_eq!(err.code(), 10);
+        assert_eq!(XiError::from(1234), XiError::Unknown(1234));
+        assert_eq!(err.to_string(), "Timeout (xiAPI error 10)");
+        let err = XiError::from(error::XI_LIBRARY_NOT_FOUND);
+        assert_eq!(err, XiError::LibraryNotFound);
+        assert_eq!(err.code(), error::XI_LIBRARY_NOT_FOUND);
+    }
+
+    #[test]
+    fn enum_conversion() {
+        let source = TriggerSource::try_from(XI_TRG_SOURCE::XI_TRG_SOFTWARE);
+        assert_eq!(source, Ok(TriggerSource::Software));
+        assert_eq!(u32::from(LedMode::Blink), XI_LED_MODE::XI_LED_BLINK);
+        assert_eq!(BitDepth::Bpp12 as u32, 12);
+        let err = TriggerSource::try_from(1234).unwrap_err();
+        assert_eq!(err, XiError::UnknownValue(1234));
+        assert_eq!(
+            err.to_string(),
+            "Value is not known to these bindings (1234)"
+        );
+    }
+
+    #[test]
+    fn param_range() {
+        let range = ParamRange::new(16u32, 1000, 16);
+        assert!(range.contains(32));
+        assert!(!range.contains(40));
+        assert!(!range.contains(1008));
+        assert_eq!(range.clamp(2000), 1000);
+        assert_eq!(range.last(), 992);
+        assert_eq!(range.snap_down(40), 32);
+        assert_eq!(range.snap_up(40), 48);
+        assert_eq!(range.snap_nearest(41), 48);
+        assert_eq!(range.snap_up(999), 992);
+        assert_eq!(range.snap_down(0), 16);
+        assert_eq!(range.iter().len(), 62);
+        assert_eq!(range.into_iter().nth(1), Some(32));
+
+        let range = ParamRange::new(-2.0f32, 2.0, 0.1);
+        assert!(range.contains(0.3));
+        assert_abs_diff_eq!(range.snap_nearest(0.33), 0.3, epsilon = 1e-5);
+        assert_abs_diff_eq!(range.snap_down(0.3), 0.3, epsilon = 1e-5);
+        assert_eq!(range.iter().count(), 41);
+        let continuous = ParamRange::new(0.0f32, 1.0, 0.0);
+        assert_eq!(continuous.snap_nearest(0.25), 0.25);
+    }
+
+    #[test]
+    fn sim_param_range() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        let range = cam.width_range()?;
+        assert_eq!(range.minimum, cam.width_minimum()?);
+        assert_eq!(range.increment, 16);
+        assert_eq!(cam.set_width_clamped(650)?, 656);
+        assert_eq!(cam.width()?, 656);
+        assert_eq!(cam.set_exposure_clamped(1e9)?, cam.exposure_maximum()?);
+        let roi = cam.set_roi(&Roi {
+            offset_x: 33,
+            offset_y: 17,
+            width: 250,
+            height: 4000,
+        })?;
+        assert_eq!((roi.offset_x, roi.width), (32, 240));
+        assert_eq!(roi.height, cam.height_range()?.last());
+        Ok(())
+    }
+
+    #[test]
+    fn param_error_context() {
+        let err = XiError::from(XI_RET::XI_OUT_OF_RANGE as XI_RETURN).with_param(
+            XI_PRM_OFFSET_Y,
+            ParamOperation::Set,
+            Some(&1033u32),
+        );
+        assert_eq!(err.root(), &XiError::OutOfRange);
+        assert_eq!(err.code(), XI_RET::XI_OUT_OF_RANGE as XI_RETURN);
+        assert_eq!(
+            err.to_string(),
+            "set offsetY=1033 failed: Parameter value is out of range (xiAPI error 205)"
+        );
+    }
+
+    #[test]
+    fn sim_parameters() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_exposure(12_345.0)?;
+        assert_eq!(cam.exposure()?, 12_345.0);
+        assert_eq!(cam.width_increment()?, 16);
+        cam.set_width(640)?;
+        assert_eq!(cam.offset_x_maximum()?, 640);
+        let err = cam.set_offset_x(1280).unwrap_err();
+        assert_eq!(err.root(), &XiError::OutOfRange);
+        let err = cam.set_width(100).unwrap_err();
+        assert_eq!(err.root(), &XiError::WrongParamValue);
+        cam.set_gain_selector(GainSelector::AnalogAll)?;
+        cam.set_gain(6.0)?;
+        cam.set_gain_selector(GainSelector::All)?;
+        assert_eq!(cam.gain()?, 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_enumerate_devices() -> Result<(), XiError> {
+        let backend = SimBackend::with_models([
+            SensorModel::default(),
+            SensorModel::color(640, 480, XI_COLOR_FILTER_ARRAY::XI_CFA_BAYER_RGGB),
+        ]);
+        let devices = enumerate_devices_with_backend(&backend)?;
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[1].dev_id, 1);
+        assert_eq!(devices[1].name, "SIM-COLOR");
+        assert_eq!(devices[1].serial_number, "SIM00002");
+        assert_eq!(devices[1].instance_path, "sim/1");
+        assert_eq!(devices[1].sensor_id, None);
+        assert_ne!(devices[0].serial_number, devices[1].serial_number);
+
+        // Cameras may reject the sensor model ID with other codes than XI_NOT_SUPPORTED
+        let recorder = RecordingBackend::new(std::sync::Arc::new(backend), Vec::new()).unwrap();
+        enumerate_devices_with_backend(&recorder)?;
+        let trace = String::from_utf8(recorder.into_writer()).unwrap();
+        let not_supported = format!("ret={}", XI_RET::XI_NOT_SUPPORTED);
+        assert!(trace.contains(&not_supported));
+        for code in [XI_RET::XI_NOT_SUPPORTED_PARAM, XI_RET::XI_UNKNOWN_PARAM] {
+            let trace = trace.replace(&not_supported, &format!("ret={}", code));
+            let replay = ReplayBackend::from_reader(trace.as_bytes()).unwrap();
+            let devices = enumerate_devices_with_backend(&replay)?;
+            assert_eq!(devices[0].sensor_id, None);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn sim_open_device_by() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(3));
+        let devices = enumerate_devices_with_backend(backend.as_ref())?;
+        let serial = OpenBy::Serial(&devices[2].serial_number);
+        let cam = open_device_by_with_backend(backend.clone(), serial)?;
+        let err = open_device_with_backend(backend.clone(), Some(2)).err();
+        assert_eq!(err, Some(XiError::ResourceOrFunctionLocked));
+        drop(cam);
+        open_device_by_with_backend(backend.clone(), OpenBy::InstancePath("sim/1"))?;
+        let err = open_device_by_with_backend(backend.clone(), OpenBy::Serial("unknown")).err();
+        assert_eq!(err, Some(XiError::NoDevicesFound));
+        let err = open_device_by_with_backend(backend, OpenBy::UserId("a\0b")).err();
+        assert_eq!(err, Some(XiError::InvalidArg));
+
+        // Identifiers with spaces and `=` can be recorded and replayed
+        let model = SensorModel {
+            user_id: "left cam=1".to_string(),
+            ..SensorModel::default()
+        };
+        let sim = std::sync::Arc::new(SimBackend::with_models([model]));
+        let recorder = std::sync::Arc::new(RecordingBackend::new(sim, Vec::new()).unwrap());
+        drop(open_device_by_with_backend(
+            recorder.clone(),
+            OpenBy::UserId("left cam=1"),
+        )?);
+        let trace = std::sync::Arc::try_unwrap(recorder)
+            .ok()
+            .unwrap()
+            .into_writer();
+        let replay = std::sync::Arc::new(ReplayBackend::from_reader(&trace[..]).unwrap());
+        drop(open_device_by_with_backend(
+            replay.clone(),
+            OpenBy::UserId("left cam=1"),
+        )?);
+        assert_eq!(replay.remaining(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_string_parameters() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(2));
+        let mut cam = open_device_with_backend(backend.clone(), Some(1))?;
+        assert_eq!(cam.device_name()?, "SIM-MONO");
+        // Binary values contain exactly the bytes reported by xiAPI
+        let name = std::ffi::CStr::from_bytes_with_nul(XI_PRM_DEVICE_NAME).unwrap();
+        let bytes = unsafe { <Vec<u8> as camera::ParamType>::get_param(&*backend, *cam, name)? };
+        assert_eq!(bytes, b"SIM-MONO\0");
+        assert_eq!(cam.device_sn()?, "SIM00002");
+        assert_eq!(cam.device_instance_path()?, "sim/1");
+        assert_eq!(cam.api_version()?, env!("CARGO_PKG_VERSION"));
+        assert!(!cam.fpga1_version()?.is_empty());
+        let err = cam.device_sens_sn().unwrap_err();
+        assert_eq!(err.root(), &XiError::NotSupported);
+
+        cam.set_device_user_id("left")?;
+        assert_eq!(cam.device_user_id()?, "left");
+        let err = cam.set_device_user_id("a\0b").unwrap_err();
+        assert_eq!(err.root(), &XiError::InvalidArg);
+        drop(cam);
+        let cam = open_device_by_with_backend(backend, OpenBy::UserId("left"))?;
+        assert_eq!(cam.device_sn()?, "SIM00002");
+        Ok(())
+    }
+
+    #[test]
+    fn sim_dynamic_parameters() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        assert_eq!(cam.get_dynamic("exposure")?, ParamValue::Float(10000.0));
+        assert_eq!(cam.get_dynamic("width")?, ParamValue::Int(1280));
+        assert_eq!(
+            cam.get_dynamic("device_sn")?,
+            ParamValue::String("SIM00001".into())
+        );
+
+        cam.set_dynamic("gain", ParamValue::Float(3.0))?;
+        assert_eq!(cam.gain()?, 3.0);
+        cam.set_dynamic("exposure", 2000)?;
+        assert_eq!(cam.exposure()?, 2000.0);
+        cam.set_dynamic("device_user_id", "left")?;
+        assert_eq!(cam.device_user_id()?, "left");
+
+        let err = cam.get_dynamic("no_such_param").unwrap_err();
+        assert_eq!(err.root(), &XiError::NotSupported);
+        let err = cam.set_dynamic("device_sn", "x").unwrap_err();
+        assert_eq!(err.root(), &XiError::ReadOnlyParam);
+        Ok(())
+    }
+
+    #[test]
+    fn manifest_parsing() -> Result<(), XiError> {
+        let xml = include_str!("../tests/fixtures/device_manifest.xml");
+        let manifest = Manifest::parse(xml)?;
+        assert_eq!(manifest.version.as_deref(), Some("1.0"));
+        assert_eq!(manifest.parameters.len(), 9);
+        let exposure = manifest.parameter("exposure").unwrap();
+        assert_eq!(exposure.kind, Some(ParamKind::Float));
+        assert!(exposure.readable && exposure.writable && exposure.direct_update);
+        assert_eq!(exposure.maximum, Some(ParamValue::Float(1e6)));
+        let width = manifest.parameter("width").unwrap();
+        assert!(!width.direct_update);
+        assert_eq!(width.range::<u32>(), Some(ParamRange::new(32, 1280, 16)));
+        let device_sn = manifest.parameter("device_sn").unwrap();
+        assert!(device_sn.readable && !device_sn.writable);
+        assert_eq!(device_sn.range::<i32>(), None);
+        let timestamp = manifest.parameter("timestamp").unwrap();
+        assert_eq!(timestamp.maximum, Some(ParamValue::Int64(0xFFFF_FFFF_FFFF)));
+        let trigger = manifest.parameter("trigger_software").unwrap();
+        assert!(!trigger.readable && trigger.writable);
+        assert_eq!(
+            manifest.parameter("a&b!").unwrap().kind,
+            Some(ParamKind::Bool)
+        );
+        assert!(!manifest.contains("offsetX"));
+
+        // Only the attributes of parameter elements directly below the root are read, names are
+        // case-insensitive
+        let xml = r#"<Manifest>
+                <Parameter Name="gain" access="W"/>
+                <group><parameter name="width"/></group>
+                <parameter><name>height</name></parameter>
+                <parameter name="exposure" Type="Float" access="ReadOnly" direct_update="1"/>
+            </Manifest>"#;
+        let manifest = Manifest::parse(xml)?;
+        assert_eq!(manifest.parameters.len(), 2);
+        let exposure = manifest.parameter("exposure").unwrap();
+        assert_eq!(exposure.kind, Some(ParamKind::Float));
+        assert!(exposure.readable && !exposure.writable && exposure.direct_update);
+        assert!(!manifest.parameter("gain").unwrap().readable);
+
+        // A manifest without parameters is not understood
+        let invalid = [
+            "",
+            "<a>",
+            "<a></b>",
+            "<a b=c/>",
+            "<a>&unknown;</a>",
+            "<a/><b/>",
+            "<a/>",
+        ];
+        let invalid = invalid
+            .into_iter()
+            .chain(["<manifest/>", "<manifest><group/></manifest>"]);
+        for xml in invalid {
+            assert_eq!(Manifest::parse(xml), Err(XiError::InvalidData), "{xml}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn sim_manifest() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let cam = open_device_with_backend(backend, None)?;
+        assert!(cam.is_supported("exposure")?);
+        assert!(cam.is_supported("device_user_id")?);
+        assert!(!cam.is_supported("hdr")?);
+        let manifest = cam.manifest()?;
+        let gain = manifest.parameter("gain").unwrap();
+        assert_eq!(gain.kind, Some(ParamKind::Float));
+        assert!(gain.direct_update);
+        let available_bandwidth = manifest.parameter("available_bandwidth").unwrap();
+        assert!(!available_bandwidth.writable);
+        let width = manifest.parameter("width").unwrap().range::<u32>().unwrap();
+        assert_eq!(width, cam.width_range()?);
+        let format = manifest.parameter("imgdataformat").unwrap();
+        assert_eq!(format.kind, Some(ParamKind::Enum));
+        Ok(())
+    }
+
+    #[test]
+    fn sim_settings() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(2));
+        let mut cam = open_device_with_backend(backend.clone(), Some(0))?;
+        cam.set_image_data_format(ImageFormat::Mono16)?;
+        cam.set_image_data_bit_depth(BitDepth::Bpp10)?;
+        cam.set_roi(&Roi {
+            offset_x: 64,
+            offset_y: 32,
+            width: 640,
+            height: 480,
+        })?;
+        cam.set_gain_selector(GainSelector::AnalogAll)?;
+        cam.set_gain(6.0)?;
+        cam.set_gain_selector(GainSelector::All)?;
+        cam.set_acq_timing_mode(AcqTimingMode::FrameRate)?;
+        cam.set_framerate(20.0)?;
+        cam.set_exposure(40_000.0)?;
+        cam.set_gpo_selector(GpoSelector::Port2)?;
+        cam.set_gpo_mode(GpoMode::FrameActive)?;
+        cam.set_buffer_policy(BufferPolicy::Safe)?;
+        let settings = cam.snapshot_settings()?;
+        assert_eq!(settings.buffer_policy, Some(BufferPolicy::Safe));
+        assert_eq!(cam.gpo_selector()?, GpoSelector::Port2);
+        assert_eq!(settings.gain.get(&GainSelector::AnalogAll), Some(&6.0));
+        assert_eq!(settings.gpo_mode.len(), 2);
+        assert_eq!(settings.framerate, Some(20.0));
+
+        // The exposure time is not possible with the current frame rate and the offset is not
+        // possible with the current width, so the order of the parameters matters
+        let mut other = open_device_with_backend(backend, Some(1))?;
+        other.set_roi(&Roi {
+            offset_x: 320,
+            offset_y: 0,
+            width: 960,
+            height: 1024,
+        })?;
+        other.apply_settings(&settings)?;
+        assert_eq!(other.snapshot_settings()?, settings);
+        assert_eq!(other.gain_selector()?, GainSelector::All);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn settings_serde() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        let settings = cam.snapshot_settings()?;
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(json.contains(r#""image_data_format":"Mono8""#));
+        let restored: CameraSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, settings);
+
+        let partial = r#"{"exposure": 1000.0, "gain": {"AnalogAll": 2.0}}"#;
+        let partial: CameraSettings = serde_json::from_str(partial).unwrap();
+        assert_eq!(partial.image_data_format, None);
+        cam.apply_settings(&partial)?;
+        assert_eq!(cam.exposure()?, 1000.0);
+        cam.set_gain_selector(GainSelector::AnalogAll)?;
+        assert_eq!(cam.gain()?, 2.0);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_user_sets() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend.clone(), None)?;
+        assert_eq!(cam.user_set_default()?, UserSetSelector::None);
+        cam.set_image_data_bit_depth(BitDepth::Bpp8)?;
+        cam.load_user_set(UserSetSelector::Std12High)?;
+        assert_eq!(cam.user_set_selector()?, UserSetSelector::Std12High);
+        assert_eq!(cam.image_data_bit_depth()?, BitDepth::Bpp12);
+        assert_eq!(cam.gain()?, 12.0);
+        let err = cam.load_user_set(UserSetSelector::Hdr2x12Low).unwrap_err();
+        assert_eq!(err.root(), &XiError::WrongParamValue);
+
+        cam.set_user_set_default(UserSetSelector::Std12High)?;
+        drop(cam);
+        let cam = open_device_with_backend(backend, None)?;
+        assert_eq!(cam.user_set_default()?, UserSetSelector::Std12High);
+        assert_eq!(cam.gain()?, 12.0);
+
+        let mut model = SensorModel::default();
+        model.user_sets.clear();
+        let backend = std::sync::Arc::new(SimBackend::with_models([model]));
+        let cam = open_device_with_backend(backend, None)?;
+        let err = cam.user_set_default().unwrap_err();
+        assert_eq!(err.root(), &XiError::NotSupported);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_selected() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        {
+            let mut port2 = cam.select(GpoSelector::Port2)?;
+            assert_eq!(port2.gpo_selector()?, GpoSelector::Port2);
+            port2.set_gpo_mode(GpoMode::ExposureActive)?;
+        }
+        assert_eq!(cam.gpo_selector()?, GpoSelector::Port1);
+        assert_eq!(cam.gpo_mode()?, GpoMode::Off);
+        let mode = cam.with_selected(GpoSelector::Port2, |cam| cam.gpo_mode())?;
+        assert_eq!(mode, GpoMode::ExposureActive);
+
+        let err = cam
+            .with_selected(GainSelector::AnalogAll, |cam| cam.set_gain(100.0))
+            .unwrap_err();
+        assert_eq!(err.root(), &XiError::OutOfRange);
+        assert_eq!(cam.gain_selector()?, GainSelector::All);
+        let err = cam.select(LedSelector::Led5).err().unwrap();
+        assert_eq!(err.root(), &XiError::WrongParamValue);
+        assert_eq!(cam.led_selector()?, LedSelector::Led1);
+
+        let temp = cam.with_selected(TempSelector::SensorBoard, |cam| cam.temp())?;
+        assert_eq!(temp, 40.0);
+        assert_eq!(cam.counter(CounterSelector::ApiSkippedFrames)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_acquisition() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(2));
+        let mut cam = open_device_with_backend(backend.clone(), Some(1))?;
+        let err = open_device_with_backend(backend.clone(), Some(1)).err();
+        assert_eq!(err, Some(XiError::ResourceOrFunctionLocked));
+        let err = open_device_with_backend(backend, Some(2)).err();
+        assert_eq!(err, Some(XiError::NoDevicesFound));
+
+        cam.set_width(320)?;
+        cam.set_height(240)?;
+        cam.set_test_pattern(TestPattern::GreyHorizRamp)?;
+        cam.set_image_user_data(42)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let first = acq_buffer.next_image::<u8>(None)?;
+        let second = acq_buffer.next_image::<u8>(None)?;
+        assert_eq!((first.width(), first.height()), (320, 240));
+        assert_eq!(first.pixel(17, 3), Some(&17));
+        assert_eq!(first.nframe() + 1, second.nframe());
+        assert_eq!(second.image_user_data(), 42);
+        acq_buffer.stop_acquisition()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sim_buffer_lifetime() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_width(64)?;
+        cam.set_height(8)?;
+        cam.set_buffers_queue_size(2)?;
+        cam.set_test_pattern(TestPattern::FrameCounter)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let first = acq_buffer.next_image::<u8>(None)?;
+        let data = first.data().to_vec();
+        let images = (0..4)
+            .map(|_| acq_buffer.next_image::<u8>(None))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Images are not overwritten or freed by later frames, regardless of the queue size
+        assert_eq!(first.data(), &data[..]);
+        assert_ne!(images[3].data(), &data[..]);
+        assert!(images
+            .iter()
+            .all(|image| image.data().as_ptr() != first.data().as_ptr()));
+        Ok(())
+    }
+
+    #[test]
+    fn sim_acquisition_parameters() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let cam = open_device_with_backend(backend, None)?;
+        let mut acq_buffer = cam.start_acquisition()?;
+        acq_buffer.set_exposure(2500.0)?;
+        acq_buffer.set_gain_selector(GainSelector::AnalogAll)?;
+        acq_buffer.set_gain_clamped(30.0)?;
+        acq_buffer.set_gpo_mode(GpoMode::FrameActive)?;
+        acq_buffer.set_image_user_data(7)?;
+        assert_eq!(acq_buffer.gain()?, acq_buffer.gain_maximum()?);
+        assert_eq!(acq_buffer.counter(CounterSelector::ApiSkippedFrames)?, 0);
+        assert_eq!(acq_buffer.temp()?, 40.0);
+        let image = acq_buffer.next_image::<u8>(None)?;
+        assert_eq!(image.image_user_data(), 7);
+
+        let cam = acq_buffer.stop_acquisition()?;
+        assert_eq!(cam.exposure()?, 2500.0);
+        assert_eq!(cam.gpo_mode()?, GpoMode::FrameActive);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_start_acquisition_error() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_acq_buffer_size(1 << 20)?;
+        let err = cam.start_acquisition().err().unwrap();
+        assert_eq!(err.error(), &XiError::MemoryAllocation);
+        let mut cam = err.into_inner();
+        cam.set_acq_buffer_size(2 << 20)?;
+        let acq_buffer = cam.start_acquisition()?;
+        acq_buffer.next_image::<u8>(None)?;
+        acq_buffer.stop_acquisition()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sim_direct_update() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_exposure_direct(1500.0)?;
+        assert_eq!(cam.exposure()?, 1500.0);
+        let mut acq_buffer = cam.start_acquisition()?;
+        acq_buffer.set_exposure_direct(2000.0)?;
+        acq_buffer.set_gain_direct(3.0)?;
+        assert_eq!(acq_buffer.exposure()?, 2000.0);
+        assert_eq!(acq_buffer.gain()?, 3.0);
+        let err = acq_buffer.set_gain_direct(100.0).unwrap_err();
+        assert_eq!(err.root(), &XiError::OutOfRange);
+        assert!(err
+            .to_string()
+            .starts_with("set gain:direct_update=100.0 failed"));
+        acq_buffer.stop_acquisition()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sim_color_formats() -> Result<(), XiError> {
+        use XI_COLOR_FILTER_ARRAY::XI_CFA_BAYER_RGGB;
+        let model = SensorModel::color(256, 64, XI_CFA_BAYER_RGGB);
+        let backend = std::sync::Arc::new(SimBackend::with_models([model]));
+        let mut cam = open_device_with_backend(backend, None)?;
+        assert_eq!(cam.color_filter_array()?, ColorFilterArray::BayerRggb);
+        cam.set_test_pattern(TestPattern::ColorBar)?;
+
+        // The second bar is yellow
+        cam.set_image_data_format(ImageFormat::Rgb24)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let image = acq_buffer.next_image::<u8>(None)?;
+        assert_eq!(&image.data()[32 * 3..33 * 3], &[0, 255, 255]);
+        #[cfg(feature = "image")]
+        {
+            let err = ::image::GrayImage::try_from(image).unwrap_err();
+            assert!(matches!(
+                err,
+                XiError::PixelFormatMismatch {
+                    format: ImageFormat::Rgb24,
+                    ..
+                }
+            ));
+        }
+        let image = acq_buffer.next_image::<Bgr8>(None)?;
+        let yellow = Bgr8 {
+            b: 0,
+            g: 255,
+            r: 255,
+        };
+        assert_eq!(image.pixel(32, 0), Some(&yellow));
+        #[cfg(feature = "image")]
+        {
+            let buffer = ::image::RgbImage::try_from(image)?;
+            assert_eq!(buffer.get_pixel(32, 0).0, [255, 255, 0]);
+        }
+        let mut cam = acq_buffer.stop_acquisition()?;
+
+        cam.set_image_data_format(ImageFormat::Rgb64)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let image = acq_buffer.next_image::<Bgra16>(None)?;
+        let yellow = *image.pixel(32, 0).unwrap();
+        assert_eq!((yellow.r, yellow.b), (yellow.g, 0));
+        assert!(yellow.r > 0);
+        #[cfg(feature = "image")]
+        {
+            let frame = acq_buffer.next_image_dynamic(None)?;
+            let image = frame.into_dynamic_image().unwrap().into_rgba16();
+            let [r, g, b, a] = image.get_pixel(32, 0).0;
+            assert_eq!([r, g, b, a], [yellow.r, yellow.g, yellow.b, yellow.a]);
+        }
+        let mut cam = acq_buffer.stop_acquisition()?;
+
+        cam.set_image_data_format(ImageFormat::Raw8)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let image = acq_buffer.next_image::<u8>(None)?;
+        assert_eq!(image.pixel(32, 0), Some(&255)); // Red
+        assert_eq!(image.pixel(33, 0), Some(&255)); // Green
+        assert_eq!(image.pixel(33, 1), Some(&0)); // Blue
+        assert_eq!(image.xi_img.color_filter_array, XI_CFA_BAYER_RGGB);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_raw32() -> Result<(), XiError> {
+        use crate::XI_BIT_DEPTH::*;
+        use crate::XI_IMG_FORMAT::*;
+
+        let mut model = SensorModel::mono(64, 8);
+        model.image_formats = vec![XI_RAW32, XI_RAW16];
+        model.bit_depths = vec![XI_BPP_12, XI_BPP_32];
+        let backend = std::sync::Arc::new(SimBackend::with_models([model]));
+        let mut cam = open_device_with_backend(backend, None)?;
+        assert_eq!(cam.image_data_bit_depth()?, BitDepth::Bpp32);
+        cam.set_test_pattern(TestPattern::White)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let image = acq_buffer.next_image::<u32>(None)?;
+        assert_eq!(image.pixel(63, 7), Some(&u32::MAX));
+        let mut cam = acq_buffer.stop_acquisition()?;
+
+        // The values of 16 bit formats are limited to 16 bits
+        cam.set_image_data_format(ImageFormat::Raw16)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let image = acq_buffer.next_image::<u16>(None)?;
+        assert_eq!(image.pixel(63, 7), Some(&u16::MAX));
+        Ok(())
+    }
+
+    #[test]
+    fn sim_pixel_format() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_image_data_format(ImageFormat::Mono16)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let err = acq_buffer.next_image::<u8>(None).err().unwrap();
+        assert_eq!(
+            err,
+            XiError::PixelFormatMismatch {
+                format: ImageFormat::Mono16,
+                pixel_type: "u8"
+            }
+        );
+        assert_eq!(
+            err.code(),
+            XI_RET::XI_NOT_SUPPORTED_DATA_FORMAT as XI_RETURN
+        );
+        let image = acq_buffer.next_image::<u16>(None)?;
+        assert_eq!(image.data().len(), 1280 * 1024);
+        acq_buffer.stop_acquisition()?;
+
+        assert_eq!(u16::channels(ImageFormat::Rgb48), Some(3));
+        assert_eq!(f32::FORMATS, &[ImageFormat::Raw32Float]);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_dynamic_frame() -> Result<(), XiError> {
+        use XI_COLOR_FILTER_ARRAY::XI_CFA_BAYER_RGGB;
+        let model = SensorModel::color(256, 64, XI_CFA_BAYER_RGGB);
+        let backend = std::sync::Arc::new(SimBackend::with_models([model]));
+        let mut cam = open_device_with_backend(backend, None)?;
+        for format in [ImageFormat::Raw8, ImageFormat::Rgb24, ImageFormat::Mono16] {
+            cam.set_image_data_format(format)?;
+            let acq_buffer = cam.start_acquisition()?;
+            let frame = acq_buffer.next_image_dynamic(None)?;
+            assert_eq!(frame.format(), format);
+            assert_eq!((frame.width(), frame.height()), (256, 64));
+            match frame {
+                DynamicFrame::Raw8 { cfa, .. } => {
+                    assert_eq!(cfa, Some(ColorFilterArray::BayerRggb))
+                }
+                DynamicFrame::Rgb24(image) => assert_eq!(image.data().len(), 256 * 64),
+                DynamicFrame::Mono16(_) => {
+                    assert_eq!(frame.color_filter_array(), None);
+                    let err = frame.into_image::<u8>().err().unwrap();
+                    assert!(matches!(err, XiError::PixelFormatMismatch { .. }));
+                }
+                _ => panic!("Unexpected format {:?}", format),
+            }
+            cam = acq_buffer.stop_acquisition()?;
+        }
+
+        #[cfg(feature = "image")]
+        {
+            let acq_buffer = cam.start_acquisition()?;
+            let image = acq_buffer.next_image_dynamic(None)?.into_dynamic_image();
+            assert!(matches!(image, Some(::image::DynamicImage::ImageLuma16(_))));
+        }
+
+        // A color filter array unknown to the bindings does not fail the whole frame
+        let model = SensorModel::color(256, 64, 0x1234);
+        let backend = std::sync::Arc::new(SimBackend::with_models([model]));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_image_data_format(ImageFormat::Raw8)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let frame = acq_buffer.next_image_dynamic(None)?;
+        assert!(matches!(frame, DynamicFrame::Raw8 { cfa: None, .. }));
+        assert_eq!(frame.color_filter_array(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_image_padding() -> Result<(), XiError> {
+        let mut model = SensorModel::mono(100, 8);
+        model.line_alignment = 64;
+        let backend = std::sync::Arc::new(SimBackend::with_models([model]));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_test_pattern(TestPattern::GreyHorizRamp)?;
+        cam.set_image_data_format(ImageFormat::Mono16)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let image = acq_buffer.next_image::<u16>(None)?;
+        assert_eq!(image.padding_x(), 56);
+        assert_eq!(image.rows().len(), 8);
+        assert!(image.rows().all(|row| row.len() == 100));
+        assert_eq!(image.row(3).unwrap()[17], *image.pixel(17, 3).unwrap());
+        assert_eq!(image.row(8), None);
+        assert_eq!(image.pixels().count(), 100 * 8);
+        let (x, y, pixel) = image.enumerate_pixels().nth(100 + 5).unwrap();
+        assert_eq!((x, y, pixel), (5, 1, image.pixel(5, 1).unwrap()));
+
+        #[cfg(feature = "image")]
+        {
+            let buffer = ::image::ImageBuffer::<::image::Luma<u16>, _>::try_from(image)?;
+            assert_eq!(buffer.dimensions(), (100, 8));
+            assert_eq!(buffer.get_pixel(99, 7).0, buffer.get_pixel(99, 0).0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn sim_owned_image() -> Result<(), XiError> {
+        fn assert_send_sync_clone<T: Send + Sync + Clone>(_: &T) {}
+
+        let mut model = SensorModel::mono(100, 8);
+        model.line_alignment = 64;
+        let backend = std::sync::Arc::new(SimBackend::with_models([model]));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_test_pattern(TestPattern::GreyHorizRamp)?;
+        cam.set_image_data_format(ImageFormat::Mono16)?;
+        let acq_buffer = cam.start_acquisition()?;
+        let image = acq_buffer.next_image::<u16>(None)?;
+        let owned = image.to_owned();
+        assert_send_sync_clone(&owned);
+        assert_eq!(owned.padding_x(), 0);
+        assert_eq!(owned.data().len(), 100 * 8);
+        assert_eq!(
+            (owned.width(), owned.height()),
+            (image.width(), image.height())
+        );
+        assert_eq!(owned.nframe(), image.nframe());
+        assert_eq!(owned.timestamp_raw(), image.timestamp_raw());
+        assert!(owned.rows().eq(image.rows()));
+        assert!(owned.enumerate_pixels().eq(image.enumerate_pixels()));
+        assert_eq!(owned.pixel(99, 7), image.pixel(99, 7));
+        assert_eq!(owned.pixel(100, 0), None);
+
+        let nframe = image.nframe();
+        let worker = std::thread::spawn(move || owned.pixels().copied().max());
+        let next = acq_buffer.next_image::<u16>(None)?;
+        assert_eq!(next.nframe(), nframe + 1);
+        assert_eq!(worker.join().unwrap(), image.pixels().copied().max());
+
+        #[cfg(feature = "image")]
+        {
+            let owned = image.to_owned();
+            let buffer = ::image::ImageBuffer::<::image::Luma<u16>, _>::try_from(owned.as_image())?;
+            assert_eq!(buffer.get_pixel(99, 7).0, [*image.pixel(99, 7).unwrap()]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn empty_owned_image() -> Result<(), XiError> {
+        let mut xi_img: XI_IMG = unsafe { std::mem::zeroed() };
+        xi_img.frm = XI_IMG_FORMAT::XI_MONO8;
+        let image = Image::<u8>::from_xi_img(xi_img)?;
+        assert!(image.data().is_empty());
+        let owned = image.to_owned();
+        assert!(owned.data().is_empty());
+        let image = owned.as_image();
+        assert!(image.data().is_empty());
+        assert_eq!(image.rows().len(), 0);
+        assert_eq!(image.pixel(0, 0), None);
+        Ok(())
+    }
+
+    #[test]
+    fn sim_software_trigger() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_trg_source(TriggerSource::Software)?;
+        cam.set_trg_selector(TriggerSelector::FrameBurstStart)?;
+        cam.set_acq_frame_burst_count(2)?;
+        cam.set_exposure(50_000.0)?;
+        let mut acq_buffer = cam.start_acquisition()?;
+        assert_eq!(
+            acq_buffer.next_image::<u8>(Some(10)).err(),
+            Some(XiError::Timeout)
+        );
+        acq_buffer.software_trigger()?;
+        let first = acq_buffer.next_image::<u8>(Some(10))?;
+        let second = acq_buffer.next_image::<u8>(Some(10))?;
+        assert_eq!((first.acq_nframe(), second.acq_nframe()), (1, 2));
+        // The exposure time limits the frame rate
+        assert_eq!(second.timestamp_raw() - first.timestamp_raw(), 50_000);
+        assert_eq!(
+            acq_buffer.next_image::<u8>(Some(10)).err(),
+            Some(XiError::Timeout)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sim_exposure_framerate() -> Result<(), XiError> {
+        let backend = std::sync::Arc::new(SimBackend::new(1));
+        let mut cam = open_device_with_backend(backend, None)?;
+        cam.set_exposure(50_000.0)?;
+        assert_eq!(cam.framerate_maximum()?, 20.0);
+        cam.set_acq_timing_mode(AcqTimingMode::FrameRate)?;
+        cam.set_framerate(10.0)?;
+        assert_eq!(cam.exposure_maximum()?, 100_000.0);
+        let err = cam.set_exposure(200_000.0).unwrap_err();
+        assert_eq!(err.root(), &XiError::OutOfRange);
+        Ok(())
+    }
+
+    #[test]
+    fn record_and_replay() -> Result<(), XiError> {
+        use std::sync::Arc;
+        let session = |backend: Arc<dyn Backend>| -> Result<(f32, Vec<Vec<u8>>), XiError> {
+            let mut cam = open_device_with_backend(backend, None)?;
+            cam.set_exposure(2_000.0)?;
+            cam.set_width(64)?;
+            cam.set_height(8)?;
+            cam.set_test_pattern(TestPattern::GreyHorizRampMoving)?;
+            let exposure = cam.exposure()?;
+            let acq_buffer = cam.start_acquisition()?;
+            // All images stay valid while further images are acquired
+            let images = (0..6)
+                .map(|_| acq_buffer.next_image::<u8>(None))
+                .collect::<Result<Vec<_>, _>>()?;
+            let data: Vec<_> = images.iter().map(|image| image.data().to_vec()).collect();
+            assert_ne!(data[0], data[4]);
+            drop(images);
+            acq_buffer.stop_acquisition()?;
+            Ok((exposure, data))
+        };
+
+        let recorder =
+            Arc::new(RecordingBackend::new(Arc::new(SimBackend::new(1)), Vec::new()).unwrap());
+        let recorded = session(recorder.clone())?;
+        let trace = Arc::try_unwrap(recorder).ok().unwrap().into_writer();
+
+        let replay = Arc::new(ReplayBackend::from_reader(&trace[..]).unwrap());
+        let replayed = session(replay.clone())?;
+        assert_eq!(recorded, replayed);
+        assert_eq!(replay.remaining(), 0);
+
+        let replay = Arc::new(ReplayBackend::from_reader(&trace[..]).unwrap());
+        let mut cam = open_device_with_backend(replay.clone(), None)?;
+        let err = cam.set_exposure(1_000.0).unwrap_err();
+        assert_eq!(err.root(), &XiError::InvalidArg);
+        assert!(replay.mismatch().is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn drop_acquisition_buffer() -> Result<(), XiError> {
+        use std::sync::Arc;
+        let recorder =
+            Arc::new(RecordingBackend::new(Arc::new(SimBackend::new(1)), Vec::new()).unwrap());
+        let cam = open_device_with_backend(recorder.clone(), None)?;
+        let (image, cam) = cam
+            .with_acquisition(|buffer| buffer.next_image::<u8>(None).map(|image| image.nframe()))?;
+        assert_eq!(image?, 1);
+        let acq_buffer = cam.start_acquisition()?;
+        let thread = std::thread::spawn(move || {
+            let _acq_buffer = acq_buffer;
+            panic!("Acquisition failed");
+        });
+        assert!(thread.join().is_err());
+
+        let trace = Arc::try_unwrap(recorder).ok().unwrap().into_writer();
+        let calls: Vec<_> = String::from_utf8(trace)
+            .unwrap()
+            .lines()
+            .filter_map(|line| {
+                ["start_acquisition", "stop_acquisition", "close_device"]
+                    .into_iter()
+                    .find(|call| line.contains(call))
+            })
+            .collect();
+        assert_eq!(
+            calls,
+            [
+                "start_acquisition",
+                "stop_acquisition",
+                "start_acquisition",
+                "stop_acquisition",
+                "close_device"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn with_acquisition_stop_failure() -> Result<(), XiError> {
+        use std::sync::Arc;
+        let recorder = RecordingBackend::new(Arc::new(SimBackend::new(1)), Vec::new()).unwrap();
+        let recorder = Arc::new(recorder);
+        let cam = open_device_with_backend(recorder.clone(), None)?;
+        let ((), cam) = cam.with_acquisition(|_| ())?;
+        drop(cam);
+
+        let trace = Arc::try_unwrap(recorder).ok().unwrap().into_writer();
+        let trace: Vec<_> = String::from_utf8(trace)
+            .unwrap()
+            .lines()
+            .map(|line| match line.starts_with("stop_acquisition") {
+                true => line.replace("ret=0", &format!("ret={}", XI_RET::XI_TIMEOUT)),
+                false => line.to_string(),
+            })
+            .collect();
+        let replay = Arc::new(ReplayBackend::from_reader(trace.join("\n").as_bytes()).unwrap());
+        let cam = open_device_with_backend(replay.clone(), None)?;
+        let (error, cam) = match cam.with_acquisition(|_| ()) {
+            Ok(_) => panic!("Stopping the acquisition should fail"),
+            Err(err) => err.into_parts(),
+        };
+        assert_eq!(error, XiError::Timeout);
+        drop(cam);
+        assert_eq!(replay.remaining(), 0);
+        Ok(())
+    }
+
     #[test]
     #[serial]
-    fn start_stop_acquisition() -> Result<(), XI_RETURN> {
+    fn start_stop_acquisition() -> Result<(), XiError> {
         let cam = open_device(None)?;
         let acq = cam.start_acquisition()?;
         acq.stop_acquisition()?;
@@ -70,17 +1092,10 @@ mod tests {
 
     #[test]
     #[serial]
-    fn set_get_exposure() -> Result<(), XI_RETURN> {
+    fn set_get_exposure() -> Result<(), XiError> {
         let mut cam = open_device(None)?;
-        match cam.set_exposure_burst_count(1) {
-            Err(x) => {
-                match x as XI_RET::Type {
-                    XI_RET::XI_NOT_IMPLEMENTED => {} // Ignore error for cameras that do not have this feature
-                    XI_RET::XI_NOT_SUPPORTED => {}
-                    _ => return Err(x),
-                }
-            }
-            _ => {}
+        if cam.is_supported("exposure_burst_count")? {
+            cam.set_exposure_burst_count(1)?;
         }
         cam.set_exposure(12_345.0)?;
         let exp = cam.exposure()?;
@@ -90,9 +1105,9 @@ mod tests {
 
     #[test]
     #[serial]
-    fn default_gains() -> Result<(), XI_RETURN> {
+    fn default_gains() -> Result<(), XiError> {
         let mut cam = open_device(None)?;
-        cam.set_gain_selector(XI_GAIN_SELECTOR_ALL)?;
+        cam.set_gain_selector(GainSelector::All)?;
         let gain_all = cam.gain()?;
         assert_eq!(gain_all, 0.0);
         Ok(())
@@ -100,20 +1115,20 @@ mod tests {
 
     #[test]
     #[serial]
-    fn downsampling_defaults() -> Result<(), XI_RETURN> {
+    fn downsampling_defaults() -> Result<(), XiError> {
         let mut cam = open_device(None)?;
         let default_type = cam.downsampling_type()?;
-        assert_eq!(default_type, XI_BINNING);
+        assert_eq!(default_type, DownsamplingType::Binning);
         let default_value = cam.downsampling()?;
-        assert_eq!(default_value, XI_DWN_1x1);
-        match cam.set_downsampling_type(XI_SKIPPING) {
-            Err(x) => match x as XI_RET::Type {
-                XI_RET::XI_INVALID_ARG => {} // This happens when a camera does not support skipping
+        assert_eq!(default_value, Downsampling::Dwn1x1);
+        match cam.set_downsampling_type(DownsamplingType::Skipping) {
+            Err(x) => match x.root() {
+                XiError::InvalidArg => {} // This happens when a camera does not support skipping
                 _ => return Err(x),
             },
             Ok(()) => {
                 let skipping_value = cam.downsampling()?;
-                assert_eq!(skipping_value, XI_DWN_1x1);
+                assert_eq!(skipping_value, Downsampling::Dwn1x1);
             }
         }
         Ok(())
@@ -121,16 +1136,16 @@ mod tests {
 
     #[test]
     #[serial]
-    fn image_format_defaults() -> Result<(), XI_RETURN> {
+    fn image_format_defaults() -> Result<(), XiError> {
         let cam = open_device(None)?;
         let default_format = cam.image_data_format()?;
-        assert_eq!(default_format, XI_MONO8);
+        assert_eq!(default_format, ImageFormat::Mono8);
         Ok(())
     }
 
     #[test]
     #[serial]
-    fn get_image() -> Result<(), XI_RETURN> {
+    fn get_image() -> Result<(), XiError> {
         let cam = open_device(None)?;
         let acq = cam.start_acquisition()?;
         let img = acq.next_image::<u8>(None)?;
@@ -148,18 +1163,18 @@ mod tests {
 
     #[test]
     #[serial]
-    fn test_pattern_defaults() -> Result<(), XI_RETURN> {
+    fn test_pattern_defaults() -> Result<(), XiError> {
         let cam = open_device(None)?;
         //let generator = cam.test_pattern_generator_selector()?;
         //assert_eq!(generator, XI_TESTPAT_GEN_FPGA);
         let pattern = cam.test_pattern()?;
-        assert_eq!(pattern, XI_TESTPAT_OFF);
+        assert_eq!(pattern, TestPattern::Off);
         Ok(())
     }
 
     #[test]
     #[serial]
-    fn get_increment() -> Result<(), XI_RETURN> {
+    fn get_increment() -> Result<(), XiError> {
         let cam = open_device(None)?;
         let increment = cam.width_increment()?;
         println!("{}", increment);
@@ -168,7 +1183,7 @@ mod tests {
 
     #[test]
     #[serial]
-    fn set_get_roi() -> Result<(), XI_RETURN> {
+    fn set_get_roi() -> Result<(), XiError> {
         let mut cam = open_device(None)?;
         let roi = Roi {
             offset_x: cam.offset_x_minimum()? + cam.offset_x_increment()?,
@@ -187,16 +1202,16 @@ mod tests {
 
     #[test]
     #[serial]
-    fn blink_leds() -> Result<(), XI_RETURN> {
+    fn blink_leds() -> Result<(), XiError> {
         let mut cam = open_device(None)?;
-        cam.set_led_selector(XI_LED_SEL1)?;
-        cam.set_led_mode(XI_LED_BLINK)?;
+        cam.set_led_selector(LedSelector::Led1)?;
+        cam.set_led_mode(LedMode::Blink)?;
         Ok(())
     }
 
     #[test]
     #[serial]
-    fn image_user_data() -> Result<(), XI_RETURN> {
+    fn image_user_data() -> Result<(), XiError> {
         let mut cam = open_device(None)?;
         cam.set_image_user_data(42u32)?;
         let acq_buffer = cam.start_acquisition()?;
@@ -207,9 +1222,9 @@ mod tests {
 
     #[test]
     #[serial]
-    fn iterate_over_image() -> Result<(), XI_RETURN> {
+    fn iterate_over_image() -> Result<(), XiError> {
         let mut cam = open_device(None)?;
-        cam.set_image_data_format(XI_RAW16)?;
+        cam.set_image_data_format(ImageFormat::Raw16)?;
         let acq_buffer = cam.start_acquisition()?;
         let image = acq_buffer.next_image::<u16>(None)?;
         let data = image.data();
@@ -223,7 +1238,7 @@ mod tests {
 
     #[test]
     #[serial]
-    fn available_bandwidth() -> Result<(), XI_RETURN> {
+    fn available_bandwidth() -> Result<(), XiError> {
         let cam = open_device(None)?;
         let bandwidth = cam.available_bandwidth()?;
         assert!(bandwidth > 0);
@@ -232,27 +1247,31 @@ mod tests {
 
     #[test]
     #[serial]
-    fn read_counters() -> Result<(), XI_RETURN> {
+    fn read_counters() -> Result<(), XiError> {
         let mut cam = open_device_manual_bandwidth(None, 1000)?;
-        let skipped_frames =
-            cam.counter(XI_COUNTER_SELECTOR::XI_CNT_SEL_TRANSPORT_SKIPPED_FRAMES)?;
+        let skipped_frames = cam.counter(CounterSelector::TransportSkippedFrames)?;
         assert_eq!(skipped_frames, 0);
         Ok(())
     }
 
     #[test]
     #[serial]
-    fn raw_handle_access() -> Result<(), XI_RETURN> {
+    #[cfg(feature = "link")]
+    fn raw_handle_access() -> Result<(), XiError> {
+        use std::os::raw::c_char;
         let cam = open_device(None)?;
         let exposure_low = unsafe {
             let handle = *cam;
             let mut value = 0.0f32;
-            xiapi_sys::xiGetParamFloat(handle,  XI_PRM_EXPOSURE.as_ptr() as *const c_char, &mut value);
+            xiapi_sys::xiGetParamFloat(
+                handle,
+                XI_PRM_EXPOSURE.as_ptr() as *const c_char,
+                &mut value,
+            );
             value
         };
         let exposure_high = cam.exposure()?;
         assert_eq!(exposure_high, exposure_low);
         Ok(())
-
     }
 }
@@ -0,0 +1,411 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::collections::BTreeMap;
+
+use crate::ParamRange;
+use crate::ParamValue;
+use crate::RangeValue;
+use crate::XiError;
+
+/// Type of a parameter as described in the device manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParamKind {
+    /// Integer parameter
+    Int,
+    /// 64 bit integer parameter
+    Int64,
+    /// Float parameter
+    Float,
+    /// Boolean parameter
+    Bool,
+    /// String parameter
+    String,
+    /// Enumeration, accessed as an integer
+    Enum,
+    /// Command that is executed when it is set
+    Command,
+}
+
+impl ParamKind {
+    fn parse(kind: &str) -> Option<Self> {
+        let kind = match kind.to_ascii_lowercase().as_str() {
+            "int" => ParamKind::Int,
+            "int64" => ParamKind::Int64,
+            "float" => ParamKind::Float,
+            "bool" => ParamKind::Bool,
+            "string" => ParamKind::String,
+            "enum" => ParamKind::Enum,
+            "command" => ParamKind::Command,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+/// Description of a single parameter in the device manifest
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamInfo {
+    /// Name of the parameter as used by xiAPI (see `XI_PRM_*`)
+    pub name: String,
+    /// Type of the parameter, if the manifest specifies a known type
+    pub kind: Option<ParamKind>,
+    /// The parameter can be read
+    pub readable: bool,
+    /// The parameter can be set
+    pub writable: bool,
+    /// Smallest value of the parameter, if the manifest specifies it
+    pub minimum: Option<ParamValue>,
+    /// Largest value of the parameter, if the manifest specifies it
+    pub maximum: Option<ParamValue>,
+    /// Increment of the parameter, if the manifest specifies it
+    pub increment: Option<ParamValue>,
+    /// The parameter supports the `:direct_update` modifier
+    pub direct_update: bool,
+}
+
+impl ParamInfo {
+    fn from_element(element: &Element) -> Option<Self> {
+        let name = element.attribute("name")?.to_string();
+        let kind = element.attribute("type").and_then(ParamKind::parse);
+        let access = element.attribute("access").map(str::to_ascii_lowercase);
+        let (readable, writable) = match access.as_deref() {
+            Some("r" | "ro" | "read" | "readonly") => (true, false),
+            Some("w" | "wo" | "write" | "writeonly") => (false, true),
+            _ => (true, true),
+        };
+        let value = |key: &str| {
+            element
+                .attribute(key)
+                .and_then(|value| parse_value(kind, value))
+        };
+        Some(ParamInfo {
+            name,
+            kind,
+            readable,
+            writable,
+            minimum: value("min"),
+            maximum: value("max"),
+            increment: value("inc"),
+            direct_update: element
+                .attribute("direct_update")
+                .is_some_and(|value| value.eq_ignore_ascii_case("true") || value == "1"),
+        })
+    }
+
+    /// The range of this parameter, if the manifest specifies its minimum and maximum.
+    ///
+    /// If no increment is specified, integer parameters use an increment of 1 and float parameters
+    /// are continuous.
+    pub fn range<T: RangeValue>(&self) -> Option<ParamRange<T>> {
+        let minimum = as_f64(self.minimum.as_ref()?)?;
+        let maximum = as_f64(self.maximum.as_ref()?)?;
+        let increment = match &self.increment {
+            Some(increment) => as_f64(increment)?,
+            None if T::INTEGER => 1.0,
+            None => 0.0,
+        };
+        Some(ParamRange::new(
+            T::from_f64(minimum),
+            T::from_f64(maximum),
+            T::from_f64(increment),
+        ))
+    }
+}
+
+/// Capabilities of a camera model, parsed from the XML manifest the camera reports as
+/// `device_manifest`.
+///
+/// The root element `manifest` contains one `parameter` element for every parameter. Its
+/// properties are read from the attributes `name`, `type` (`int`, `int64`, `float`, `bool`,
+/// `string`, `enum` or `command`), `access` (`R`, `W` or `RW`, also spelled out like
+/// `ReadOnly`), `min`, `max`, `inc` and `direct_update` (`true` or `false`). Names of elements and
+/// attributes and the values of `type` and `access` are case-insensitive. Elements without a
+/// `name` are skipped, properties that are missing or can not be interpreted are left empty.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), xiapi::XiError> {
+///     let cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+///     let manifest = cam.manifest()?;
+///     let gain = manifest.parameter("gain").unwrap();
+///     assert!(gain.writable);
+///     assert_eq!(gain.range::<f32>().unwrap().maximum, cam.gain_maximum()?);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Manifest {
+    /// Version of the manifest, if it is specified
+    pub version: Option<String>,
+    /// All parameters described by the manifest, indexed by their name
+    pub parameters: BTreeMap<String, ParamInfo>,
+}
+
+impl Manifest {
+    /// Parse a device manifest.
+    ///
+    /// Fails with [XiError::InvalidData] if `xml` is not a well-formed XML document, its root
+    /// element is not `manifest` or it does not describe any parameter. An empty manifest most
+    /// likely means that the format of the camera is not understood, in which case
+    /// [Camera::is_supported](crate::Camera::is_supported) would report every parameter as
+    /// unsupported.
+    pub fn parse(xml: &str) -> Result<Self, XiError> {
+        let root = XmlParser::parse(xml)?;
+        if !root.name.eq_ignore_ascii_case("manifest") {
+            return Err(XiError::InvalidData);
+        }
+        let parameters: BTreeMap<_, _> = root
+            .children
+            .iter()
+            .filter(|element| element.name.eq_ignore_ascii_case("parameter"))
+            .filter_map(ParamInfo::from_element)
+            .map(|info| (info.name.clone(), info))
+            .collect();
+        if parameters.is_empty() {
+            return Err(XiError::InvalidData);
+        }
+        Ok(Manifest {
+            version: root.attribute("version").map(str::to_string),
+            parameters,
+        })
+    }
+
+    /// The description of parameter `name`, if it is part of the manifest
+    pub fn parameter(&self, name: &str) -> Option<&ParamInfo> {
+        self.parameters.get(name)
+    }
+
+    /// Returns true if parameter `name` is part of the manifest
+    pub fn contains(&self, name: &str) -> bool {
+        self.parameters.contains_key(name)
+    }
+}
+
+fn parse_int(value: &str) -> Option<i64> {
+    match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => i64::from_str_radix(hex, 16).ok(),
+        None => value
+            .parse()
+            .ok()
+            .or_else(|| value.parse::<f64>().ok().map(|v| v as i64)),
+    }
+}
+
+/// Parse a value of a parameter of type `kind`
+fn parse_value(kind: Option<ParamKind>, value: &str) -> Option<ParamValue> {
+    let value = value.trim();
+    let value = match kind {
+        Some(ParamKind::Float) => ParamValue::Float(value.parse().ok()?),
+        Some(ParamKind::Int64) => ParamValue::Int64(parse_int(value)? as u64),
+        Some(ParamKind::Int | ParamKind::Enum | ParamKind::Command | ParamKind::Bool) => {
+            ParamValue::Int(parse_int(value)? as i32)
+        }
+        Some(ParamKind::String) => return None,
+        None => match value.parse() {
+            Ok(value) => ParamValue::Int(value),
+            Err(_) => ParamValue::Float(value.parse().ok()?),
+        },
+    };
+    Some(value)
+}
+
+fn as_f64(value: &ParamValue) -> Option<f64> {
+    match value {
+        ParamValue::Int(value) => Some(*value as f64),
+        ParamValue::Int64(value) => Some(*value as f64),
+        ParamValue::Float(value) => Some(*value as f64),
+        ParamValue::Bool(value) => Some(*value as i32 as f64),
+        ParamValue::String(_) => None,
+    }
+}
+
+/// An element of an XML document. Text content is not needed for the manifest and is dropped.
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+}
+
+impl Element {
+    /// The value of attribute `name`, which is compared case-insensitively
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Minimal XML parser that supports everything needed to read the device manifest: elements,
+/// attributes, text, CDATA sections and the predefined and numeric entities. Comments,
+/// processing instructions and the document type declaration are skipped.
+struct XmlParser<'a> {
+    xml: &'a str,
+    position: usize,
+}
+
+impl<'a> XmlParser<'a> {
+    /// Parse `xml` and return its root element
+    fn parse(xml: &'a str) -> Result<Element, XiError> {
+        let mut parser = XmlParser { xml, position: 0 };
+        parser.skip_misc()?;
+        let root = parser.element()?;
+        parser.skip_misc()?;
+        match parser.rest().is_empty() {
+            true => Ok(root),
+            false => Err(XiError::InvalidData),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.xml[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), XiError> {
+        match self.rest().starts_with(token) {
+            true => {
+                self.position += token.len();
+                Ok(())
+            }
+            false => Err(XiError::InvalidData),
+        }
+    }
+
+    /// Return everything up to `end` and move behind it
+    fn take_until(&mut self, end: &str) -> Result<&'a str, XiError> {
+        let rest = self.rest();
+        let length = rest.find(end).ok_or(XiError::InvalidData)?;
+        self.position += length + end.len();
+        Ok(&rest[..length])
+    }
+
+    /// Skip whitespace, comments, processing instructions and the document type declaration
+    fn skip_misc(&mut self) -> Result<(), XiError> {
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("<?") {
+                self.take_until("?>")?;
+            } else if rest.starts_with("<!--") {
+                self.take_until("-->")?;
+            } else if rest.starts_with("<!DOCTYPE") {
+                self.take_until(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, XiError> {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '=' | '<'))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(XiError::InvalidData);
+        }
+        self.position += length;
+        Ok(&rest[..length])
+    }
+
+    fn element(&mut self) -> Result<Element, XiError> {
+        self.expect("<")?;
+        let mut element = Element {
+            name: self.name()?.to_string(),
+            ..Element::default()
+        };
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            let name = self.name()?.to_string();
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(XiError::InvalidData),
+            };
+            self.position += 1;
+            let value = self.take_until(quote.encode_utf8(&mut [0; 4]))?;
+            element.attributes.push((name, unescape(value)?));
+        }
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.position += 2;
+                let name = self.name()?;
+                self.skip_whitespace();
+                self.expect(">")?;
+                return match name == element.name {
+                    true => Ok(element),
+                    false => Err(XiError::InvalidData),
+                };
+            } else if rest.starts_with("<!--") {
+                self.take_until("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.position += "<![CDATA[".len();
+                self.take_until("]]>")?;
+            } else if rest.starts_with("<?") {
+                self.take_until("?>")?;
+            } else if rest.starts_with('<') {
+                element.children.push(self.element()?);
+            } else if rest.is_empty() {
+                return Err(XiError::InvalidData);
+            } else {
+                let length = rest.find('<').unwrap_or(rest.len());
+                self.position += length;
+                unescape(&rest[..length])?;
+            }
+        }
+    }
+}
+
+/// Replace the entity references in `text`
+fn unescape(text: &str) -> Result<String, XiError> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        let end = rest[start..].find(';').ok_or(XiError::InvalidData)? + start;
+        let entity = &rest[start + 1..end];
+        let character = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = match entity.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+                };
+                code.and_then(char::from_u32).ok_or(XiError::InvalidData)?
+            }
+        };
+        result.push(character);
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
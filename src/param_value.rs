@@ -0,0 +1,118 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::fmt::{Display, Formatter};
+
+use crate::sys::XI_PRM_TYPE;
+
+/// Value of a parameter whose type is only known at runtime.
+///
+/// This is used by [Camera::get_dynamic](crate::Camera::get_dynamic) and
+/// [Camera::set_dynamic](crate::Camera::set_dynamic) to access parameters by name.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ParamValue {
+    /// Integer parameter (`xiTypeInteger`), also used for enumerations and commands
+    Int(i32),
+    /// 64 bit integer parameter (`xiTypeInteger64`)
+    Int64(u64),
+    /// Float parameter (`xiTypeFloat`)
+    Float(f32),
+    /// Boolean parameter (`xiTypeBoolean`)
+    Bool(bool),
+    /// String parameter (`xiTypeString`)
+    String(String),
+}
+
+impl ParamValue {
+    /// Decode a value of type `prm_type` as returned by `xiGetParam`
+    pub(crate) fn decode(prm_type: XI_PRM_TYPE::Type, bytes: &[u8]) -> Option<Self> {
+        let value = match prm_type {
+            XI_PRM_TYPE::xiTypeInteger | XI_PRM_TYPE::xiTypeEnum | XI_PRM_TYPE::xiTypeCommand => {
+                ParamValue::Int(i32::from_ne_bytes(bytes.get(..4)?.try_into().ok()?))
+            }
+            XI_PRM_TYPE::xiTypeBoolean => {
+                ParamValue::Bool(i32::from_ne_bytes(bytes.get(..4)?.try_into().ok()?) != 0)
+            }
+            XI_PRM_TYPE::xiTypeInteger64 => {
+                ParamValue::Int64(u64::from_ne_bytes(bytes.get(..8)?.try_into().ok()?))
+            }
+            XI_PRM_TYPE::xiTypeFloat => {
+                ParamValue::Float(f32::from_ne_bytes(bytes.get(..4)?.try_into().ok()?))
+            }
+            XI_PRM_TYPE::xiTypeString => {
+                let length = bytes.iter().position(|c| *c == 0).unwrap_or(bytes.len());
+                ParamValue::String(String::from_utf8_lossy(&bytes[..length]).into_owned())
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// Encode this value for `xiSetParam`
+    pub(crate) fn encode(&self) -> (XI_PRM_TYPE::Type, Vec<u8>) {
+        match self {
+            ParamValue::Int(value) => (XI_PRM_TYPE::xiTypeInteger, value.to_ne_bytes().to_vec()),
+            ParamValue::Int64(value) => {
+                (XI_PRM_TYPE::xiTypeInteger64, value.to_ne_bytes().to_vec())
+            }
+            ParamValue::Float(value) => (XI_PRM_TYPE::xiTypeFloat, value.to_ne_bytes().to_vec()),
+            ParamValue::Bool(value) => (
+                XI_PRM_TYPE::xiTypeBoolean,
+                (*value as i32).to_ne_bytes().to_vec(),
+            ),
+            ParamValue::String(value) => {
+                (XI_PRM_TYPE::xiTypeString, [value.as_bytes(), &[0]].concat())
+            }
+        }
+    }
+}
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::Int(value) => Display::fmt(value, f),
+            ParamValue::Int64(value) => Display::fmt(value, f),
+            ParamValue::Float(value) => Display::fmt(value, f),
+            ParamValue::Bool(value) => Display::fmt(value, f),
+            ParamValue::String(value) => Display::fmt(value, f),
+        }
+    }
+}
+
+impl From<i32> for ParamValue {
+    fn from(value: i32) -> Self {
+        ParamValue::Int(value)
+    }
+}
+
+impl From<u64> for ParamValue {
+    fn from(value: u64) -> Self {
+        ParamValue::Int64(value)
+    }
+}
+
+impl From<f32> for ParamValue {
+    fn from(value: f32) -> Self {
+        ParamValue::Float(value)
+    }
+}
+
+impl From<bool> for ParamValue {
+    fn from(value: bool) -> Self {
+        ParamValue::Bool(value)
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(value: String) -> Self {
+        ParamValue::String(value)
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        ParamValue::String(value.to_string())
+    }
+}
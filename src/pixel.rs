@@ -0,0 +1,132 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+#[cfg(feature = "image")]
+use image::{Rgb, Rgba};
+
+use crate::ImageFormat;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Type of the values in the data of an [Image](crate::Image).
+///
+/// [AcquisitionBuffer::next_image](crate::AcquisitionBuffer::next_image) checks that the format
+/// of each image can be read as the requested type and fails with
+/// [XiError::PixelFormatMismatch](crate::XiError::PixelFormatMismatch) otherwise.
+///
+/// | Type     | Image formats                             |
+/// |----------|-------------------------------------------|
+/// | `u8`     | `Mono8`, `Raw8`, `Rgb24`\*, `Rgb32`\*     |
+/// | `u16`    | `Mono16`, `Raw16`, `Rgb48`\*, `Rgb64`\*   |
+/// | `u32`    | `Raw32`                                   |
+/// | `f32`    | `Raw32Float`                              |
+/// | [Bgr8]   | `Rgb24`                                   |
+/// | [Bgra8]  | `Rgb32`                                   |
+/// | [Bgr16]  | `Rgb48`                                   |
+/// | [Bgra16] | `Rgb64`                                   |
+///
+/// \* Each value is a single channel of the pixel, use the pixel types to access whole pixels.
+///
+/// This trait is sealed and can not be implemented outside of this crate.
+pub trait PixelFormat: Copy + 'static + private::Sealed {
+    /// Image formats whose data can be read as this type
+    const FORMATS: &'static [ImageFormat];
+
+    /// Number of values of this type that make up one pixel in `format`.
+    ///
+    /// Returns `None` if the data of `format` can not be read as this type.
+    fn channels(format: ImageFormat) -> Option<usize>;
+}
+
+/// This macro implements [PixelFormat] from a list of image formats and their number of channels
+macro_rules! pixel_format {
+    ($($type:ty => { $($format:ident: $channels:literal),* $(,)? })*) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl PixelFormat for $type {
+                const FORMATS: &'static [ImageFormat] = &[$(ImageFormat::$format,)*];
+
+                fn channels(format: ImageFormat) -> Option<usize> {
+                    match format {
+                        $(ImageFormat::$format => Some($channels),)*
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// This macro generates pixel structs with their channels in the order of the image data
+macro_rules! pixel {
+    ($(
+        $(#[doc = $doc:expr])*
+        $name:ident($type:ty) { $($channel:ident),* }
+    )*) => {
+        $(
+            $(#[doc = $doc])*
+            #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+            #[repr(C)]
+            pub struct $name {
+                $(
+                    #[doc = concat!("Value of the `", stringify!($channel), "` channel")]
+                    pub $channel: $type,
+                )*
+            }
+        )*
+    };
+}
+
+pixel! {
+    /// Pixel of an [ImageFormat::Rgb24] image, which is stored in blue, green, red order
+    Bgr8(u8) { b, g, r }
+    /// Pixel of an [ImageFormat::Rgb32] image, which is stored in blue, green, red, alpha order
+    Bgra8(u8) { b, g, r, a }
+    /// Pixel of an [ImageFormat::Rgb48] image, which is stored in blue, green, red order
+    Bgr16(u16) { b, g, r }
+    /// Pixel of an [ImageFormat::Rgb64] image, which is stored in blue, green, red, alpha order
+    Bgra16(u16) { b, g, r, a }
+}
+
+pixel_format! {
+    u8 => { Mono8: 1, Raw8: 1, Rgb24: 3, Rgb32: 4 }
+    u16 => { Mono16: 1, Raw16: 1, Rgb48: 3, Rgb64: 4 }
+    u32 => { Raw32: 1 }
+    f32 => { Raw32Float: 1 }
+    Bgr8 => { Rgb24: 1 }
+    Bgra8 => { Rgb32: 1 }
+    Bgr16 => { Rgb48: 1 }
+    Bgra16 => { Rgb64: 1 }
+}
+
+#[cfg(feature = "image")]
+impl From<Bgr8> for Rgb<u8> {
+    fn from(pixel: Bgr8) -> Self {
+        Rgb([pixel.r, pixel.g, pixel.b])
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<Bgra8> for Rgba<u8> {
+    fn from(pixel: Bgra8) -> Self {
+        Rgba([pixel.r, pixel.g, pixel.b, pixel.a])
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<Bgr16> for Rgb<u16> {
+    fn from(pixel: Bgr16) -> Self {
+        Rgb([pixel.r, pixel.g, pixel.b])
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<Bgra16> for Rgba<u16> {
+    fn from(pixel: Bgra16) -> Self {
+        Rgba([pixel.r, pixel.g, pixel.b, pixel.a])
+    }
+}
@@ -0,0 +1,254 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::fmt::Debug;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for i32 {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+}
+
+/// Numeric parameter type that can be used in a [ParamRange].
+///
+/// This trait is sealed and implemented for all numeric parameter types of xiAPI.
+pub trait RangeValue: Copy + PartialOrd + Debug + private::Sealed {
+    /// Values of this type are integers
+    #[doc(hidden)]
+    const INTEGER: bool;
+
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+
+    #[doc(hidden)]
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! range_value {
+    ($($type:ty => $integer:literal,)*) => {
+        $(
+            impl RangeValue for $type {
+                const INTEGER: bool = $integer;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $type
+                }
+            }
+        )*
+    };
+}
+
+range_value! {
+    f32 => false,
+    i32 => true,
+    u32 => true,
+    u64 => true,
+}
+
+/// Tolerance for rounding errors of float parameters, relative to the increment
+const FLOAT_TOLERANCE: f64 = 1e-4;
+
+/// Range of legal values of a numeric parameter.
+///
+/// The legal values are `minimum + n * increment` for every `n` that does not exceed the maximum.
+/// An increment of zero means that every value between minimum and maximum is legal.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), xiapi::XiError> {
+///     let backend = std::sync::Arc::new(xiapi::SimBackend::new(1));
+///     let mut cam = xiapi::open_device_with_backend(backend, None)?;
+///     let range = cam.width_range()?;
+///     assert!(range.contains(640));
+///     assert_eq!(range.snap_down(650), 640);
+///     let width = cam.set_width_clamped(100_000)?;
+///     assert_eq!(width, range.maximum);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParamRange<T> {
+    /// Smallest legal value
+    pub minimum: T,
+    /// Largest value that may be set. It is not necessarily a legal value itself, see
+    /// [ParamRange::last].
+    pub maximum: T,
+    /// Distance between two legal values
+    pub increment: T,
+}
+
+impl<T: RangeValue> ParamRange<T> {
+    /// Create a new range
+    pub fn new(minimum: T, maximum: T, increment: T) -> Self {
+        ParamRange {
+            minimum,
+            maximum,
+            increment,
+        }
+    }
+
+    /// Number of increments between the minimum and `value`
+    fn steps(&self, value: T) -> f64 {
+        (value.to_f64() - self.minimum.to_f64()) / self.increment.to_f64()
+    }
+
+    /// The value that is `steps` increments above the minimum
+    fn value(&self, steps: f64) -> T {
+        T::from_f64(self.minimum.to_f64() + steps * self.increment.to_f64())
+    }
+
+    /// Round the number of steps, allowing for rounding errors of float parameters
+    fn round_steps(steps: f64, round: fn(f64) -> f64) -> f64 {
+        if !T::INTEGER && (steps - steps.round()).abs() < FLOAT_TOLERANCE {
+            steps.round()
+        } else {
+            round(steps)
+        }
+    }
+
+    fn is_continuous(&self) -> bool {
+        self.increment.to_f64() <= 0.0
+    }
+
+    /// The largest legal value
+    pub fn last(&self) -> T {
+        if self.maximum < self.minimum {
+            return self.minimum;
+        }
+        if self.is_continuous() {
+            return self.maximum;
+        }
+        self.value(Self::round_steps(self.steps(self.maximum), f64::floor))
+    }
+
+    /// Returns true if `value` is a legal value of this range
+    pub fn contains(&self, value: T) -> bool {
+        if value < self.minimum || value > self.maximum {
+            return false;
+        }
+        if self.is_continuous() {
+            return true;
+        }
+        let steps = self.steps(value);
+        if T::INTEGER {
+            steps.fract() == 0.0
+        } else {
+            (steps - steps.round()).abs() < FLOAT_TOLERANCE
+        }
+    }
+
+    /// Limit `value` to the minimum and maximum of this range, without snapping it to the
+    /// increment
+    pub fn clamp(&self, value: T) -> T {
+        if value < self.minimum {
+            self.minimum
+        } else if value > self.maximum {
+            self.maximum
+        } else {
+            value
+        }
+    }
+
+    /// The largest legal value that is less or equal to `value`, or the minimum if there is none
+    pub fn snap_down(&self, value: T) -> T {
+        self.snap(value, f64::floor)
+    }
+
+    /// The smallest legal value that is greater or equal to `value`, or the last legal value if
+    /// there is none
+    pub fn snap_up(&self, value: T) -> T {
+        self.snap(value, f64::ceil)
+    }
+
+    /// The legal value that is closest to `value`
+    pub fn snap_nearest(&self, value: T) -> T {
+        self.snap(value, f64::round)
+    }
+
+    fn snap(&self, value: T, round: fn(f64) -> f64) -> T {
+        if value <= self.minimum {
+            return self.minimum;
+        }
+        let last = self.last();
+        if value >= last {
+            return last;
+        }
+        if self.is_continuous() {
+            return value;
+        }
+        self.value(Self::round_steps(self.steps(value), round))
+    }
+
+    /// Iterate over all legal values in ascending order.
+    ///
+    /// If the increment is zero, only the minimum is returned.
+    pub fn iter(&self) -> ParamRangeIter<T> {
+        let count = if self.maximum < self.minimum {
+            0
+        } else if self.is_continuous() {
+            1
+        } else {
+            Self::round_steps(self.steps(self.maximum), f64::floor) as u64 + 1
+        };
+        ParamRangeIter {
+            range: *self,
+            next: 0,
+            count,
+        }
+    }
+}
+
+impl<T: RangeValue> IntoIterator for ParamRange<T> {
+    type Item = T;
+    type IntoIter = ParamRangeIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: RangeValue> IntoIterator for &ParamRange<T> {
+    type Item = T;
+    type IntoIter = ParamRangeIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the legal values of a [ParamRange]
+#[derive(Debug, Clone)]
+pub struct ParamRangeIter<T> {
+    range: ParamRange<T>,
+    next: u64,
+    count: u64,
+}
+
+impl<T: RangeValue> Iterator for ParamRangeIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next >= self.count {
+            return None;
+        }
+        let value = self.range.value(self.next as f64);
+        self.next += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.next) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: RangeValue> ExactSizeIterator for ParamRangeIter<T> {}
@@ -1,4 +1,6 @@
 /// Roi represents a region of interest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Roi {
     /// Offset from the left in the horizontal direction
     pub offset_x: u32,
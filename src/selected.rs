@@ -0,0 +1,170 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::fmt::Debug;
+use std::ops::{Deref, DerefMut};
+
+use crate::enums::*;
+use crate::Camera;
+use crate::XiError;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Selector parameter that determines which instance of other parameters is accessed, e.g.
+/// [GpoSelector] for [gpo_mode](Camera::gpo_mode).
+///
+/// This trait is sealed and implemented for all selector enums.
+pub trait Selector: Copy + Debug + 'static + private::Sealed {
+    #[doc(hidden)]
+    fn get(camera: &Camera) -> Result<Self, XiError>;
+
+    #[doc(hidden)]
+    fn set(camera: &mut Camera, value: Self) -> Result<(), XiError>;
+
+    #[doc(hidden)]
+    fn values() -> &'static [Self];
+}
+
+/// This macro implements [Selector] for enums using the getter and setter of their parameter
+macro_rules! selector {
+    ($($type:ty => $prm:ident,)*) => {
+        paste::paste! {
+            $(
+                impl private::Sealed for $type {}
+
+                impl Selector for $type {
+                    fn get(camera: &Camera) -> Result<Self, XiError> {
+                        camera.$prm()
+                    }
+
+                    fn set(camera: &mut Camera, value: Self) -> Result<(), XiError> {
+                        camera.[<set_ $prm>](value)
+                    }
+
+                    fn values() -> &'static [Self] {
+                        Self::VALUES
+                    }
+                }
+            )*
+        }
+    };
+}
+
+selector! {
+    GainSelector => gain_selector,
+    GpiSelector => gpi_selector,
+    GpoSelector => gpo_selector,
+    LedSelector => led_selector,
+    SensorFeatureSelector => sensor_feature_selector,
+    TriggerSelector => trg_selector,
+    CounterSelector => counter_selector,
+    TempSelector => temp_selector,
+}
+
+/// Camera with a selector set to a specific value.
+///
+/// The Selected guard is created by [Camera::select] and dereferences to the camera, so all
+/// parameters that depend on the selector can be accessed through it.
+/// When the guard is dropped, the selector is set back to its previous value. Because the guard
+/// borrows the camera mutably, no other code can observe or change the selector in the meantime.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use xiapi::{GpoMode, GpoSelector};
+/// # fn main() -> Result<(), xiapi::XiError> {
+///     let mut cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+///     let mut port2 = cam.select(GpoSelector::Port2)?;
+///     port2.set_gpo_mode(GpoMode::FrameActive)?;
+///     port2.restore()?;
+///     assert_eq!(cam.gpo_selector()?, GpoSelector::Port1);
+/// #   Ok(())
+/// # }
+/// ```
+pub struct Selected<'a, S: Selector> {
+    camera: &'a mut Camera,
+    previous: Option<S>,
+}
+
+impl<'a, S: Selector> Selected<'a, S> {
+    /// Set the selector back to its previous value.
+    ///
+    /// This is done automatically when the guard is dropped, but errors are ignored there.
+    pub fn restore(mut self) -> Result<(), XiError> {
+        self.restore_previous()
+    }
+
+    fn restore_previous(&mut self) -> Result<(), XiError> {
+        match self.previous.take() {
+            Some(previous) => S::set(self.camera, previous),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a, S: Selector> Deref for Selected<'a, S> {
+    type Target = Camera;
+
+    fn deref(&self) -> &Camera {
+        self.camera
+    }
+}
+
+impl<'a, S: Selector> DerefMut for Selected<'a, S> {
+    fn deref_mut(&mut self) -> &mut Camera {
+        self.camera
+    }
+}
+
+impl<'a, S: Selector> Drop for Selected<'a, S> {
+    fn drop(&mut self) {
+        let _ = self.restore_previous();
+    }
+}
+
+impl Camera {
+    /// Set a selector to `value` until the returned guard is dropped.
+    ///
+    /// See [Selected] for details.
+    pub fn select<S: Selector>(&mut self, value: S) -> Result<Selected<'_, S>, XiError> {
+        let previous = S::get(self)?;
+        S::set(self, value)?;
+        Ok(Selected {
+            camera: self,
+            previous: Some(previous),
+        })
+    }
+
+    /// Call `f` with a selector set to `value` and set it back to its previous value afterwards.
+    ///
+    /// The selector is restored even if `f` fails, the error of `f` takes precedence over an
+    /// error while restoring the selector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use xiapi::{GainSelector, GpoMode, GpoSelector};
+    /// # fn main() -> Result<(), xiapi::XiError> {
+    ///     let mut cam = xiapi::open_device_with_backend(Arc::new(xiapi::SimBackend::new(1)), None)?;
+    ///     cam.with_selected(GpoSelector::Port1, |gpo| gpo.set_gpo_mode(GpoMode::On))?;
+    ///     let analog_gain = cam.with_selected(GainSelector::AnalogAll, |cam| cam.gain())?;
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn with_selected<S: Selector, R>(
+        &mut self,
+        value: S,
+        f: impl FnOnce(&mut Camera) -> Result<R, XiError>,
+    ) -> Result<R, XiError> {
+        let mut selected = self.select(value)?;
+        let result = f(&mut selected);
+        let restored = selected.restore();
+        let result = result?;
+        restored.map(|_| result)
+    }
+}
@@ -0,0 +1,329 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::collections::BTreeMap;
+
+use paste::paste;
+
+use crate::enums::*;
+use crate::Camera;
+use crate::Roi;
+use crate::Selector;
+use crate::XiError;
+
+/// Read the parameters that have no selector into the fields of the same name
+macro_rules! read_settings {
+    ($cam:expr, $settings:expr; $($prm:ident),* $(,)?) => {
+        $($settings.$prm = unavailable_as_none($cam.$prm())?;)*
+    };
+}
+
+/// Write the fields that are set to the parameters of the same name, in the given order
+macro_rules! write_settings {
+    ($cam:expr, $settings:expr; $($prm:ident),* $(,)?) => {
+        paste! {
+            $(
+                if let Some(value) = $settings.$prm.clone() {
+                    $cam.[<set_ $prm>](value)?;
+                }
+            )*
+        }
+    };
+}
+
+/// Snapshot of the settings of a camera.
+///
+/// A snapshot is created with [Camera::snapshot_settings] and can be restored on the same or
+/// another camera of the same model with [Camera::apply_settings].
+/// Parameters that are not supported by the camera are `None` (or empty for parameters that
+/// depend on a selector) and are left unchanged when the settings are applied.
+///
+/// With the `serde` feature, the settings can be serialized, e.g. to store the configuration of a
+/// camera in a JSON or TOML file. Missing fields are treated as `None` when deserializing.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), xiapi::XiError> {
+///     let backend = Arc::new(xiapi::SimBackend::new(2));
+///     let mut cam = xiapi::open_device_with_backend(backend.clone(), Some(0))?;
+///     cam.set_exposure(5000.0)?;
+///     let settings = cam.snapshot_settings()?;
+///
+///     let mut other = xiapi::open_device_with_backend(backend, Some(1))?;
+///     other.apply_settings(&settings)?;
+///     assert_eq!(other.exposure()?, 5000.0);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[non_exhaustive]
+pub struct CameraSettings {
+    /// See [Camera::image_data_format]
+    pub image_data_format: Option<ImageFormat>,
+    /// See [Camera::sensor_data_bit_depth]
+    pub sensor_data_bit_depth: Option<BitDepth>,
+    /// See [Camera::output_data_bit_depth]
+    pub output_data_bit_depth: Option<BitDepth>,
+    /// See [Camera::image_data_bit_depth]
+    pub image_data_bit_depth: Option<BitDepth>,
+    /// See [Camera::downsampling_type]
+    pub downsampling_type: Option<DownsamplingType>,
+    /// See [Camera::downsampling]
+    pub downsampling: Option<Downsampling>,
+    /// See [Camera::roi]
+    pub roi: Option<Roi>,
+    /// See [Camera::horizontal_flip]
+    pub horizontal_flip: Option<bool>,
+    /// See [Camera::vertical_flip]
+    pub vertical_flip: Option<bool>,
+    /// See [Camera::test_pattern_generator_selector]
+    pub test_pattern_generator_selector: Option<TestPatternGenerator>,
+    /// See [Camera::test_pattern]
+    pub test_pattern: Option<TestPattern>,
+    /// See [Camera::acq_timing_mode]
+    pub acq_timing_mode: Option<AcqTimingMode>,
+    /// See [Camera::exposure]
+    pub exposure: Option<f32>,
+    /// See [Camera::framerate]. Only stored if the frame rate is not free running.
+    pub framerate: Option<f32>,
+    /// See [Camera::exposure_burst_count]
+    pub exposure_burst_count: Option<i32>,
+    /// See [Camera::gain], for each [GainSelector]
+    pub gain: BTreeMap<GainSelector, f32>,
+    /// See [Camera::trg_selector]
+    pub trg_selector: Option<TriggerSelector>,
+    /// See [Camera::trg_overlap]
+    pub trg_overlap: Option<TriggerOverlap>,
+    /// See [Camera::acq_frame_burst_count]
+    pub acq_frame_burst_count: Option<u32>,
+    /// See [Camera::trg_source]
+    pub trg_source: Option<TriggerSource>,
+    /// See [Camera::gpi_mode], for each [GpiSelector]
+    pub gpi_mode: BTreeMap<GpiSelector, GpiMode>,
+    /// See [Camera::debounce_en], for each [GpiSelector]
+    pub debounce_en: BTreeMap<GpiSelector, bool>,
+    /// See [Camera::gpo_mode], for each [GpoSelector]
+    pub gpo_mode: BTreeMap<GpoSelector, GpoMode>,
+    /// See [Camera::led_mode], for each [LedSelector]
+    pub led_mode: BTreeMap<LedSelector, LedMode>,
+    /// See [Camera::sensor_feature_value], for each [SensorFeatureSelector]
+    pub sensor_feature_value: BTreeMap<SensorFeatureSelector, i32>,
+    /// See [Camera::column_fpn_correction]
+    pub column_fpn_correction: Option<bool>,
+    /// See [Camera::row_fpn_correction]
+    pub row_fpn_correction: Option<bool>,
+    /// See [Camera::column_black_offset_correction]
+    pub column_black_offset_correction: Option<bool>,
+    /// See [Camera::row_black_offset_correction]
+    pub row_black_offset_correction: Option<bool>,
+    /// See [Camera::wb_kr]
+    pub wb_kr: Option<f32>,
+    /// See [Camera::wb_kg]
+    pub wb_kg: Option<f32>,
+    /// See [Camera::wb_kb]
+    pub wb_kb: Option<f32>,
+    /// See [Camera::auto_wb]
+    pub auto_wb: Option<bool>,
+    /// See [Camera::limit_bandwidth]
+    pub limit_bandwidth: Option<i32>,
+    /// See [Camera::image_user_data]
+    pub image_user_data: Option<u32>,
+    /// See [Camera::buffer_policy]
+    pub buffer_policy: Option<BufferPolicy>,
+    /// See [Camera::buffers_queue_size]
+    pub buffers_queue_size: Option<i32>,
+    /// See [Camera::recent_frame]
+    pub recent_frame: Option<bool>,
+    /// See [Camera::transport_data_target]
+    pub transport_data_target: Option<TransportDataTarget>,
+}
+
+/// Returns true if `err` means that a parameter (or the selected value of its selector) is not
+/// available on this camera
+fn is_unavailable(err: &XiError) -> bool {
+    matches!(
+        err.root(),
+        XiError::NotSupported
+            | XiError::NotImplemented
+            | XiError::NotSupportedParam
+            | XiError::UnknownParam
+            | XiError::ParamConditionallyNotAvailable
+            | XiError::WrongParamValue
+            | XiError::OutOfRange
+    )
+}
+
+fn unavailable_as_none<T>(result: Result<T, XiError>) -> Result<Option<T>, XiError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if is_unavailable(&err) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+impl Camera {
+    /// Read all settings of this camera.
+    ///
+    /// Parameters that depend on a selector (e.g. [gain](Camera::gain) on
+    /// [gain_selector](Camera::gain_selector)) are read for every value of the selector that the
+    /// camera supports. The selectors are restored afterwards.
+    pub fn snapshot_settings(&mut self) -> Result<CameraSettings, XiError> {
+        let mut settings = CameraSettings::default();
+        read_settings!(self, settings;
+            image_data_format,
+            sensor_data_bit_depth,
+            output_data_bit_depth,
+            image_data_bit_depth,
+            downsampling_type,
+            downsampling,
+            roi,
+            horizontal_flip,
+            vertical_flip,
+            test_pattern_generator_selector,
+            test_pattern,
+            acq_timing_mode,
+            exposure,
+            exposure_burst_count,
+            trg_selector,
+            trg_overlap,
+            acq_frame_burst_count,
+            trg_source,
+            column_fpn_correction,
+            row_fpn_correction,
+            column_black_offset_correction,
+            row_black_offset_correction,
+            wb_kr,
+            wb_kg,
+            wb_kb,
+            auto_wb,
+            limit_bandwidth,
+            image_user_data,
+            buffer_policy,
+            buffers_queue_size,
+            recent_frame,
+            transport_data_target,
+        );
+        if !matches!(
+            settings.acq_timing_mode,
+            None | Some(AcqTimingMode::FreeRun)
+        ) {
+            settings.framerate = unavailable_as_none(self.framerate())?;
+        }
+
+        settings.gain = self.read_selected(Camera::gain)?;
+        settings.gpi_mode = self.read_selected(Camera::gpi_mode)?;
+        settings.debounce_en = self.read_selected(Camera::debounce_en)?;
+        settings.gpo_mode = self.read_selected(Camera::gpo_mode)?;
+        settings.led_mode = self.read_selected(Camera::led_mode)?;
+        settings.sensor_feature_value = self.read_selected(Camera::sensor_feature_value)?;
+        Ok(settings)
+    }
+
+    /// Write `settings` to this camera.
+    ///
+    /// Parameters are written in an order that respects their dependencies: The image format is
+    /// set before the bit depths, downsampling before the region of interest and the width and
+    /// height before the offsets (see [set_roi](Camera::set_roi)). The exposure time is set again
+    /// after the frame rate if the previous frame rate did not allow it.
+    /// Parameters that are `None` are left unchanged, the selectors are restored after setting the
+    /// parameters that depend on them.
+    pub fn apply_settings(&mut self, settings: &CameraSettings) -> Result<(), XiError> {
+        write_settings!(self, settings;
+            image_data_format,
+            sensor_data_bit_depth,
+            output_data_bit_depth,
+            image_data_bit_depth,
+            downsampling_type,
+            downsampling,
+        );
+        if let Some(roi) = &settings.roi {
+            self.set_roi(roi)?;
+        }
+        write_settings!(self, settings;
+            horizontal_flip,
+            vertical_flip,
+            test_pattern_generator_selector,
+            test_pattern,
+            acq_timing_mode,
+        );
+        let exposure_failed = match settings.exposure {
+            Some(exposure) => self.set_exposure(exposure).is_err(),
+            None => false,
+        };
+        write_settings!(self, settings; framerate);
+        if let (true, Some(exposure)) = (exposure_failed, settings.exposure) {
+            self.set_exposure(exposure)?;
+        }
+        write_settings!(self, settings; exposure_burst_count);
+
+        self.write_selected(&settings.gain, Camera::set_gain)?;
+        write_settings!(self, settings;
+            trg_selector,
+            trg_overlap,
+            acq_frame_burst_count,
+            trg_source,
+        );
+        self.write_selected(&settings.gpi_mode, Camera::set_gpi_mode)?;
+        self.write_selected(&settings.debounce_en, Camera::set_debounce_en)?;
+        self.write_selected(&settings.gpo_mode, Camera::set_gpo_mode)?;
+        self.write_selected(&settings.led_mode, Camera::set_led_mode)?;
+        self.write_selected(
+            &settings.sensor_feature_value,
+            Camera::set_sensor_feature_value,
+        )?;
+
+        write_settings!(self, settings;
+            column_fpn_correction,
+            row_fpn_correction,
+            column_black_offset_correction,
+            row_black_offset_correction,
+            wb_kr,
+            wb_kg,
+            wb_kb,
+            auto_wb,
+            limit_bandwidth,
+            image_user_data,
+            buffer_policy,
+            buffers_queue_size,
+            recent_frame,
+            transport_data_target,
+        );
+        Ok(())
+    }
+
+    /// Read a parameter for every value of its selector that is available on this camera
+    fn read_selected<S: Selector + Ord, T>(
+        &mut self,
+        get: fn(&Camera) -> Result<T, XiError>,
+    ) -> Result<BTreeMap<S, T>, XiError> {
+        let mut values = BTreeMap::new();
+        for &selector in S::values() {
+            let selected = match self.select(selector) {
+                Ok(selected) => selected,
+                Err(err) if is_unavailable(&err) => continue,
+                Err(err) => return Err(err),
+            };
+            if let Some(value) = unavailable_as_none(get(&selected))? {
+                values.insert(selector, value);
+            }
+            selected.restore()?;
+        }
+        Ok(values)
+    }
+
+    /// Write a parameter for every selector in `values`
+    fn write_selected<S: Selector, T: Copy>(
+        &mut self,
+        values: &BTreeMap<S, T>,
+        set: fn(&mut Camera, T) -> Result<(), XiError>,
+    ) -> Result<(), XiError> {
+        values
+            .iter()
+            .try_for_each(|(selector, value)| self.with_selected(*selector, |cam| set(cam, *value)))
+    }
+}
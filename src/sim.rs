@@ -0,0 +1,1538 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+use std::mem::size_of;
+use std::os::raw::c_void;
+use std::sync::Mutex;
+
+use crate::sys::XI_PRM_TYPE::*;
+use crate::sys::XI_RET::*;
+use crate::sys::*;
+
+use crate::Backend;
+
+/// Simulated camera system that runs entirely in-process.
+///
+/// The SimBackend can be used instead of the [XiApi](crate::XiApi) backend to test code that uses
+/// this crate on machines without a XIMEA camera (e.g. in CI).
+/// Every simulated camera emulates a XIMEA camera with the sensor described by its [SensorModel].
+/// It models the parameters including their ranges and increments, rejects invalid values with
+/// the same error codes as xiAPI and generates frames according to the selected `test_pattern`.
+///
+/// The emulation covers:
+/// - All image formats listed in [SensorModel::image_formats] with the bit depth selected by
+///   `image_data_bit_depth` (8 bit formats always use 8 bits).
+/// - Bayer mosaics for RAW formats of color sensors and BGR(A) byte order for RGB formats.
+/// - The coupling of exposure time and frame rate: The frame rate can not exceed
+///   `1 / exposure`, and in `XI_ACQ_TIMING_MODE_FRAME_RATE` the exposure time is limited by the
+///   frame rate.
+/// - Triggers: With `trg_source` set to `XI_TRG_SOFTWARE`, every write to `trg_software`
+///   releases one frame (or `acq_frame_burst_count` frames if `trg_selector` is
+///   `XI_TRG_SEL_FRAME_BURST_START`). Frames that have not been triggered time out immediately,
+///   the simulation never blocks. Hardware trigger sources never fire.
+/// - User sets: Loading one of the user sets in [SensorModel::user_sets] sets the sensor, output
+///   and image data bit depth to the bit depth of the user set (if it is supported) and the gain
+///   of `XI_GAIN_SELECTOR_ALL` to 0 dB for low gain and 12 dB for high gain user sets.
+///   `user_set_default` is loaded whenever the camera is opened.
+/// - Frame counters and timestamps: `nframe` counts all frames since the device was opened,
+///   `acq_nframe` the frames since the acquisition was started (both starting at 1). Timestamps
+///   advance by the frame period of the simulated camera instead of the wall clock time.
+/// - Buffers: Every frame is stored in a buffer of its own, which stays valid until the
+///   acquisition is stopped or the device is closed (see [Backend]). Unlike xiAPI, older
+///   frames are never overwritten, so long acquisitions use more memory.
+///
+/// The following values of `test_pattern` are supported (`max` is the largest value for the
+/// current bit depth, `x` and `y` are absolute sensor coordinates, `n` is `nframe`).
+/// These formulas have not been compared to the output of a real camera yet, so images of the
+/// simulator may still differ from those of a camera with the same `test_pattern`.
+/// - `XI_TESTPAT_OFF`: A static scene whose brightness scales with exposure time and gain.
+/// - `XI_TESTPAT_BLACK` / `XI_TESTPAT_WHITE`: All pixels are 0 / `max`.
+/// - `XI_TESTPAT_GREY_HORIZ_RAMP` / `XI_TESTPAT_GREY_VERT_RAMP`: `x` / `y` modulo `max + 1`.
+/// - `XI_TESTPAT_GREY_HORIZ_RAMP_MOVING` / `XI_TESTPAT_GREY_VERT_RAMP_MOVING`: `x + n` /
+///   `y + n` modulo `max + 1`.
+/// - `XI_TESTPAT_HORIZ_LINE_MOVING` / `XI_TESTPAT_VERT_LINE_MOVING`: A white line in row /
+///   column `n` modulo the sensor height / width on a black background.
+/// - `XI_TESTPAT_COLOR_BAR`: Eight vertical bars across the sensor in the order white, yellow,
+///   cyan, green, magenta, red, blue and black.
+/// - `XI_TESTPAT_FRAME_COUNTER`: All pixels are `n` modulo `max + 1`.
+/// - `XI_TESTPAT_DEVICE_SPEC_COUNTER`: Pixels count up from 0 in row major order, modulo
+///   `max + 1`.
+///
+/// Monochrome formats contain the luminance (`(299 * R + 587 * G + 114 * B) / 1000`) of colored
+/// patterns.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), xiapi::XiError>{
+/// let backend = Arc::new(xiapi::SimBackend::new(2));
+/// let cam = xiapi::open_device_with_backend(backend, Some(1))?;
+/// let buffer = cam.start_acquisition()?;
+/// let image = buffer.next_image::<u8>(None)?;
+/// assert_eq!(image.width(), 1280);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// To use the emulated cameras from [open_device](crate::open_device), install the SimBackend
+/// as default backend:
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use xiapi::XI_COLOR_FILTER_ARRAY::XI_CFA_BAYER_RGGB;
+/// # fn main() -> Result<(), xiapi::XiError>{
+/// let model = xiapi::SensorModel::color(640, 480, XI_CFA_BAYER_RGGB);
+/// xiapi::set_default_backend(Arc::new(xiapi::SimBackend::with_models([model])));
+/// let cam = xiapi::open_device(None)?;
+/// assert_eq!(cam.color_filter_array()?, xiapi::ColorFilterArray::BayerRggb);
+/// # Ok(())
+/// # }
+/// ```
+pub struct SimBackend {
+    state: Mutex<SimState>,
+}
+
+/// Description of the sensor of a simulated camera.
+///
+/// # Examples
+///
+/// ```
+/// # use xiapi::XI_IMG_FORMAT::*;
+/// let mut model = xiapi::SensorModel::mono(2048, 1088);
+/// model.image_formats = vec![XI_MONO8, XI_MONO16, XI_RAW8, XI_RAW16, XI_RAW32FLOAT];
+/// model.max_framerate = 170.0;
+/// let backend = xiapi::SimBackend::with_models([model]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorModel {
+    /// Model name of the camera, reported as `device_name`.
+    pub name: String,
+    /// User defined ID of the camera, reported as `device_user_id`.
+    /// Changes made through the camera are kept until the SimBackend is dropped.
+    pub user_id: String,
+    /// Width of the sensor in pixels.
+    pub width: u32,
+    /// Height of the sensor in pixels.
+    pub height: u32,
+    /// Color filter array of the sensor (`XI_CFA_NONE` for monochrome sensors).
+    pub color_filter_array: XI_COLOR_FILTER_ARRAY::Type,
+    /// Supported values for `image_data_format`. The first entry is the default.
+    pub image_formats: Vec<XI_IMG_FORMAT::Type>,
+    /// Supported values for the data bit depth parameters. The highest entry is the default.
+    pub bit_depths: Vec<XI_BIT_DEPTH::Type>,
+    /// Highest frame rate of the sensor in frames per second.
+    pub max_framerate: f32,
+    /// Shortest exposure time in microseconds.
+    pub min_exposure: f32,
+    /// Longest exposure time in microseconds.
+    pub max_exposure: f32,
+    /// Supported values for `user_set_selector`. The camera has no user set parameters if this is
+    /// empty.
+    pub user_sets: Vec<XI_USER_SET_SELECTOR::Type>,
+    /// User set that is loaded when the camera is opened, reported as `user_set_default`.
+    /// Changes made through the camera are kept until the SimBackend is dropped.
+    pub default_user_set: XI_USER_SET_SELECTOR::Type,
+    /// Alignment of image lines in bytes. Lines are padded to a multiple of this value, the
+    /// padding is reported in `padding_x` of each image.
+    pub line_alignment: u32,
+}
+
+impl SensorModel {
+    /// Create the model of a monochrome sensor with the given resolution.
+    pub fn mono(width: u32, height: u32) -> Self {
+        use crate::sys::XI_IMG_FORMAT::*;
+        SensorModel {
+            name: "SIM-MONO".to_string(),
+            user_id: String::new(),
+            width,
+            height,
+            color_filter_array: XI_COLOR_FILTER_ARRAY::XI_CFA_NONE,
+            image_formats: vec![XI_MONO8, XI_MONO16, XI_RAW8, XI_RAW16],
+            bit_depths: vec![
+                XI_BIT_DEPTH::XI_BPP_8,
+                XI_BIT_DEPTH::XI_BPP_10,
+                XI_BIT_DEPTH::XI_BPP_12,
+            ],
+            max_framerate: 60.0,
+            min_exposure: 10.0,
+            max_exposure: 1_000_000.0,
+            user_sets: vec![
+                XI_USER_SET_SELECTOR::XI_US_12_STD_L,
+                XI_USER_SET_SELECTOR::XI_US_12_STD_H,
+            ],
+            default_user_set: XI_USER_SET_SELECTOR::XI_US_NONE,
+            line_alignment: 1,
+        }
+    }
+
+    /// Create the model of a color sensor with the given resolution and Bayer filter.
+    pub fn color(width: u32, height: u32, color_filter_array: XI_COLOR_FILTER_ARRAY::Type) -> Self {
+        use crate::sys::XI_IMG_FORMAT::*;
+        SensorModel {
+            name: "SIM-COLOR".to_string(),
+            color_filter_array,
+            image_formats: vec![
+                XI_MONO8, XI_MONO16, XI_RGB24, XI_RGB32, XI_RGB48, XI_RGB64, XI_RAW8, XI_RAW16,
+            ],
+            ..Self::mono(width, height)
+        }
+    }
+}
+
+impl Default for SensorModel {
+    /// A monochrome sensor with a resolution of 1280x1024 pixels
+    fn default() -> Self {
+        Self::mono(1280, 1024)
+    }
+}
+
+struct SimState {
+    devices: Vec<SimDevice>,
+    globals: BTreeMap<String, Param>,
+}
+
+/// Value of a simulated parameter
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    Int(i32),
+    Float(f32),
+    Int64(u64),
+}
+
+impl Value {
+    fn as_i32(self) -> i32 {
+        match self {
+            Value::Int(v) => v,
+            Value::Float(v) => v.round() as i32,
+            Value::Int64(v) => v as i32,
+        }
+    }
+
+    fn as_f32(self) -> f32 {
+        match self {
+            Value::Int(v) => v as f32,
+            Value::Float(v) => v,
+            Value::Int64(v) => v as f32,
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Value::Int(v) => v as f64,
+            Value::Float(v) => v as f64,
+            Value::Int64(v) => v as f64,
+        }
+    }
+
+    fn as_u64(self) -> u64 {
+        match self {
+            Value::Int(v) => v as u64,
+            Value::Float(v) => v as u64,
+            Value::Int64(v) => v,
+        }
+    }
+
+    /// Convert `self` to the same type as `other`
+    fn convert_like(self, other: Value) -> Value {
+        match other {
+            Value::Int(_) => Value::Int(self.as_i32()),
+            Value::Float(_) => Value::Float(self.as_f32()),
+            Value::Int64(_) => Value::Int64(self.as_u64()),
+        }
+    }
+
+    fn xi_type(self) -> XI_PRM_TYPE::Type {
+        match self {
+            Value::Int(_) => xiTypeInteger,
+            Value::Float(_) => xiTypeFloat,
+            Value::Int64(_) => xiTypeInteger64,
+        }
+    }
+}
+
+/// A simulated parameter with its range and current value(s)
+struct Param {
+    /// Current values, indexed by the value of the selector (or 0 if there is no selector)
+    values: BTreeMap<i32, Value>,
+    default: Value,
+    min: Value,
+    max: Value,
+    inc: Value,
+    /// Allowed values for enumerations
+    allowed: Option<Vec<i32>>,
+    /// Name of the parameter that selects which value is accessed
+    selector: Option<String>,
+    read_only: bool,
+    /// Parameter can be changed while the acquisition is running
+    live: bool,
+    /// Parameter supports the `:direct_update` modifier
+    direct: bool,
+}
+
+impl Param {
+    fn int(value: i32, min: i32, max: i32, inc: i32) -> Self {
+        Self::new(
+            Value::Int(value),
+            Value::Int(min),
+            Value::Int(max),
+            Value::Int(inc),
+        )
+    }
+
+    fn float(value: f32, min: f32, max: f32, inc: f32) -> Self {
+        Self::new(
+            Value::Float(value),
+            Value::Float(min),
+            Value::Float(max),
+            Value::Float(inc),
+        )
+    }
+
+    fn enumeration(value: u32, allowed: &[u32]) -> Self {
+        let min = allowed.iter().copied().min().unwrap_or(value);
+        let max = allowed.iter().copied().max().unwrap_or(value);
+        let mut param = Self::int(value as i32, min as i32, max as i32, 1);
+        param.allowed = Some(allowed.iter().map(|v| *v as i32).collect());
+        param
+    }
+
+    fn switch(value: XI_SWITCH::Type) -> Self {
+        Self::enumeration(value, &[XI_SWITCH::XI_OFF, XI_SWITCH::XI_ON])
+    }
+
+    fn new(value: Value, min: Value, max: Value, inc: Value) -> Self {
+        Param {
+            values: BTreeMap::new(),
+            default: value,
+            min,
+            max,
+            inc,
+            allowed: None,
+            selector: None,
+            read_only: false,
+            live: false,
+            direct: false,
+        }
+    }
+
+    fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    fn live(mut self) -> Self {
+        self.live = true;
+        self
+    }
+
+    fn direct(mut self) -> Self {
+        self.direct = true;
+        self
+    }
+
+    fn selected_by(mut self, selector: &[u8]) -> Self {
+        self.selector = Some(prm_name(selector).to_string());
+        self
+    }
+
+    /// Check if `value` is a valid value for this parameter
+    fn check(&self, value: Value, min: Value, max: Value, inc: Value) -> Result<(), XI_RETURN> {
+        if let Some(allowed) = &self.allowed {
+            return match allowed.contains(&value.as_i32()) {
+                true => Ok(()),
+                false => Err(XI_WRONG_PARAM_VALUE as XI_RETURN),
+            };
+        }
+        if value.as_f32() < min.as_f32() || value.as_f32() > max.as_f32() {
+            return Err(XI_OUT_OF_RANGE as XI_RETURN);
+        }
+        if let Value::Int(value) = value {
+            let inc = inc.as_i32();
+            if inc > 1 && (value - min.as_i32()) % inc != 0 {
+                return Err(XI_WRONG_PARAM_VALUE as XI_RETURN);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Convert an `XI_PRM_*` constant to a str
+fn prm_name(prm: &[u8]) -> &str {
+    std::str::from_utf8(prm)
+        .expect("UTF8 error on API constant -> Unreachable")
+        .trim_end_matches(char::from(0))
+}
+
+/// Supported values for `test_pattern`
+const TEST_PATTERNS: [u32; 12] = [
+    XI_TEST_PATTERN::XI_TESTPAT_OFF,
+    XI_TEST_PATTERN::XI_TESTPAT_BLACK,
+    XI_TEST_PATTERN::XI_TESTPAT_WHITE,
+    XI_TEST_PATTERN::XI_TESTPAT_GREY_HORIZ_RAMP,
+    XI_TEST_PATTERN::XI_TESTPAT_GREY_VERT_RAMP,
+    XI_TEST_PATTERN::XI_TESTPAT_GREY_HORIZ_RAMP_MOVING,
+    XI_TEST_PATTERN::XI_TESTPAT_GREY_VERT_RAMP_MOVING,
+    XI_TEST_PATTERN::XI_TESTPAT_HORIZ_LINE_MOVING,
+    XI_TEST_PATTERN::XI_TESTPAT_VERT_LINE_MOVING,
+    XI_TEST_PATTERN::XI_TESTPAT_COLOR_BAR,
+    XI_TEST_PATTERN::XI_TESTPAT_FRAME_COUNTER,
+    XI_TEST_PATTERN::XI_TESTPAT_DEVICE_SPEC_COUNTER,
+];
+
+/// Values of the version parameters reported by every simulated camera
+const VERSIONS: [(&[u8], &str); 7] = [
+    (XI_PRM_API_VERSION, env!("CARGO_PKG_VERSION")),
+    (XI_PRM_DRV_VERSION, "sim"),
+    (XI_PRM_MCU1_VERSION, "1.0"),
+    (XI_PRM_MCU2_VERSION, "1.0"),
+    (XI_PRM_MCU3_VERSION, "1.0"),
+    (XI_PRM_FPGA1_VERSION, "1.0"),
+    (XI_PRM_XMLMAN_VERSION, "1.0"),
+];
+
+/// Colors of the bars in `XI_TESTPAT_COLOR_BAR` as (red, green, blue)
+const COLOR_BARS: [[u32; 3]; 8] = [
+    [1, 1, 1],
+    [1, 1, 0],
+    [0, 1, 1],
+    [0, 1, 0],
+    [1, 0, 1],
+    [1, 0, 0],
+    [0, 0, 1],
+    [0, 0, 0],
+];
+
+struct SimDevice {
+    /// Index of the device
+    dev_id: u32,
+    model: SensorModel,
+    params: BTreeMap<String, Param>,
+    open: bool,
+    acquiring: bool,
+    /// Buffers of all frames of the current acquisition.
+    /// They are kept until the acquisition is stopped or the device is closed, because images may
+    /// still refer to them.
+    frames: Vec<Box<[u64]>>,
+    acq_nframe: u32,
+    nframe: u32,
+    /// Number of frames that have been triggered but not yet retrieved
+    pending_triggers: u32,
+    /// Simulated device clock in nanoseconds
+    clock_ns: u64,
+}
+
+impl SimDevice {
+    fn new(dev_id: u32, model: &SensorModel) -> Self {
+        let mut params = BTreeMap::new();
+        let mut add = |prm: &[u8], param: Param| {
+            params.insert(prm_name(prm).to_string(), param);
+        };
+        // The ranges of exposure, frame rate and ROI depend on other parameters,
+        // see [Self::range()]
+        add(
+            XI_PRM_EXPOSURE,
+            Param::float(10_000.0, model.min_exposure, model.max_exposure, 1.0)
+                .live()
+                .direct(),
+        );
+        add(XI_PRM_EXPOSURE_BURST_COUNT, Param::int(1, 1, 16, 1));
+        add(
+            XI_PRM_GAIN,
+            Param::float(0.0, 0.0, 24.0, 0.1)
+                .live()
+                .direct()
+                .selected_by(XI_PRM_GAIN_SELECTOR),
+        );
+        add(
+            XI_PRM_GAIN_SELECTOR,
+            Param::enumeration(
+                XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ALL,
+                &[
+                    XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ALL,
+                    XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ANALOG_ALL,
+                    XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_DIGITAL_ALL,
+                ],
+            )
+            .live(),
+        );
+        add(
+            XI_PRM_DOWNSAMPLING,
+            Param::enumeration(
+                XI_DOWNSAMPLING_VALUE::XI_DWN_1x1,
+                &[XI_DOWNSAMPLING_VALUE::XI_DWN_1x1],
+            ),
+        );
+        add(
+            XI_PRM_DOWNSAMPLING_TYPE,
+            Param::enumeration(
+                XI_DOWNSAMPLING_TYPE::XI_BINNING,
+                &[
+                    XI_DOWNSAMPLING_TYPE::XI_BINNING,
+                    XI_DOWNSAMPLING_TYPE::XI_SKIPPING,
+                ],
+            ),
+        );
+        add(
+            XI_PRM_IMAGE_DATA_FORMAT,
+            Param::enumeration(model.image_formats[0], &model.image_formats),
+        );
+        add(
+            XI_PRM_TEST_PATTERN_GENERATOR_SELECTOR,
+            Param::enumeration(
+                XI_TEST_PATTERN_GENERATOR::XI_TESTPAT_GEN_FPGA,
+                &[
+                    XI_TEST_PATTERN_GENERATOR::XI_TESTPAT_GEN_SENSOR,
+                    XI_TEST_PATTERN_GENERATOR::XI_TESTPAT_GEN_FPGA,
+                ],
+            ),
+        );
+        add(
+            XI_PRM_TEST_PATTERN,
+            Param::enumeration(XI_TEST_PATTERN::XI_TESTPAT_OFF, &TEST_PATTERNS),
+        );
+        add(XI_PRM_WIDTH, Param::int(model.width as i32, 32, 0, 16));
+        add(XI_PRM_HEIGHT, Param::int(model.height as i32, 8, 0, 2));
+        add(XI_PRM_OFFSET_X, Param::int(0, 0, 0, 16));
+        add(XI_PRM_OFFSET_Y, Param::int(0, 0, 0, 2));
+        add(XI_PRM_HORIZONTAL_FLIP, Param::switch(XI_SWITCH::XI_OFF));
+        add(XI_PRM_VERTICAL_FLIP, Param::switch(XI_SWITCH::XI_OFF));
+        add(XI_PRM_LIMIT_BANDWIDTH, Param::int(3000, 50, 3000, 1));
+        add(
+            XI_PRM_AVAILABLE_BANDWIDTH,
+            Param::int(3000, 3000, 3000, 1).read_only(),
+        );
+        add(
+            XI_PRM_TRG_SOURCE,
+            Param::enumeration(
+                XI_TRG_SOURCE::XI_TRG_OFF,
+                &[
+                    XI_TRG_SOURCE::XI_TRG_OFF,
+                    XI_TRG_SOURCE::XI_TRG_EDGE_RISING,
+                    XI_TRG_SOURCE::XI_TRG_EDGE_FALLING,
+                    XI_TRG_SOURCE::XI_TRG_SOFTWARE,
+                    XI_TRG_SOURCE::XI_TRG_LEVEL_HIGH,
+                    XI_TRG_SOURCE::XI_TRG_LEVEL_LOW,
+                ],
+            ),
+        );
+        add(
+            XI_PRM_TRG_SELECTOR,
+            Param::enumeration(
+                XI_TRG_SELECTOR::XI_TRG_SEL_FRAME_START,
+                &[
+                    XI_TRG_SELECTOR::XI_TRG_SEL_FRAME_START,
+                    XI_TRG_SELECTOR::XI_TRG_SEL_FRAME_BURST_START,
+                ],
+            ),
+        );
+        add(
+            XI_PRM_TRG_OVERLAP,
+            Param::enumeration(
+                XI_TRG_OVERLAP::XI_TRG_OVERLAP_OFF,
+                &[XI_TRG_OVERLAP::XI_TRG_OVERLAP_OFF],
+            ),
+        );
+        add(XI_PRM_TRG_SOFTWARE, Param::int(0, 0, 1, 1).live());
+        add(XI_PRM_ACQ_FRAME_BURST_COUNT, Param::int(1, 1, 65535, 1));
+        add(
+            XI_PRM_ACQ_TIMING_MODE,
+            Param::enumeration(
+                XI_ACQ_TIMING_MODE::XI_ACQ_TIMING_MODE_FREE_RUN,
+                &[
+                    XI_ACQ_TIMING_MODE::XI_ACQ_TIMING_MODE_FREE_RUN,
+                    XI_ACQ_TIMING_MODE::XI_ACQ_TIMING_MODE_FRAME_RATE,
+                ],
+            ),
+        );
+        add(
+            XI_PRM_FRAMERATE,
+            Param::float(model.max_framerate, 1.0, model.max_framerate, 0.1),
+        );
+        let gpi = [XI_GPI_SELECTOR::XI_GPI_PORT1, XI_GPI_SELECTOR::XI_GPI_PORT2];
+        add(XI_PRM_GPI_SELECTOR, Param::enumeration(gpi[0], &gpi).live());
+        add(
+            XI_PRM_GPI_MODE,
+            Param::enumeration(
+                XI_GPI_MODE::XI_GPI_OFF,
+                &[XI_GPI_MODE::XI_GPI_OFF, XI_GPI_MODE::XI_GPI_TRIGGER],
+            )
+            .selected_by(XI_PRM_GPI_SELECTOR),
+        );
+        add(
+            XI_PRM_DEBOUNCE_EN,
+            Param::switch(XI_SWITCH::XI_OFF).selected_by(XI_PRM_GPI_SELECTOR),
+        );
+        let gpo = [XI_GPO_SELECTOR::XI_GPO_PORT1, XI_GPO_SELECTOR::XI_GPO_PORT2];
+        add(XI_PRM_GPO_SELECTOR, Param::enumeration(gpo[0], &gpo).live());
+        add(
+            XI_PRM_GPO_MODE,
+            Param::enumeration(
+                XI_GPO_MODE::XI_GPO_OFF,
+                &[
+                    XI_GPO_MODE::XI_GPO_OFF,
+                    XI_GPO_MODE::XI_GPO_ON,
+                    XI_GPO_MODE::XI_GPO_FRAME_ACTIVE,
+                    XI_GPO_MODE::XI_GPO_EXPOSURE_ACTIVE,
+                ],
+            )
+            .live()
+            .selected_by(XI_PRM_GPO_SELECTOR),
+        );
+        let leds = [XI_LED_SELECTOR::XI_LED_SEL1, XI_LED_SELECTOR::XI_LED_SEL2];
+        add(
+            XI_PRM_LED_SELECTOR,
+            Param::enumeration(leds[0], &leds).live(),
+        );
+        add(
+            XI_PRM_LED_MODE,
+            Param::enumeration(
+                XI_LED_MODE::XI_LED_HEARTBEAT,
+                &[
+                    XI_LED_MODE::XI_LED_HEARTBEAT,
+                    XI_LED_MODE::XI_LED_TRIGGER_ACTIVE,
+                    XI_LED_MODE::XI_LED_BLINK,
+                    XI_LED_MODE::XI_LED_OFF,
+                    XI_LED_MODE::XI_LED_ON,
+                ],
+            )
+            .live()
+            .selected_by(XI_PRM_LED_SELECTOR),
+        );
+        let temps = [
+            XI_TEMP_SELECTOR::XI_TEMP_IMAGE_SENSOR_DIE,
+            XI_TEMP_SELECTOR::XI_TEMP_SENSOR_BOARD,
+        ];
+        add(
+            XI_PRM_TEMP_SELECTOR,
+            Param::enumeration(temps[0], &temps).live(),
+        );
+        add(
+            XI_PRM_TEMP,
+            Param::float(40.0, -40.0, 100.0, 0.1)
+                .read_only()
+                .live()
+                .selected_by(XI_PRM_TEMP_SELECTOR),
+        );
+        add(XI_PRM_IMAGE_USER_DATA, Param::int(0, 0, i32::MAX, 1).live());
+        let bit_depth = model.bit_depths.iter().copied().max().unwrap_or(8);
+        for prm in [
+            XI_PRM_SENSOR_DATA_BIT_DEPTH as &[u8],
+            XI_PRM_OUTPUT_DATA_BIT_DEPTH,
+            XI_PRM_IMAGE_DATA_BIT_DEPTH,
+        ] {
+            add(prm, Param::enumeration(bit_depth, &model.bit_depths));
+        }
+        add(
+            XI_PRM_COLUMN_FPN_CORRECTION,
+            Param::switch(XI_SWITCH::XI_OFF),
+        );
+        add(XI_PRM_ROW_FPN_CORRECTION, Param::switch(XI_SWITCH::XI_OFF));
+        add(
+            XI_PRM_COLUMN_BLACK_OFFSET_CORRECTION,
+            Param::switch(XI_SWITCH::XI_OFF),
+        );
+        add(
+            XI_PRM_ROW_BLACK_OFFSET_CORRECTION,
+            Param::switch(XI_SWITCH::XI_OFF),
+        );
+        add(
+            XI_PRM_COUNTER_SELECTOR,
+            Param::enumeration(
+                XI_COUNTER_SELECTOR::XI_CNT_SEL_TRANSPORT_SKIPPED_FRAMES,
+                &[
+                    XI_COUNTER_SELECTOR::XI_CNT_SEL_TRANSPORT_SKIPPED_FRAMES,
+                    XI_COUNTER_SELECTOR::XI_CNT_SEL_API_SKIPPED_FRAMES,
+                ],
+            )
+            .live(),
+        );
+        add(
+            XI_PRM_COUNTER_VALUE,
+            Param::int(0, 0, i32::MAX, 1)
+                .read_only()
+                .selected_by(XI_PRM_COUNTER_SELECTOR),
+        );
+        add(
+            XI_PRM_SENSOR_FEATURE_SELECTOR,
+            Param::enumeration(
+                XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_ZEROROT_ENABLE,
+                &[XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_ZEROROT_ENABLE],
+            ),
+        );
+        add(
+            XI_PRM_SENSOR_FEATURE_VALUE,
+            Param::int(0, 0, 1, 1).selected_by(XI_PRM_SENSOR_FEATURE_SELECTOR),
+        );
+        add(
+            XI_PRM_COLOR_FILTER_ARRAY,
+            Param::enumeration(model.color_filter_array, &[]).read_only(),
+        );
+        add(
+            XI_PRM_SENSOR_CLOCK_FREQ_HZ,
+            Param::float(100e6, 100e6, 100e6, 1.0).read_only(),
+        );
+        add(
+            XI_PRM_TIMESTAMP,
+            Param::new(
+                Value::Int64(0),
+                Value::Int64(0),
+                Value::Int64(u64::MAX),
+                Value::Int64(1),
+            )
+            .read_only()
+            .live(),
+        );
+        add(
+            XI_PRM_BUFFER_POLICY,
+            Param::enumeration(
+                XI_BP::XI_BP_UNSAFE,
+                &[XI_BP::XI_BP_UNSAFE, XI_BP::XI_BP_SAFE],
+            ),
+        );
+        add(XI_PRM_BUFFERS_QUEUE_SIZE, Param::int(4, 2, 64, 1));
+        add(
+            XI_PRM_ACQ_BUFFER_SIZE,
+            Param::int(64 << 20, 1 << 20, i32::MAX, 1),
+        );
+        add(XI_PRM_AUTO_WB, Param::switch(XI_SWITCH::XI_OFF).live());
+        add(XI_PRM_WB_KR, Param::float(1.0, 0.0, 8.0, 0.01).live());
+        add(XI_PRM_WB_KG, Param::float(1.0, 0.0, 8.0, 0.01).live());
+        add(XI_PRM_WB_KB, Param::float(1.0, 0.0, 8.0, 0.01).live());
+        add(XI_PRM_RECENT_FRAME, Param::switch(XI_SWITCH::XI_OFF));
+        add(
+            XI_PRM_TRANSPORT_DATA_TARGET,
+            Param::enumeration(
+                XI_TRANSPORT_DATA_TARGET_MODE::XI_TRANSPORT_DATA_TARGET_CPU_RAM,
+                &[XI_TRANSPORT_DATA_TARGET_MODE::XI_TRANSPORT_DATA_TARGET_CPU_RAM],
+            ),
+        );
+        if let Some(first) = model.user_sets.first() {
+            add(
+                XI_PRM_USER_SET_SELECTOR,
+                Param::enumeration(*first, &model.user_sets),
+            );
+            add(XI_PRM_USER_SET_LOAD, Param::int(0, 0, 1, 1));
+            let mut defaults = model.user_sets.clone();
+            defaults.push(XI_USER_SET_SELECTOR::XI_US_NONE);
+            add(
+                XI_PRM_USER_SET_DEFAULT,
+                Param::enumeration(model.default_user_set, &defaults),
+            );
+        }
+
+        let mut device = SimDevice {
+            dev_id,
+            model: model.clone(),
+            params,
+            open: false,
+            acquiring: false,
+            frames: Vec::new(),
+            acq_nframe: 0,
+            nframe: 0,
+            pending_triggers: 0,
+            clock_ns: 0,
+        };
+        device.load_user_set(model.default_user_set);
+        device
+    }
+
+    /// Apply the settings of a user set
+    fn load_user_set(&mut self, user_set: XI_USER_SET_SELECTOR::Type) {
+        use XI_USER_SET_SELECTOR::*;
+        let (bit_depth, gain) = match user_set {
+            XI_US_12_STD_L => (XI_BIT_DEPTH::XI_BPP_12, 0.0),
+            XI_US_12_STD_H => (XI_BIT_DEPTH::XI_BPP_12, 12.0),
+            XI_US_14_STD_L => (XI_BIT_DEPTH::XI_BPP_14, 0.0),
+            XI_US_14_STD_H => (XI_BIT_DEPTH::XI_BPP_14, 12.0),
+            _ => return,
+        };
+        let mut store = |prm: &[u8], index: u32, value: Value| {
+            if let Some(param) = self.params.get_mut(prm_name(prm)) {
+                param.values.insert(index as i32, value);
+            }
+        };
+        if self.model.bit_depths.contains(&bit_depth) {
+            for prm in [
+                XI_PRM_SENSOR_DATA_BIT_DEPTH as &[u8],
+                XI_PRM_OUTPUT_DATA_BIT_DEPTH,
+                XI_PRM_IMAGE_DATA_BIT_DEPTH,
+            ] {
+                store(prm, 0, Value::Int(bit_depth as i32));
+            }
+        }
+        let all = XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ALL;
+        store(XI_PRM_GAIN, all, Value::Float(gain));
+    }
+
+    /// Get a device information string, which is available without opening the device
+    fn info(&self, prm: &str) -> Option<String> {
+        let dev_id = self.dev_id;
+        match prm {
+            n if n == prm_name(XI_PRM_DEVICE_NAME) => Some(self.model.name.clone()),
+            n if n == prm_name(XI_PRM_DEVICE_SN) => Some(format!("SIM{:05}", dev_id + 1)),
+            n if n == prm_name(XI_PRM_DEVICE_TYPE) => Some("SIM".to_string()),
+            n if n == prm_name(XI_PRM_DEVICE_INSTANCE_PATH) => Some(format!("sim/{}", dev_id)),
+            n if n == prm_name(XI_PRM_DEVICE_LOCATION_PATH) => Some(format!("sim/{}", dev_id)),
+            n if n == prm_name(XI_PRM_DEVICE_USER_ID) => Some(self.model.user_id.clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a string parameter
+    fn string(&self, prm: &str) -> Option<String> {
+        if prm == prm_name(XI_PRM_DEVICE_MANIFEST) {
+            return Some(self.manifest());
+        }
+        self.info(prm).or_else(|| {
+            VERSIONS
+                .iter()
+                .find(|(name, _)| prm_name(name) == prm)
+                .map(|(_, version)| version.to_string())
+        })
+    }
+
+    /// Generate the device manifest, describing all simulated parameters
+    fn manifest(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml += "<manifest version=\"1.0\">\n";
+        let strings: [&[u8]; 6] = [
+            XI_PRM_DEVICE_NAME,
+            XI_PRM_DEVICE_SN,
+            XI_PRM_DEVICE_TYPE,
+            XI_PRM_DEVICE_INSTANCE_PATH,
+            XI_PRM_DEVICE_LOCATION_PATH,
+            XI_PRM_DEVICE_USER_ID,
+        ];
+        for prm in strings.iter().chain(VERSIONS.iter().map(|(prm, _)| prm)) {
+            let name = prm_name(prm);
+            let access = match name == prm_name(XI_PRM_DEVICE_USER_ID) {
+                true => "RW",
+                false => "R",
+            };
+            xml += &format!("  <parameter name=\"{name}\" type=\"string\" access=\"{access}\"/>\n");
+        }
+        for (name, param) in &self.params {
+            let kind = match (param.default, &param.allowed) {
+                (_, Some(_)) => "enum",
+                (Value::Int(_), None) => "int",
+                (Value::Float(_), None) => "float",
+                (Value::Int64(_), None) => "int64",
+            };
+            let access = match param.read_only {
+                true => "R",
+                false => "RW",
+            };
+            let (min, max, inc) = self.range(name, param);
+            let (min, max, inc) = (min.as_f64(), max.as_f64(), inc.as_f64());
+            xml += &format!(
+                "  <parameter name=\"{name}\" type=\"{kind}\" access=\"{access}\" \
+                 min=\"{min}\" max=\"{max}\" inc=\"{inc}\" direct_update=\"{}\"/>\n",
+                param.direct
+            );
+        }
+        xml + "</manifest>\n"
+    }
+
+    fn get_string(&self, prm: &CStr) -> Result<String, XI_RETURN> {
+        let name = prm.to_str().or(Err(XI_INVALID_ARG as XI_RETURN))?;
+        match self.string(name) {
+            Some(value) => Ok(value),
+            None => self.lookup(name).and(Err(XI_WRONG_PARAM_TYPE as XI_RETURN)),
+        }
+    }
+
+    fn set_string(&mut self, prm: &CStr, value: &[u8]) -> Result<(), XI_RETURN> {
+        let name = prm.to_str().or(Err(XI_INVALID_ARG as XI_RETURN))?;
+        if name == prm_name(XI_PRM_DEVICE_USER_ID) {
+            let value = value.split(|c| *c == 0).next().unwrap_or_default();
+            self.model.user_id = String::from_utf8_lossy(value).into_owned();
+            return Ok(());
+        }
+        match self.string(name) {
+            Some(_) => Err(XI_READ_ONLY_PARAM as XI_RETURN),
+            None => self.lookup(name).and(Err(XI_WRONG_PARAM_TYPE as XI_RETURN)),
+        }
+    }
+
+    /// Get the current value of a parameter
+    fn value(&self, prm: &[u8]) -> Value {
+        let name = prm_name(prm);
+        self.lookup(name)
+            .map(|param| self.current(param))
+            .expect("Simulated parameter does not exist")
+    }
+
+    fn lookup(&self, name: &str) -> Result<&Param, XI_RETURN> {
+        self.params.get(name).ok_or(XI_NOT_SUPPORTED as XI_RETURN)
+    }
+
+    /// Index into the values of `param` according to its selector
+    fn selected(&self, param: &Param) -> i32 {
+        match &param.selector {
+            Some(selector) => self
+                .params
+                .get(selector)
+                .map(|selector| self.current(selector).as_i32())
+                .unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    fn current(&self, param: &Param) -> Value {
+        *param
+            .values
+            .get(&self.selected(param))
+            .unwrap_or(&param.default)
+    }
+
+    /// Get the range (min, max, increment) of a parameter
+    fn range(&self, name: &str, param: &Param) -> (Value, Value, Value) {
+        let int = |value: u32| Value::Int(value as i32);
+        let (width, height) = (self.value(XI_PRM_WIDTH), self.value(XI_PRM_HEIGHT));
+        let (offset_x, offset_y) = (self.value(XI_PRM_OFFSET_X), self.value(XI_PRM_OFFSET_Y));
+        let exposure = self.value(XI_PRM_EXPOSURE).as_f32();
+        let max = match name {
+            n if n == prm_name(XI_PRM_WIDTH) => int(self.model.width - offset_x.as_i32() as u32),
+            n if n == prm_name(XI_PRM_HEIGHT) => int(self.model.height - offset_y.as_i32() as u32),
+            n if n == prm_name(XI_PRM_OFFSET_X) => int(self.model.width - width.as_i32() as u32),
+            n if n == prm_name(XI_PRM_OFFSET_Y) => int(self.model.height - height.as_i32() as u32),
+            n if n == prm_name(XI_PRM_FRAMERATE) => {
+                Value::Float(param.max.as_f32().min(1e6 / exposure))
+            }
+            n if n == prm_name(XI_PRM_EXPOSURE) && self.frame_rate_mode() => {
+                let framerate = self.value(XI_PRM_FRAMERATE).as_f32();
+                Value::Float(param.max.as_f32().min(1e6 / framerate))
+            }
+            _ => param.max,
+        };
+        (param.min, max, param.inc)
+    }
+
+    /// Frame rate is controlled by the `framerate` parameter
+    fn frame_rate_mode(&self) -> bool {
+        self.value(XI_PRM_ACQ_TIMING_MODE).as_i32() as XI_ACQ_TIMING_MODE::Type
+            == XI_ACQ_TIMING_MODE::XI_ACQ_TIMING_MODE_FRAME_RATE
+    }
+
+    /// Time between two frames in nanoseconds
+    fn frame_period_ns(&self) -> u64 {
+        let exposure_ns = self.value(XI_PRM_EXPOSURE).as_f32() as f64 * 1e3;
+        let framerate = match self.frame_rate_mode() {
+            true => self.value(XI_PRM_FRAMERATE).as_f32(),
+            false => self.model.max_framerate,
+        };
+        (1e9 / framerate as f64).max(exposure_ns) as u64
+    }
+
+    fn get(&self, prm: &CStr) -> Result<Value, XI_RETURN> {
+        let prm = prm.to_str().or(Err(XI_INVALID_ARG as XI_RETURN))?;
+        let (name, modifier) = match prm.split_once(':') {
+            Some((name, modifier)) => (name, Some(modifier)),
+            None => (prm, None),
+        };
+        if name == prm_name(XI_PRM_TIMESTAMP) && modifier.is_none() {
+            return Ok(Value::Int64(self.clock_ns));
+        }
+        if let Some(string) = self.string(name) {
+            return match modifier {
+                Some("req_buf_size") => Ok(Value::Int(string.len() as i32 + 1)),
+                Some(_) => Err(XI_NOT_SUPPORTED_PARAM_INFO as XI_RETURN),
+                None => Err(XI_WRONG_PARAM_TYPE as XI_RETURN),
+            };
+        }
+        let param = self.lookup(name)?;
+        let (min, max, inc) = self.range(name, param);
+        match modifier {
+            None => Ok(self.current(param)),
+            Some("min") => Ok(min),
+            Some("max") => Ok(max),
+            Some("inc") => Ok(inc),
+            Some(_) => Err(XI_NOT_SUPPORTED_PARAM_INFO as XI_RETURN),
+        }
+    }
+
+    fn set(&mut self, prm: &CStr, value: Value) -> Result<(), XI_RETURN> {
+        let prm = prm.to_str().or(Err(XI_INVALID_ARG as XI_RETURN))?;
+        let (name, modifier) = match prm.split_once(':') {
+            Some((name, modifier)) => (name, Some(modifier)),
+            None => (prm, None),
+        };
+        if self.string(name).is_some() {
+            return Err(XI_WRONG_PARAM_TYPE as XI_RETURN);
+        }
+        let param = self.lookup(name)?;
+        match modifier {
+            None => {}
+            Some("direct_update") if param.direct => {}
+            Some(_) => return Err(XI_NOT_SUPPORTED_PARAM_INFO as XI_RETURN),
+        }
+        if param.read_only {
+            return Err(XI_READ_ONLY_PARAM as XI_RETURN);
+        }
+        if self.acquiring && !param.live {
+            return Err(XI_ACQUISITION_ALREADY_UP as XI_RETURN);
+        }
+        let value = value.convert_like(param.default);
+        let (min, max, inc) = self.range(name, param);
+        param.check(value, min, max, inc)?;
+        let index = self.selected(param);
+        if let Some(param) = self.params.get_mut(name) {
+            param.values.insert(index, value);
+        }
+        if name == prm_name(XI_PRM_TRG_SOFTWARE) {
+            self.software_trigger();
+        }
+        if name == prm_name(XI_PRM_USER_SET_LOAD) {
+            self.load_user_set(self.value(XI_PRM_USER_SET_SELECTOR).as_i32() as u32);
+        }
+        if name == prm_name(XI_PRM_USER_SET_DEFAULT) {
+            self.model.default_user_set = value.as_i32() as u32;
+        }
+        Ok(())
+    }
+
+    fn software_trigger(&mut self) {
+        let source = self.value(XI_PRM_TRG_SOURCE).as_i32() as XI_TRG_SOURCE::Type;
+        if !self.acquiring || source != XI_TRG_SOURCE::XI_TRG_SOFTWARE {
+            return;
+        }
+        let selector = self.value(XI_PRM_TRG_SELECTOR).as_i32() as XI_TRG_SELECTOR::Type;
+        self.pending_triggers += match selector {
+            XI_TRG_SELECTOR::XI_TRG_SEL_FRAME_BURST_START => {
+                self.value(XI_PRM_ACQ_FRAME_BURST_COUNT).as_i32() as u32
+            }
+            _ => 1,
+        };
+    }
+
+    /// Layout of a pixel in the current image format as number of channels and bytes per channel
+    fn pixel_layout(&self) -> Result<(usize, usize), XI_RETURN> {
+        use crate::sys::XI_IMG_FORMAT::*;
+
+        let format = self.value(XI_PRM_IMAGE_DATA_FORMAT).as_i32() as XI_IMG_FORMAT::Type;
+        match format {
+            XI_MONO8 | XI_RAW8 => Ok((1, 1)),
+            XI_MONO16 | XI_RAW16 => Ok((1, 2)),
+            XI_RGB24 => Ok((3, 1)),
+            XI_RGB32 => Ok((4, 1)),
+            XI_RGB48 => Ok((3, 2)),
+            XI_RGB64 => Ok((4, 2)),
+            XI_RAW32 | XI_RAW32FLOAT => Ok((1, 4)),
+            _ => Err(XI_NOT_SUPPORTED_DATA_FORMAT as XI_RETURN),
+        }
+    }
+
+    /// Start the acquisition, which fails if a frame does not fit into the acquisition buffer
+    fn start_acquisition(&mut self) -> Result<(), XI_RETURN> {
+        if self.acquiring {
+            return Err(XI_ACQUISITION_ALREADY_UP as XI_RETURN);
+        }
+        let (channels, channel_size) = self.pixel_layout()?;
+        let width = self.value(XI_PRM_WIDTH).as_i32() as usize;
+        let height = self.value(XI_PRM_HEIGHT).as_i32() as usize;
+        let buffer_size = self.value(XI_PRM_ACQ_BUFFER_SIZE).as_i32() as usize;
+        if width * height * channels * channel_size > buffer_size {
+            return Err(XI_MEMORY_ALLOCATION as XI_RETURN);
+        }
+        self.acquiring = true;
+        self.acq_nframe = 0;
+        self.pending_triggers = 0;
+        Ok(())
+    }
+
+    /// Generate the next frame and fill the image structure
+    fn capture(&mut self, img: &mut XI_IMG) -> Result<(), XI_RETURN> {
+        use crate::sys::XI_IMG_FORMAT::*;
+
+        if !self.acquiring {
+            return Err(XI_ACQUISITION_STOPED as XI_RETURN);
+        }
+        let source = self.value(XI_PRM_TRG_SOURCE).as_i32() as XI_TRG_SOURCE::Type;
+        if source != XI_TRG_SOURCE::XI_TRG_OFF {
+            if self.pending_triggers == 0 {
+                return Err(XI_TIMEOUT as XI_RETURN);
+            }
+            self.pending_triggers -= 1;
+        }
+
+        let format = self.value(XI_PRM_IMAGE_DATA_FORMAT).as_i32() as XI_IMG_FORMAT::Type;
+        let (channels, channel_size) = self.pixel_layout()?;
+        let bit_depth = match channel_size {
+            1 => 8,
+            _ => (self.value(XI_PRM_IMAGE_DATA_BIT_DEPTH).as_i32() as u32)
+                .clamp(1, 8 * channel_size as u32),
+        };
+        let raw = matches!(format, XI_RAW8 | XI_RAW16 | XI_RAW32 | XI_RAW32FLOAT);
+        let width = self.value(XI_PRM_WIDTH).as_i32() as usize;
+        let height = self.value(XI_PRM_HEIGHT).as_i32() as usize;
+        let offset_x = self.value(XI_PRM_OFFSET_X).as_i32() as usize;
+        let offset_y = self.value(XI_PRM_OFFSET_Y).as_i32() as usize;
+        let flip_x = self.value(XI_PRM_HORIZONTAL_FLIP).as_i32() != 0;
+        let flip_y = self.value(XI_PRM_VERTICAL_FLIP).as_i32() != 0;
+        let max_value = u32::MAX >> (32 - bit_depth);
+        let pattern = self.value(XI_PRM_TEST_PATTERN).as_i32() as XI_TEST_PATTERN::Type;
+        let exposure = self.value(XI_PRM_EXPOSURE).as_f32() as f64;
+        let gain = self.value(XI_PRM_GAIN).as_f32() as f64;
+        // The scene reaches half of the full scale at the default exposure time of 10ms
+        let brightness = exposure / 20_000.0 * 10f64.powf(gain / 20.0);
+
+        self.nframe += 1;
+        self.acq_nframe += 1;
+
+        let pixel_size = channels * channel_size;
+        let alignment = self.model.line_alignment.max(1) as usize;
+        let stride = (width * pixel_size + alignment - 1) / alignment * alignment;
+        let size = stride * height;
+        // Use u64 as storage to guarantee the alignment for all pixel types
+        let mut buffer = vec![0u64; (size + size_of::<u64>() - 1) / size_of::<u64>()];
+        let data = unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, size) };
+        for y in 0..height {
+            let sensor_y = offset_y + if flip_y { height - 1 - y } else { y };
+            for x in 0..width {
+                let sensor_x = offset_x + if flip_x { width - 1 - x } else { x };
+                let [r, g, b] =
+                    self.pattern_rgb(pattern, sensor_x, sensor_y, max_value, brightness);
+                let luminance = ((299 * r as u64 + 587 * g as u64 + 114 * b as u64) / 1000) as u32;
+                let value = match raw {
+                    true => self
+                        .mosaic(sensor_x, sensor_y, [r, g, b])
+                        .unwrap_or(luminance),
+                    false => luminance,
+                };
+                // XIMEA stores color pixels in BGR(A) order
+                let pixel = match channels {
+                    1 => [value, 0, 0, 0],
+                    _ => [b, g, r, 0],
+                };
+                let offset = y * stride + x * pixel_size;
+                let target = &mut data[offset..offset + pixel_size];
+                for (channel, bytes) in target.chunks_exact_mut(channel_size).enumerate() {
+                    let value = pixel[channel];
+                    match (channel_size, format) {
+                        (1, _) => bytes[0] = value as u8,
+                        (2, _) => bytes.copy_from_slice(&(value as u16).to_ne_bytes()),
+                        (_, XI_RAW32FLOAT) => {
+                            bytes.copy_from_slice(&(value as f32 / max_value as f32).to_ne_bytes())
+                        }
+                        _ => bytes.copy_from_slice(&value.to_ne_bytes()),
+                    }
+                }
+            }
+        }
+
+        let timestamp_us = self.clock_ns / 1000;
+        img.bp = buffer.as_mut_ptr() as *mut c_void;
+        img.bp_size = size as u32;
+        img.frm = format;
+        img.width = width as u32;
+        img.height = height as u32;
+        img.nframe = self.nframe;
+        img.acq_nframe = self.acq_nframe;
+        img.tsSec = (timestamp_us / 1_000_000) as u32;
+        img.tsUSec = (timestamp_us % 1_000_000) as u32;
+        img.black_level = 0;
+        img.padding_x = (stride - width * pixel_size) as u32;
+        img.AbsoluteOffsetX = offset_x as u32;
+        img.AbsoluteOffsetY = offset_y as u32;
+        img.transport_frm = format;
+        img.DownsamplingX = 1;
+        img.DownsamplingY = 1;
+        img.exposure_time_us = self.value(XI_PRM_EXPOSURE).as_f32() as u32;
+        img.gain_db = self.value(XI_PRM_GAIN).as_f32();
+        img.image_user_data = self.value(XI_PRM_IMAGE_USER_DATA).as_i32() as u32;
+        img.color_filter_array = match raw {
+            true => self.model.color_filter_array,
+            false => XI_COLOR_FILTER_ARRAY::XI_CFA_NONE,
+        };
+
+        self.frames.push(buffer.into_boxed_slice());
+        self.clock_ns += self.frame_period_ns();
+        Ok(())
+    }
+
+    /// Select the color channel that is seen by the sensor pixel at the given position.
+    /// Returns None for monochrome sensors.
+    fn mosaic(&self, x: usize, y: usize, [r, g, b]: [u32; 3]) -> Option<u32> {
+        use crate::sys::XI_COLOR_FILTER_ARRAY::*;
+
+        let pattern = match self.model.color_filter_array {
+            XI_CFA_BAYER_RGGB => [[r, g], [g, b]],
+            XI_CFA_BAYER_BGGR => [[b, g], [g, r]],
+            XI_CFA_BAYER_GRBG => [[g, r], [b, g]],
+            XI_CFA_BAYER_GBRG => [[g, b], [r, g]],
+            _ => return None,
+        };
+        Some(pattern[y % 2][x % 2])
+    }
+
+    /// Color of the test pattern at the given sensor position as (red, green, blue)
+    fn pattern_rgb(
+        &self,
+        pattern: XI_TEST_PATTERN::Type,
+        x: usize,
+        y: usize,
+        max_value: u32,
+        brightness: f64,
+    ) -> [u32; 3] {
+        use crate::sys::XI_TEST_PATTERN::*;
+
+        let modulo = max_value as u64 + 1;
+        let n = self.nframe as usize;
+        let (sensor_width, sensor_height) = (self.model.width as usize, self.model.height as usize);
+        let grey = |value: usize| {
+            let value = (value as u64 % modulo) as u32;
+            [value; 3]
+        };
+        match pattern {
+            XI_TESTPAT_BLACK => [0; 3],
+            XI_TESTPAT_WHITE => [max_value; 3],
+            XI_TESTPAT_GREY_HORIZ_RAMP => grey(x),
+            XI_TESTPAT_GREY_VERT_RAMP => grey(y),
+            XI_TESTPAT_GREY_HORIZ_RAMP_MOVING => grey(x + n),
+            XI_TESTPAT_GREY_VERT_RAMP_MOVING => grey(y + n),
+            XI_TESTPAT_HORIZ_LINE_MOVING => match y == n % sensor_height {
+                true => [max_value; 3],
+                false => [0; 3],
+            },
+            XI_TESTPAT_VERT_LINE_MOVING => match x == n % sensor_width {
+                true => [max_value; 3],
+                false => [0; 3],
+            },
+            XI_TESTPAT_COLOR_BAR => {
+                COLOR_BARS[x * COLOR_BARS.len() / sensor_width].map(|channel| channel * max_value)
+            }
+            XI_TESTPAT_FRAME_COUNTER => grey(n),
+            XI_TESTPAT_DEVICE_SPEC_COUNTER => grey(y * sensor_width + x),
+            _ => self.scene_rgb(x, y, max_value, brightness),
+        }
+    }
+
+    /// Simulated scene that is seen by the sensor if no test pattern is active
+    fn scene_rgb(&self, x: usize, y: usize, max_value: u32, brightness: f64) -> [u32; 3] {
+        let scale = |value: usize| {
+            let value = (value % 256) as f64 / 255.0 * brightness;
+            (value.min(1.0) * max_value as f64).round() as u32
+        };
+        match self.model.color_filter_array {
+            XI_COLOR_FILTER_ARRAY::XI_CFA_NONE => [scale(x + y); 3],
+            _ => [scale(x), scale(y), scale(x + y)],
+        }
+    }
+}
+
+impl SimBackend {
+    /// Create a simulated system with `number_devices` identical cameras.
+    ///
+    /// All cameras use the default [SensorModel].
+    pub fn new(number_devices: u32) -> Self {
+        Self::with_models((0..number_devices).map(|_| SensorModel::default()))
+    }
+
+    /// Create a simulated system with one camera for each of the given sensor models.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any model does not support at least one image format.
+    pub fn with_models(models: impl IntoIterator<Item = SensorModel>) -> Self {
+        let mut globals = BTreeMap::new();
+        globals.insert(
+            prm_name(XI_PRM_DEBUG_LEVEL).to_string(),
+            Param::int(XI_DEBUG_LEVEL::XI_DL_WARNING as i32, 0, 100, 1),
+        );
+        globals.insert(
+            prm_name(XI_PRM_AUTO_BANDWIDTH_CALCULATION).to_string(),
+            Param::switch(XI_SWITCH::XI_ON),
+        );
+        let state = SimState {
+            devices: models
+                .into_iter()
+                .zip(0..)
+                .map(|(model, dev_id)| {
+                    assert!(
+                        !model.image_formats.is_empty(),
+                        "SensorModel must support at least one image format"
+                    );
+                    SimDevice::new(dev_id, &model)
+                })
+                .collect(),
+            globals,
+        };
+        SimBackend {
+            state: Mutex::new(state),
+        }
+    }
+
+    fn with_state<R>(&self, f: impl FnOnce(&mut SimState) -> R) -> R {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut state)
+    }
+
+    /// Run `f` on the open device identified by `handle` and convert the result to a return code
+    fn with_device(
+        &self,
+        handle: HANDLE,
+        f: impl FnOnce(&mut SimDevice) -> Result<(), XI_RETURN>,
+    ) -> XI_RETURN {
+        self.with_state(|state| match state.device(handle) {
+            Ok(device) => match f(device) {
+                Ok(()) => XI_OK as XI_RETURN,
+                Err(err) => err,
+            },
+            Err(err) => err,
+        })
+    }
+
+    fn get_value(&self, handle: HANDLE, prm: &CStr) -> Result<Value, XI_RETURN> {
+        self.with_state(|state| {
+            if handle.is_null() {
+                let name = prm.to_str().or(Err(XI_INVALID_ARG as XI_RETURN))?;
+                return match state.globals.get(name) {
+                    Some(param) => Ok(*param.values.get(&0).unwrap_or(&param.default)),
+                    None => Err(XI_INVALID_HANDLE as XI_RETURN),
+                };
+            }
+            state.device(handle)?.get(prm)
+        })
+    }
+
+    fn set_value(&self, handle: HANDLE, prm: &CStr, value: Value) -> XI_RETURN {
+        if handle.is_null() {
+            return self.with_state(|state| {
+                let name = prm.to_str().unwrap_or_default();
+                match state.globals.get_mut(name) {
+                    Some(param) => {
+                        param.values.insert(0, value.convert_like(param.default));
+                        XI_OK as XI_RETURN
+                    }
+                    None => XI_INVALID_HANDLE as XI_RETURN,
+                }
+            });
+        }
+        self.with_device(handle, |device| device.set(prm, value))
+    }
+}
+
+impl Default for SimBackend {
+    /// Create a simulated system with a single camera
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl SimState {
+    fn device(&mut self, handle: HANDLE) -> Result<&mut SimDevice, XI_RETURN> {
+        let index = (handle as usize).wrapping_sub(1);
+        match self.devices.get_mut(index) {
+            Some(device) if device.open => Ok(device),
+            _ => Err(XI_INVALID_HANDLE as XI_RETURN),
+        }
+    }
+}
+
+/// Write the result of a get function to `value` and convert it to a return code
+fn store<T>(result: Result<T, XI_RETURN>, value: &mut T) -> XI_RETURN {
+    match result {
+        Ok(v) => {
+            *value = v;
+            XI_OK as XI_RETURN
+        }
+        Err(err) => err,
+    }
+}
+
+unsafe impl Backend for SimBackend {
+    fn get_number_devices(&self, count: &mut u32) -> XI_RETURN {
+        *count = self.with_state(|state| state.devices.len() as u32);
+        XI_OK as XI_RETURN
+    }
+
+    fn get_device_info_string(&self, dev_id: u32, prm: &CStr, value: &mut [u8]) -> XI_RETURN {
+        self.with_state(|state| match state.devices.get(dev_id as usize) {
+            Some(device) => match device.info(prm.to_str().unwrap_or_default()) {
+                Some(info) if info.len() < value.len() => {
+                    value[..info.len()].copy_from_slice(info.as_bytes());
+                    value[info.len()] = 0;
+                    XI_OK as XI_RETURN
+                }
+                Some(_) => XI_BUFFER_TOO_SMALL as XI_RETURN,
+                None => XI_NOT_SUPPORTED as XI_RETURN,
+            },
+            None => XI_NO_DEVICES_FOUND as XI_RETURN,
+        })
+    }
+
+    fn open_device(&self, dev_id: u32, handle: &mut HANDLE) -> XI_RETURN {
+        self.with_state(|state| match state.devices.get_mut(dev_id as usize) {
+            Some(device) if device.open => XI_RESOURCE_OR_FUNCTION_LOCKED as XI_RETURN,
+            Some(device) => {
+                *device = SimDevice::new(dev_id, &device.model);
+                device.open = true;
+                *handle = (dev_id as usize + 1) as HANDLE;
+                XI_OK as XI_RETURN
+            }
+            None => XI_NO_DEVICES_FOUND as XI_RETURN,
+        })
+    }
+
+    fn open_device_by(&self, sel: XI_OPEN_BY::Type, prm: &CStr, handle: &mut HANDLE) -> XI_RETURN {
+        let info: &[u8] = match sel {
+            XI_OPEN_BY::XI_OPEN_BY_INST_PATH => XI_PRM_DEVICE_INSTANCE_PATH,
+            XI_OPEN_BY::XI_OPEN_BY_SN => XI_PRM_DEVICE_SN,
+            XI_OPEN_BY::XI_OPEN_BY_USER_ID => XI_PRM_DEVICE_USER_ID,
+            XI_OPEN_BY::XI_OPEN_BY_LOC_PATH => XI_PRM_DEVICE_LOCATION_PATH,
+            _ => return XI_INVALID_ARG as XI_RETURN,
+        };
+        let value = prm.to_string_lossy();
+        let dev_id = self.with_state(|state| {
+            state.devices.iter().find_map(|device| {
+                let found = device.info(prm_name(info)).filter(|id| !id.is_empty());
+                (found.as_deref() == Some(value.as_ref())).then_some(device.dev_id)
+            })
+        });
+        match dev_id {
+            Some(dev_id) => self.open_device(dev_id, handle),
+            None => XI_NO_DEVICES_FOUND as XI_RETURN,
+        }
+    }
+
+    unsafe fn close_device(&self, handle: HANDLE) -> XI_RETURN {
+        self.with_device(handle, |device| {
+            device.open = false;
+            device.acquiring = false;
+            device.frames.clear();
+            Ok(())
+        })
+    }
+
+    unsafe fn start_acquisition(&self, handle: HANDLE) -> XI_RETURN {
+        self.with_device(handle, |device| device.start_acquisition())
+    }
+
+    unsafe fn stop_acquisition(&self, handle: HANDLE) -> XI_RETURN {
+        self.with_device(handle, |device| {
+            if !device.acquiring {
+                return Err(XI_ACQUISITION_STOPED as XI_RETURN);
+            }
+            device.acquiring = false;
+            device.frames.clear();
+            Ok(())
+        })
+    }
+
+    unsafe fn get_image(&self, handle: HANDLE, _timeout: u32, img: &mut XI_IMG) -> XI_RETURN {
+        self.with_device(handle, |device| device.capture(img))
+    }
+
+    unsafe fn get_param_int(&self, handle: HANDLE, prm: &CStr, value: &mut i32) -> XI_RETURN {
+        store(self.get_value(handle, prm).map(Value::as_i32), value)
+    }
+
+    unsafe fn set_param_int(&self, handle: HANDLE, prm: &CStr, value: i32) -> XI_RETURN {
+        self.set_value(handle, prm, Value::Int(value))
+    }
+
+    unsafe fn get_param_float(&self, handle: HANDLE, prm: &CStr, value: &mut f32) -> XI_RETURN {
+        store(self.get_value(handle, prm).map(Value::as_f32), value)
+    }
+
+    unsafe fn set_param_float(&self, handle: HANDLE, prm: &CStr, value: f32) -> XI_RETURN {
+        self.set_value(handle, prm, Value::Float(value))
+    }
+
+    unsafe fn get_param_string(&self, handle: HANDLE, prm: &CStr, value: &mut [u8]) -> XI_RETURN {
+        self.with_device(handle, |device| {
+            let string = device.get_string(prm)?;
+            if string.len() >= value.len() {
+                return Err(XI_BUFFER_TOO_SMALL as XI_RETURN);
+            }
+            value[..string.len()].copy_from_slice(string.as_bytes());
+            value[string.len()] = 0;
+            Ok(())
+        })
+    }
+
+    unsafe fn set_param_string(&self, handle: HANDLE, prm: &CStr, value: &[u8]) -> XI_RETURN {
+        self.with_device(handle, |device| device.set_string(prm, value))
+    }
+
+    unsafe fn get_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &mut [u8],
+        size: &mut u32,
+        prm_type: &mut XI_PRM_TYPE::Type,
+    ) -> XI_RETURN {
+        let name = prm.to_str().unwrap_or_default();
+        let string = self.with_state(|state| {
+            let device = state.device(handle).ok()?;
+            device.string(name)
+        });
+        // Numbers are converted to the requested type, strings can only be read as strings
+        let (bytes, native_type) = match string {
+            Some(string) => ([string.as_bytes(), &[0]].concat(), xiTypeString),
+            None => {
+                let v = match self.get_value(handle, prm) {
+                    Ok(v) => v,
+                    Err(err) => return err,
+                };
+                let bytes = match *prm_type {
+                    XI_PRM_TYPE::xiTypeInteger64 => v.as_u64().to_ne_bytes().to_vec(),
+                    XI_PRM_TYPE::xiTypeFloat => v.as_f32().to_ne_bytes().to_vec(),
+                    XI_PRM_TYPE::xiTypeInteger
+                    | XI_PRM_TYPE::xiTypeEnum
+                    | XI_PRM_TYPE::xiTypeBoolean => v.as_i32().to_ne_bytes().to_vec(),
+                    _ => Vec::new(),
+                };
+                (bytes, v.xi_type())
+            }
+        };
+        if bytes.is_empty() || (native_type == xiTypeString) != (*prm_type == xiTypeString) {
+            *prm_type = native_type;
+            return XI_WRONG_PARAM_TYPE as XI_RETURN;
+        }
+        if bytes.len() > value.len() {
+            return XI_BUFFER_TOO_SMALL as XI_RETURN;
+        }
+        value[..bytes.len()].copy_from_slice(&bytes);
+        *size = bytes.len() as u32;
+        *prm_type = native_type;
+        XI_OK as XI_RETURN
+    }
+
+    unsafe fn set_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &[u8],
+        prm_type: XI_PRM_TYPE::Type,
+    ) -> XI_RETURN {
+        let value = match (prm_type, value.len()) {
+            (XI_PRM_TYPE::xiTypeInteger64, 8) => {
+                Value::Int64(u64::from_ne_bytes(value.try_into().unwrap()))
+            }
+            (XI_PRM_TYPE::xiTypeFloat, 4) => {
+                Value::Float(f32::from_ne_bytes(value.try_into().unwrap()))
+            }
+            (
+                XI_PRM_TYPE::xiTypeInteger | XI_PRM_TYPE::xiTypeEnum | XI_PRM_TYPE::xiTypeBoolean,
+                4,
+            ) => Value::Int(i32::from_ne_bytes(value.try_into().unwrap())),
+            (XI_PRM_TYPE::xiTypeInteger64 | XI_PRM_TYPE::xiTypeFloat, _)
+            | (XI_PRM_TYPE::xiTypeInteger | XI_PRM_TYPE::xiTypeEnum, _)
+            | (XI_PRM_TYPE::xiTypeBoolean, _) => return XI_WRONG_PARAM_SIZE as XI_RETURN,
+            (XI_PRM_TYPE::xiTypeString, _) => {
+                return self.with_device(handle, |device| device.set_string(prm, value))
+            }
+            _ => return XI_WRONG_PARAM_TYPE as XI_RETURN,
+        };
+        self.set_value(handle, prm, value)
+    }
+}
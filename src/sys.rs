@@ -0,0 +1,2539 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+//! Types and constants of xiAPI, used instead of the xiapi-sys crate without the `link` feature.
+//!
+//! The definitions are taken from `xiApi.h` as shipped with xiapi-sys 0.1.2. The functions of
+//! xiAPI are not declared here, they are loaded at runtime (see the `runtime-loading` feature).
+//! The tests at the end of this file compare the definitions to xiapi-sys when the `link` feature
+//! is enabled.
+
+#![allow(
+    non_upper_case_globals,
+    non_camel_case_types,
+    non_snake_case,
+    dead_code,
+    missing_docs
+)]
+#![allow(clippy::all)]
+
+use std::os::raw::{c_int, c_void};
+pub type XI_RETURN = c_int;
+pub type DWORD = u32;
+pub type HANDLE = *mut c_void;
+pub type PHANDLE = *mut HANDLE;
+pub type PDWORD = *mut DWORD;
+pub type LPVOID = *mut c_void;
+pub type xiProcessingHandle_t = *mut c_void;
+pub const XI_PRM_EXPOSURE: &[u8; 9] = b"exposure\0";
+pub const XI_PRM_EXPOSURE_TIME_SELECTOR: &[u8; 23] = b"exposure_time_selector\0";
+pub const XI_PRM_EXPOSURE_BURST_COUNT: &[u8; 21] = b"exposure_burst_count\0";
+pub const XI_PRM_GAIN_SELECTOR: &[u8; 14] = b"gain_selector\0";
+pub const XI_PRM_GAIN: &[u8; 5] = b"gain\0";
+pub const XI_PRM_DOWNSAMPLING: &[u8; 13] = b"downsampling\0";
+pub const XI_PRM_DOWNSAMPLING_TYPE: &[u8; 18] = b"downsampling_type\0";
+pub const XI_PRM_TEST_PATTERN_GENERATOR_SELECTOR: &[u8; 32] = b"test_pattern_generator_selector\0";
+pub const XI_PRM_TEST_PATTERN: &[u8; 13] = b"test_pattern\0";
+pub const XI_PRM_IMAGE_DATA_FORMAT: &[u8; 14] = b"imgdataformat\0";
+pub const XI_PRM_SHUTTER_TYPE: &[u8; 13] = b"shutter_type\0";
+pub const XI_PRM_SENSOR_TAPS: &[u8; 12] = b"sensor_taps\0";
+pub const XI_PRM_AEAG: &[u8; 5] = b"aeag\0";
+pub const XI_PRM_AEAG_ROI_OFFSET_X: &[u8; 18] = b"aeag_roi_offset_x\0";
+pub const XI_PRM_AEAG_ROI_OFFSET_Y: &[u8; 18] = b"aeag_roi_offset_y\0";
+pub const XI_PRM_AEAG_ROI_WIDTH: &[u8; 15] = b"aeag_roi_width\0";
+pub const XI_PRM_AEAG_ROI_HEIGHT: &[u8; 16] = b"aeag_roi_height\0";
+pub const XI_PRM_SENS_DEFECTS_CORR_LIST_SELECTOR: &[u8; 18] = b"bpc_list_selector\0";
+pub const XI_PRM_SENS_DEFECTS_CORR_LIST_CONTENT: &[u8; 31] = b"sens_defects_corr_list_content\0";
+pub const XI_PRM_SENS_DEFECTS_CORR: &[u8; 4] = b"bpc\0";
+pub const XI_PRM_AUTO_WB: &[u8; 8] = b"auto_wb\0";
+pub const XI_PRM_MANUAL_WB: &[u8; 10] = b"manual_wb\0";
+pub const XI_PRM_WB_ROI_OFFSET_X: &[u8; 16] = b"wb_roi_offset_x\0";
+pub const XI_PRM_WB_ROI_OFFSET_Y: &[u8; 16] = b"wb_roi_offset_y\0";
+pub const XI_PRM_WB_ROI_WIDTH: &[u8; 13] = b"wb_roi_width\0";
+pub const XI_PRM_WB_ROI_HEIGHT: &[u8; 14] = b"wb_roi_height\0";
+pub const XI_PRM_WB_KR: &[u8; 6] = b"wb_kr\0";
+pub const XI_PRM_WB_KG: &[u8; 6] = b"wb_kg\0";
+pub const XI_PRM_WB_KB: &[u8; 6] = b"wb_kb\0";
+pub const XI_PRM_WIDTH: &[u8; 6] = b"width\0";
+pub const XI_PRM_HEIGHT: &[u8; 7] = b"height\0";
+pub const XI_PRM_OFFSET_X: &[u8; 8] = b"offsetX\0";
+pub const XI_PRM_OFFSET_Y: &[u8; 8] = b"offsetY\0";
+pub const XI_PRM_REGION_SELECTOR: &[u8; 16] = b"region_selector\0";
+pub const XI_PRM_REGION_MODE: &[u8; 12] = b"region_mode\0";
+pub const XI_PRM_HORIZONTAL_FLIP: &[u8; 16] = b"horizontal_flip\0";
+pub const XI_PRM_VERTICAL_FLIP: &[u8; 14] = b"vertical_flip\0";
+pub const XI_PRM_INTERLINE_EXPOSURE_MODE: &[u8; 24] = b"interline_exposure_mode\0";
+pub const XI_PRM_FFC: &[u8; 4] = b"ffc\0";
+pub const XI_PRM_FFC_FLAT_FIELD_FILE_NAME: &[u8; 25] = b"ffc_flat_field_file_name\0";
+pub const XI_PRM_FFC_DARK_FIELD_FILE_NAME: &[u8; 25] = b"ffc_dark_field_file_name\0";
+pub const XI_PRM_BINNING_SELECTOR: &[u8; 17] = b"binning_selector\0";
+pub const XI_PRM_BINNING_VERTICAL_MODE: &[u8; 22] = b"binning_vertical_mode\0";
+pub const XI_PRM_BINNING_VERTICAL: &[u8; 17] = b"binning_vertical\0";
+pub const XI_PRM_BINNING_HORIZONTAL_MODE: &[u8; 24] = b"binning_horizontal_mode\0";
+pub const XI_PRM_BINNING_HORIZONTAL: &[u8; 19] = b"binning_horizontal\0";
+pub const XI_PRM_BINNING_HORIZONTAL_PATTERN: &[u8; 27] = b"binning_horizontal_pattern\0";
+pub const XI_PRM_BINNING_VERTICAL_PATTERN: &[u8; 25] = b"binning_vertical_pattern\0";
+pub const XI_PRM_DECIMATION_SELECTOR: &[u8; 20] = b"decimation_selector\0";
+pub const XI_PRM_DECIMATION_VERTICAL: &[u8; 20] = b"decimation_vertical\0";
+pub const XI_PRM_DECIMATION_HORIZONTAL: &[u8; 22] = b"decimation_horizontal\0";
+pub const XI_PRM_DECIMATION_HORIZONTAL_PATTERN: &[u8; 30] = b"decimation_horizontal_pattern\0";
+pub const XI_PRM_DECIMATION_VERTICAL_PATTERN: &[u8; 28] = b"decimation_vertical_pattern\0";
+pub const XI_PRM_EXP_PRIORITY: &[u8; 13] = b"exp_priority\0";
+pub const XI_PRM_AG_MAX_LIMIT: &[u8; 13] = b"ag_max_limit\0";
+pub const XI_PRM_AE_MAX_LIMIT: &[u8; 13] = b"ae_max_limit\0";
+pub const XI_PRM_AEAG_LEVEL: &[u8; 11] = b"aeag_level\0";
+pub const XI_PRM_LIMIT_BANDWIDTH: &[u8; 16] = b"limit_bandwidth\0";
+pub const XI_PRM_LIMIT_BANDWIDTH_MODE: &[u8; 21] = b"limit_bandwidth_mode\0";
+pub const XI_PRM_SENSOR_DATA_BIT_DEPTH: &[u8; 17] = b"sensor_bit_depth\0";
+pub const XI_PRM_OUTPUT_DATA_BIT_DEPTH: &[u8; 17] = b"output_bit_depth\0";
+pub const XI_PRM_IMAGE_DATA_BIT_DEPTH: &[u8; 21] = b"image_data_bit_depth\0";
+pub const XI_PRM_OUTPUT_DATA_PACKING: &[u8; 19] = b"output_bit_packing\0";
+pub const XI_PRM_OUTPUT_DATA_PACKING_TYPE: &[u8; 24] = b"output_bit_packing_type\0";
+pub const XI_PRM_IS_COOLED: &[u8; 9] = b"iscooled\0";
+pub const XI_PRM_COOLING: &[u8; 8] = b"cooling\0";
+pub const XI_PRM_TARGET_TEMP: &[u8; 12] = b"target_temp\0";
+pub const XI_PRM_TEMP_SELECTOR: &[u8; 14] = b"temp_selector\0";
+pub const XI_PRM_TEMP: &[u8; 5] = b"temp\0";
+pub const XI_PRM_TEMP_CONTROL_MODE: &[u8; 29] = b"device_temperature_ctrl_mode\0";
+pub const XI_PRM_CHIP_TEMP: &[u8; 10] = b"chip_temp\0";
+pub const XI_PRM_HOUS_TEMP: &[u8; 10] = b"hous_temp\0";
+pub const XI_PRM_HOUS_BACK_SIDE_TEMP: &[u8; 20] = b"hous_back_side_temp\0";
+pub const XI_PRM_SENSOR_BOARD_TEMP: &[u8; 18] = b"sensor_board_temp\0";
+pub const XI_PRM_TEMP_ELEMENT_SEL: &[u8; 31] = b"device_temperature_element_sel\0";
+pub const XI_PRM_TEMP_ELEMENT_VALUE: &[u8; 31] = b"device_temperature_element_val\0";
+pub const XI_PRM_CMS: &[u8; 4] = b"cms\0";
+pub const XI_PRM_CMS_INTENT: &[u8; 11] = b"cms_intent\0";
+pub const XI_PRM_APPLY_CMS: &[u8; 10] = b"apply_cms\0";
+pub const XI_PRM_INPUT_CMS_PROFILE: &[u8; 18] = b"input_cms_profile\0";
+pub const XI_PRM_OUTPUT_CMS_PROFILE: &[u8; 19] = b"output_cms_profile\0";
+pub const XI_PRM_IMAGE_IS_COLOR: &[u8; 8] = b"iscolor\0";
+pub const XI_PRM_COLOR_FILTER_ARRAY: &[u8; 4] = b"cfa\0";
+pub const XI_PRM_GAMMAY: &[u8; 7] = b"gammaY\0";
+pub const XI_PRM_GAMMAC: &[u8; 7] = b"gammaC\0";
+pub const XI_PRM_SHARPNESS: &[u8; 10] = b"sharpness\0";
+pub const XI_PRM_CC_MATRIX_00: &[u8; 8] = b"ccMTX00\0";
+pub const XI_PRM_CC_MATRIX_01: &[u8; 8] = b"ccMTX01\0";
+pub const XI_PRM_CC_MATRIX_02: &[u8; 8] = b"ccMTX02\0";
+pub const XI_PRM_CC_MATRIX_03: &[u8; 8] = b"ccMTX03\0";
+pub const XI_PRM_CC_MATRIX_10: &[u8; 8] = b"ccMTX10\0";
+pub const XI_PRM_CC_MATRIX_11: &[u8; 8] = b"ccMTX11\0";
+pub const XI_PRM_CC_MATRIX_12: &[u8; 8] = b"ccMTX12\0";
+pub const XI_PRM_CC_MATRIX_13: &[u8; 8] = b"ccMTX13\0";
+pub const XI_PRM_CC_MATRIX_20: &[u8; 8] = b"ccMTX20\0";
+pub const XI_PRM_CC_MATRIX_21: &[u8; 8] = b"ccMTX21\0";
+pub const XI_PRM_CC_MATRIX_22: &[u8; 8] = b"ccMTX22\0";
+pub const XI_PRM_CC_MATRIX_23: &[u8; 8] = b"ccMTX23\0";
+pub const XI_PRM_CC_MATRIX_30: &[u8; 8] = b"ccMTX30\0";
+pub const XI_PRM_CC_MATRIX_31: &[u8; 8] = b"ccMTX31\0";
+pub const XI_PRM_CC_MATRIX_32: &[u8; 8] = b"ccMTX32\0";
+pub const XI_PRM_CC_MATRIX_33: &[u8; 8] = b"ccMTX33\0";
+pub const XI_PRM_DEFAULT_CC_MATRIX: &[u8; 9] = b"defccMTX\0";
+pub const XI_PRM_CC_MATRIX_NORM: &[u8; 10] = b"ccMTXnorm\0";
+pub const XI_PRM_TRG_SOURCE: &[u8; 15] = b"trigger_source\0";
+pub const XI_PRM_TRG_SOFTWARE: &[u8; 17] = b"trigger_software\0";
+pub const XI_PRM_TRG_SELECTOR: &[u8; 17] = b"trigger_selector\0";
+pub const XI_PRM_TRG_OVERLAP: &[u8; 16] = b"trigger_overlap\0";
+pub const XI_PRM_ACQ_FRAME_BURST_COUNT: &[u8; 22] = b"acq_frame_burst_count\0";
+pub const XI_PRM_TIMESTAMP: &[u8; 10] = b"timestamp\0";
+pub const XI_PRM_GPI_SELECTOR: &[u8; 13] = b"gpi_selector\0";
+pub const XI_PRM_GPI_MODE: &[u8; 9] = b"gpi_mode\0";
+pub const XI_PRM_GPI_LEVEL: &[u8; 10] = b"gpi_level\0";
+pub const XI_PRM_GPI_LEVEL_AT_IMAGE_EXP_START: &[u8; 29] = b"gpi_level_at_image_exp_start\0";
+pub const XI_PRM_GPI_LEVEL_AT_IMAGE_EXP_END: &[u8; 27] = b"gpi_level_at_image_exp_end\0";
+pub const XI_PRM_GPO_SELECTOR: &[u8; 13] = b"gpo_selector\0";
+pub const XI_PRM_GPO_MODE: &[u8; 9] = b"gpo_mode\0";
+pub const XI_PRM_LED_SELECTOR: &[u8; 13] = b"led_selector\0";
+pub const XI_PRM_LED_MODE: &[u8; 9] = b"led_mode\0";
+pub const XI_PRM_DEBOUNCE_EN: &[u8; 8] = b"dbnc_en\0";
+pub const XI_PRM_DEBOUNCE_T0: &[u8; 8] = b"dbnc_t0\0";
+pub const XI_PRM_DEBOUNCE_T1: &[u8; 8] = b"dbnc_t1\0";
+pub const XI_PRM_DEBOUNCE_POL: &[u8; 9] = b"dbnc_pol\0";
+pub const XI_PRM_LENS_MODE: &[u8; 10] = b"lens_mode\0";
+pub const XI_PRM_LENS_APERTURE_VALUE: &[u8; 20] = b"lens_aperture_value\0";
+pub const XI_PRM_LENS_APERTURE_INDEX: &[u8; 20] = b"lens_aperture_index\0";
+pub const XI_PRM_LENS_FOCUS_MOVEMENT_VALUE: &[u8; 26] = b"lens_focus_movement_value\0";
+pub const XI_PRM_LENS_FOCUS_MOVE: &[u8; 16] = b"lens_focus_move\0";
+pub const XI_PRM_LENS_FOCAL_LENGTH: &[u8; 18] = b"lens_focal_length\0";
+pub const XI_PRM_LENS_FEATURE_SELECTOR: &[u8; 22] = b"lens_feature_selector\0";
+pub const XI_PRM_LENS_FEATURE: &[u8; 13] = b"lens_feature\0";
+pub const XI_PRM_DEVICE_NAME: &[u8; 12] = b"device_name\0";
+pub const XI_PRM_DEVICE_TYPE: &[u8; 12] = b"device_type\0";
+pub const XI_PRM_DEVICE_MODEL_ID: &[u8; 16] = b"device_model_id\0";
+pub const XI_PRM_SENSOR_MODEL_ID: &[u8; 16] = b"sensor_model_id\0";
+pub const XI_PRM_DEVICE_SN: &[u8; 10] = b"device_sn\0";
+pub const XI_PRM_DEVICE_SENS_SN: &[u8; 15] = b"device_sens_sn\0";
+pub const XI_PRM_DEVICE_INSTANCE_PATH: &[u8; 17] = b"device_inst_path\0";
+pub const XI_PRM_DEVICE_LOCATION_PATH: &[u8; 16] = b"device_loc_path\0";
+pub const XI_PRM_DEVICE_USER_ID: &[u8; 15] = b"device_user_id\0";
+pub const XI_PRM_DEVICE_MANIFEST: &[u8; 16] = b"device_manifest\0";
+pub const XI_PRM_IMAGE_USER_DATA: &[u8; 16] = b"image_user_data\0";
+pub const XI_PRM_IMAGE_DATA_FORMAT_RGB32_ALPHA: &[u8; 24] = b"imgdataformatrgb32alpha\0";
+pub const XI_PRM_IMAGE_PAYLOAD_SIZE: &[u8; 15] = b"imgpayloadsize\0";
+pub const XI_PRM_TRANSPORT_PIXEL_FORMAT: &[u8; 23] = b"transport_pixel_format\0";
+pub const XI_PRM_TRANSPORT_DATA_TARGET: &[u8; 22] = b"transport_data_target\0";
+pub const XI_PRM_SENSOR_CLOCK_FREQ_HZ: &[u8; 21] = b"sensor_clock_freq_hz\0";
+pub const XI_PRM_SENSOR_CLOCK_FREQ_INDEX: &[u8; 24] = b"sensor_clock_freq_index\0";
+pub const XI_PRM_SENSOR_OUTPUT_CHANNEL_COUNT: &[u8; 28] = b"sensor_output_channel_count\0";
+pub const XI_PRM_FRAMERATE: &[u8; 10] = b"framerate\0";
+pub const XI_PRM_COUNTER_SELECTOR: &[u8; 17] = b"counter_selector\0";
+pub const XI_PRM_COUNTER_VALUE: &[u8; 14] = b"counter_value\0";
+pub const XI_PRM_ACQ_TIMING_MODE: &[u8; 16] = b"acq_timing_mode\0";
+pub const XI_PRM_AVAILABLE_BANDWIDTH: &[u8; 20] = b"available_bandwidth\0";
+pub const XI_PRM_BUFFER_POLICY: &[u8; 14] = b"buffer_policy\0";
+pub const XI_PRM_LUT_EN: &[u8; 10] = b"LUTEnable\0";
+pub const XI_PRM_LUT_INDEX: &[u8; 9] = b"LUTIndex\0";
+pub const XI_PRM_LUT_VALUE: &[u8; 9] = b"LUTValue\0";
+pub const XI_PRM_TRG_DELAY: &[u8; 14] = b"trigger_delay\0";
+pub const XI_PRM_TS_RST_MODE: &[u8; 12] = b"ts_rst_mode\0";
+pub const XI_PRM_TS_RST_SOURCE: &[u8; 14] = b"ts_rst_source\0";
+pub const XI_PRM_IS_DEVICE_EXIST: &[u8; 8] = b"isexist\0";
+pub const XI_PRM_ACQ_BUFFER_SIZE: &[u8; 16] = b"acq_buffer_size\0";
+pub const XI_PRM_ACQ_BUFFER_SIZE_UNIT: &[u8; 21] = b"acq_buffer_size_unit\0";
+pub const XI_PRM_ACQ_TRANSPORT_BUFFER_SIZE: &[u8; 26] = b"acq_transport_buffer_size\0";
+pub const XI_PRM_ACQ_TRANSPORT_PACKET_SIZE: &[u8; 26] = b"acq_transport_packet_size\0";
+pub const XI_PRM_BUFFERS_QUEUE_SIZE: &[u8; 19] = b"buffers_queue_size\0";
+pub const XI_PRM_ACQ_TRANSPORT_BUFFER_COMMIT: &[u8; 28] = b"acq_transport_buffer_commit\0";
+pub const XI_PRM_RECENT_FRAME: &[u8; 13] = b"recent_frame\0";
+pub const XI_PRM_DEVICE_RESET: &[u8; 13] = b"device_reset\0";
+pub const XI_PRM_CONCAT_IMG_MODE: &[u8; 16] = b"concat_img_mode\0";
+pub const XI_PRM_CONCAT_IMG_COUNT: &[u8; 17] = b"concat_img_count\0";
+pub const XI_PRM_CONCAT_IMG_TRANSPORT_IMG_OFFSET: &[u8; 32] = b"concat_img_transport_img_offset\0";
+pub const XI_PRM_PROBE_SELECTOR: &[u8; 15] = b"probe_selector\0";
+pub const XI_PRM_PROBE_VALUE: &[u8; 12] = b"probe_value\0";
+pub const XI_PRM_COLUMN_FPN_CORRECTION: &[u8; 22] = b"column_fpn_correction\0";
+pub const XI_PRM_ROW_FPN_CORRECTION: &[u8; 19] = b"row_fpn_correction\0";
+pub const XI_PRM_COLUMN_BLACK_OFFSET_CORRECTION: &[u8; 31] = b"column_black_offset_correction\0";
+pub const XI_PRM_ROW_BLACK_OFFSET_CORRECTION: &[u8; 28] = b"row_black_offset_correction\0";
+pub const XI_PRM_SENSOR_MODE: &[u8; 12] = b"sensor_mode\0";
+pub const XI_PRM_HDR: &[u8; 4] = b"hdr\0";
+pub const XI_PRM_HDR_KNEEPOINT_COUNT: &[u8; 20] = b"hdr_kneepoint_count\0";
+pub const XI_PRM_HDR_T1: &[u8; 7] = b"hdr_t1\0";
+pub const XI_PRM_HDR_T2: &[u8; 7] = b"hdr_t2\0";
+pub const XI_PRM_KNEEPOINT1: &[u8; 15] = b"hdr_kneepoint1\0";
+pub const XI_PRM_KNEEPOINT2: &[u8; 15] = b"hdr_kneepoint2\0";
+pub const XI_PRM_IMAGE_BLACK_LEVEL: &[u8; 18] = b"image_black_level\0";
+pub const XI_PRM_IMAGE_AREA: &[u8; 11] = b"image_area\0";
+pub const XI_PRM_DUAL_ADC_MODE: &[u8; 14] = b"dual_adc_mode\0";
+pub const XI_PRM_DUAL_ADC_GAIN_RATIO: &[u8; 20] = b"dual_adc_gain_ratio\0";
+pub const XI_PRM_DUAL_ADC_THRESHOLD: &[u8; 19] = b"dual_adc_threshold\0";
+pub const XI_PRM_COMPRESSION_REGION_SELECTOR: &[u8; 28] = b"compression_region_selector\0";
+pub const XI_PRM_COMPRESSION_REGION_START: &[u8; 25] = b"compression_region_start\0";
+pub const XI_PRM_COMPRESSION_REGION_GAIN: &[u8; 24] = b"compression_region_gain\0";
+pub const XI_PRM_VERSION_SELECTOR: &[u8; 17] = b"version_selector\0";
+pub const XI_PRM_VERSION: &[u8; 8] = b"version\0";
+pub const XI_PRM_API_VERSION: &[u8; 12] = b"api_version\0";
+pub const XI_PRM_DRV_VERSION: &[u8; 12] = b"drv_version\0";
+pub const XI_PRM_MCU1_VERSION: &[u8; 13] = b"version_mcu1\0";
+pub const XI_PRM_MCU2_VERSION: &[u8; 13] = b"version_mcu2\0";
+pub const XI_PRM_MCU3_VERSION: &[u8; 13] = b"version_mcu3\0";
+pub const XI_PRM_FPGA1_VERSION: &[u8; 14] = b"version_fpga1\0";
+pub const XI_PRM_XMLMAN_VERSION: &[u8; 15] = b"version_xmlman\0";
+pub const XI_PRM_HW_REVISION: &[u8; 12] = b"hw_revision\0";
+pub const XI_PRM_FACTORY_SET_VERSION: &[u8; 20] = b"factory_set_version\0";
+pub const XI_PRM_DEBUG_LEVEL: &[u8; 12] = b"debug_level\0";
+pub const XI_PRM_AUTO_BANDWIDTH_CALCULATION: &[u8; 27] = b"auto_bandwidth_calculation\0";
+pub const XI_PRM_NEW_PROCESS_CHAIN_ENABLE: &[u8; 25] = b"new_process_chain_enable\0";
+pub const XI_PRM_PROC_NUM_THREADS: &[u8; 17] = b"proc_num_threads\0";
+pub const XI_PRM_READ_FILE_FFS: &[u8; 14] = b"read_file_ffs\0";
+pub const XI_PRM_WRITE_FILE_FFS: &[u8; 15] = b"write_file_ffs\0";
+pub const XI_PRM_FFS_FILE_NAME: &[u8; 14] = b"ffs_file_name\0";
+pub const XI_PRM_FFS_FILE_ID: &[u8; 12] = b"ffs_file_id\0";
+pub const XI_PRM_FFS_FILE_SIZE: &[u8; 14] = b"ffs_file_size\0";
+pub const XI_PRM_FREE_FFS_SIZE: &[u8; 14] = b"free_ffs_size\0";
+pub const XI_PRM_USED_FFS_SIZE: &[u8; 14] = b"used_ffs_size\0";
+pub const XI_PRM_FFS_ACCESS_KEY: &[u8; 15] = b"ffs_access_key\0";
+pub const XI_PRM_API_CONTEXT_LIST: &[u8; 19] = b"xiapi_context_list\0";
+pub const XI_PRM_SENSOR_FEATURE_SELECTOR: &[u8; 24] = b"sensor_feature_selector\0";
+pub const XI_PRM_SENSOR_FEATURE_VALUE: &[u8; 21] = b"sensor_feature_value\0";
+pub const XI_PRM_ACQUISITION_STATUS_SELECTOR: &[u8; 28] = b"acquisition_status_selector\0";
+pub const XI_PRM_ACQUISITION_STATUS: &[u8; 19] = b"acquisition_status\0";
+pub const XI_PRM_DP_UNIT_SELECTOR: &[u8; 17] = b"dp_unit_selector\0";
+pub const XI_PRM_DP_PROC_SELECTOR: &[u8; 17] = b"dp_proc_selector\0";
+pub const XI_PRM_DP_PARAM_SELECTOR: &[u8; 18] = b"dp_param_selector\0";
+pub const XI_PRM_DP_PARAM_VALUE: &[u8; 15] = b"dp_param_value\0";
+pub const XI_PRM_GENTL_DATASTREAM_ENABLED: &[u8; 16] = b"gentl_stream_en\0";
+pub const XI_PRM_GENTL_DATASTREAM_CONTEXT: &[u8; 21] = b"gentl_stream_context\0";
+pub const XI_PRM_USER_SET_SELECTOR: &[u8; 18] = b"user_set_selector\0";
+pub const XI_PRM_USER_SET_LOAD: &[u8; 14] = b"user_set_load\0";
+pub const XI_PRM_USER_SET_DEFAULT: &[u8; 17] = b"user_set_default\0";
+pub const XI_PRM_INFO_SETTABLE: &[u8; 10] = b":settable\0";
+pub const XI_PRM_INFO_MIN: &[u8; 5] = b":min\0";
+pub const XI_PRM_INFO_MAX: &[u8; 5] = b":max\0";
+pub const XI_PRM_INFO_INCREMENT: &[u8; 5] = b":inc\0";
+pub const XI_PRM_INFO: &[u8; 6] = b":info\0";
+pub const XI_PRMM_REQ_VAL_BUFFER_SIZE: &[u8; 14] = b":req_buf_size\0";
+pub const XI_PRMM_DIRECT_UPDATE: &[u8; 15] = b":direct_update\0";
+pub const XI_PRM_BPC: &[u8; 4] = b"bpc\0";
+pub const XI_MQ_LED_STATUS1: u32 = 1;
+pub const XI_MQ_LED_STATUS2: u32 = 2;
+pub const XI_MQ_LED_POWER: u32 = 3;
+pub mod E_MODEL {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const MODEL_ID_UNKNOWN: Type = 0;
+    pub const MODEL_ID_MR274CU_BH: Type = 1;
+    pub const MODEL_ID_MR16000MU: Type = 2;
+    pub const MODEL_ID_MR282CC_BH: Type = 3;
+    pub const MODEL_ID_MR274MU_BH: Type = 4;
+    pub const MODEL_ID_MR456CU_BH: Type = 5;
+    pub const MODEL_ID_MR252CC_BH: Type = 6;
+    pub const MODEL_ID_MR4021MU_BH: Type = 7;
+    pub const MODEL_ID_MR4022MU_BH: Type = 116;
+    pub const MODEL_ID_MR655CU_BH: Type = 9;
+    pub const MODEL_ID_MR11002M: Type = 10;
+    pub const MODEL_ID_MR4021CU_BH: Type = 11;
+    pub const MODEL_ID_MR655MU_BH: Type = 12;
+    pub const MODEL_ID_MR282CU_BH: Type = 13;
+    pub const MODEL_ID_MR252CU_BH: Type = 14;
+    pub const MODEL_ID_MR285MU_BH: Type = 15;
+    pub const MODEL_ID_MR285CU_BH: Type = 16;
+    pub const MODEL_ID_MR285MC_BH: Type = 17;
+    pub const MODEL_ID_MR285CC_BH: Type = 18;
+    pub const MODEL_ID_MH160MC_KK_FA: Type = 112;
+    pub const MODEL_ID_MU9PC_BH: Type = 20;
+    pub const MODEL_ID_MR11002C: Type = 21;
+    pub const MODEL_ID_MU9PM_MH: Type = 22;
+    pub const MODEL_ID_MU9PC_MH: Type = 23;
+    pub const MODEL_ID_MU9PM_BH: Type = 24;
+    pub const MODEL_ID_GENTLXIAPI_REFERENCE: Type = 35;
+    pub const MODEL_ID_MQ013CG_E2: Type = 49;
+    pub const MODEL_ID_MQ013MG_E2: Type = 50;
+    pub const MODEL_ID_MQ003CG_CM: Type = 51;
+    pub const MODEL_ID_MQ003MG_CM: Type = 52;
+    pub const MODEL_ID_MQ022CG_CM: Type = 53;
+    pub const MODEL_ID_MQ022MG_CM: Type = 54;
+    pub const MODEL_ID_MQ042CG_CM: Type = 55;
+    pub const MODEL_ID_MQ042MG_CM: Type = 56;
+    pub const MODEL_ID_MM282CU_BH: Type = 158;
+    pub const MODEL_ID_MQ022MG_CM_SR2: Type = 58;
+    pub const MODEL_ID_MQ042CG_CM_TG: Type = 59;
+    pub const MODEL_ID_MQ042MG_CM_TG: Type = 60;
+    pub const MODEL_ID_MQ_USB3LINK: Type = 61;
+    pub const MODEL_ID_MU9PC_SLC5: Type = 62;
+    pub const MODEL_ID_MQ022CG_CM_TS: Type = 66;
+    pub const MODEL_ID_MQ022MG_CM_TS: Type = 67;
+    pub const MODEL_ID_MQ042CG_CM_TS: Type = 68;
+    pub const MODEL_ID_MQ042MG_CM_TS: Type = 69;
+    pub const MODEL_ID_MQ013CG_ONV: Type = 70;
+    pub const MODEL_ID_MQ013MG_ONV: Type = 71;
+    pub const MODEL_ID_MQ013RG_E2: Type = 72;
+    pub const MODEL_ID_MQ042RG_CM: Type = 73;
+    pub const MODEL_ID_MR11002XC_ICW: Type = 75;
+    pub const MODEL_ID_MQ020CG_E2: Type = 76;
+    pub const MODEL_ID_MQ020MG_E2: Type = 77;
+    pub const MODEL_ID_MQ022RG_CM: Type = 78;
+    pub const MODEL_ID_MR285CC_DP: Type = 79;
+    pub const MODEL_ID_MR285MC_DP: Type = 80;
+    pub const MODEL_ID_MR252CU_BRD: Type = 81;
+    pub const MODEL_ID_MH110MC_KK_FA: Type = 82;
+    pub const MODEL_ID_MR282CU_BRD: Type = 83;
+    pub const MODEL_ID_MR282CC_DP: Type = 84;
+    pub const MODEL_ID_MR285MU_BH_IRE: Type = 85;
+    pub const MODEL_ID_MR285MC_DP_IRE: Type = 86;
+    pub const MODEL_ID_MH110XC_KK_FA: Type = 87;
+    pub const MODEL_ID_MH160XC_KK_FA: Type = 88;
+    pub const MODEL_ID_MR252CC_DP: Type = 90;
+    pub const MODEL_ID_MR285MC_BH_IRE: Type = 91;
+    pub const MODEL_ID_MR456CC_BH: Type = 92;
+    pub const MODEL_ID_MR282CU_DP: Type = 93;
+    pub const MODEL_ID_MQ022HG_IM_ST32_NIR: Type = 135;
+    pub const MODEL_ID_MR282CC_BRD: Type = 96;
+    pub const MODEL_ID_MR252CC_BRD: Type = 100;
+    pub const MODEL_ID_MQ022HG_IM_SM4X4_VIS: Type = 136;
+    pub const MODEL_ID_MR252CU_DP: Type = 101;
+    pub const MODEL_ID_MR285MU_BRD: Type = 102;
+    pub const MODEL_ID_MR285CU_BRD: Type = 103;
+    pub const MODEL_ID_MR285MC_BRD: Type = 104;
+    pub const MODEL_ID_MR285CC_BRD: Type = 105;
+    pub const MODEL_ID_MR285CC_DP_IRE: Type = 106;
+    pub const MODEL_ID_MR285CC_BH_IRE: Type = 107;
+    pub const MODEL_ID_MR285CU_BH_IRE: Type = 108;
+    pub const MODEL_ID_MX11002: Type = 109;
+    pub const MODEL_ID_MH110CC_KK_FA: Type = 110;
+    pub const MODEL_ID_MR16000CU: Type = 111;
+    pub const MODEL_ID_MH160CC_KK_FA: Type = 113;
+    pub const MODEL_ID_MR4022MC_VELETA: Type = 114;
+    pub const MODEL_ID_MR4021MC_VELETA: Type = 115;
+    pub const MODEL_ID_MU9JC_BH: Type = 117;
+    pub const MODEL_ID_MU9JM_BH: Type = 118;
+    pub const MODEL_ID_MQ022HG_IM_LS100_NIR: Type = 134;
+    pub const MODEL_ID_CB120RG_CM_X8G3: Type = 174;
+    pub const MODEL_ID_MD091CC_SY: Type = 122;
+    pub const MODEL_ID_CB120MG_CM_X8G3: Type = 173;
+    pub const MODEL_ID_MD028CU_SY: Type = 126;
+    pub const MODEL_ID_MD061CU_SY: Type = 127;
+    pub const MODEL_ID_MD091CU_SY: Type = 128;
+    pub const MODEL_ID_MD028MU_SY: Type = 129;
+    pub const MODEL_ID_MD061MU_SY: Type = 130;
+    pub const MODEL_ID_MD091MU_SY: Type = 131;
+    pub const MODEL_ID_CB200CG_CM: Type = 132;
+    pub const MODEL_ID_CB200MG_CM: Type = 133;
+    pub const MODEL_ID_CB120CG_CM_X8G3: Type = 172;
+    pub const MODEL_ID_CB120RG_CM: Type = 171;
+    pub const MODEL_ID_MD120CU_SY: Type = 139;
+    pub const MODEL_ID_MD120MU_SY: Type = 140;
+    pub const MODEL_ID_MQ022HG_IM_UN: Type = 141;
+    pub const MODEL_ID_CAL_Simulator: Type = 142;
+    pub const MODEL_ID_MT031CG_SY: Type = 164;
+    pub const MODEL_ID_MQ022HG_IM_LS150_VISNIR: Type = 143;
+    pub const MODEL_ID_MQ022HG_IM_SM5X5_NIR: Type = 144;
+    pub const MODEL_ID_MQ022HG_IM_SM4X4_NIR: Type = 145;
+    pub const MODEL_ID_MQ022MG_CM_BARE_BRD: Type = 146;
+    pub const MODEL_ID_MQ042MG_CM_BARE_BRD: Type = 147;
+    pub const MODEL_ID_MT023CG_SY: Type = 148;
+    pub const MODEL_ID_MT023MG_SY: Type = 149;
+    pub const MODEL_ID_MT200CG_CM: Type = 150;
+    pub const MODEL_ID_MT200MG_CM: Type = 151;
+    pub const MODEL_ID_CB120CG_CM: Type = 152;
+    pub const MODEL_ID_CB120MG_CM: Type = 153;
+    pub const MODEL_ID_MT003CG_LX: Type = 154;
+    pub const MODEL_ID_MT003MG_LX: Type = 155;
+    pub const MODEL_ID_MQ013CG_ON: Type = 156;
+    pub const MODEL_ID_MQ013MG_ON: Type = 157;
+    pub const MODEL_ID_MT050CG_SY: Type = 159;
+    pub const MODEL_ID_MT050MG_SY: Type = 160;
+    pub const MODEL_ID_MT120CG_CM: Type = 161;
+    pub const MODEL_ID_MT031MG_SY: Type = 165;
+    pub const MODEL_ID_MT120MG_CM: Type = 163;
+    pub const MODEL_ID_MJ042IC_TS_UB: Type = 166;
+    pub const MODEL_ID_MH110XC_KK_TP2_1: Type = 168;
+    pub const MODEL_ID_MC023CG_SY: Type = 169;
+    pub const MODEL_ID_MC023MG_SY: Type = 170;
+    pub const MODEL_ID_MC023CG_SY_FLEX: Type = 205;
+    pub const MODEL_ID_MX124CG_SY_X2G2: Type = 175;
+    pub const MODEL_ID_MX124MG_SY_X2G2: Type = 176;
+    pub const MODEL_ID_MX089CG_SY_X2G2: Type = 177;
+    pub const MODEL_ID_MX089MG_SY_X2G2: Type = 178;
+    pub const MODEL_ID_MC031CG_SY: Type = 179;
+    pub const MODEL_ID_MC031MG_SY: Type = 180;
+    pub const MODEL_ID_MC050CG_SY: Type = 181;
+    pub const MODEL_ID_MC050MG_SY: Type = 182;
+    pub const MODEL_ID_MC089CG_SY: Type = 183;
+    pub const MODEL_ID_MC124CG_SY: Type = 186;
+    pub const MODEL_ID_MC089MG_SY: Type = 185;
+    pub const MODEL_ID_MC124MG_SY: Type = 187;
+    pub const MODEL_ID_MX023CG_SY_X2G2: Type = 188;
+    pub const MODEL_ID_MX023MG_SY_X2G2: Type = 189;
+    pub const MODEL_ID_MX031CG_SY_X2G2: Type = 190;
+    pub const MODEL_ID_MX031MG_SY_X2G2: Type = 191;
+    pub const MODEL_ID_MX050CG_SY_X2G2: Type = 192;
+    pub const MODEL_ID_MX050MG_SY_X2G2: Type = 193;
+    pub const MODEL_ID_MX042CG_CM_X2G2: Type = 194;
+    pub const MODEL_ID_MX042MG_CM_X2G2: Type = 195;
+    pub const MODEL_ID_MX042RG_CM_X2G2: Type = 196;
+    pub const MODEL_ID_CB500CG_CM: Type = 197;
+    pub const MODEL_ID_CB500MG_CM: Type = 198;
+    pub const MODEL_ID_CB042CG_GP: Type = 199;
+    pub const MODEL_ID_CB042MG_GP: Type = 200;
+    pub const MODEL_ID_CB013CG_LX_X8G3: Type = 201;
+    pub const MODEL_ID_CB013MG_LX_X8G3: Type = 202;
+    pub const MODEL_ID_MJ081MC_TS_TC: Type = 203;
+    pub const MODEL_ID_MC023MG_SY_FLEX: Type = 206;
+    pub const MODEL_ID_MC031CG_SY_FLEX: Type = 207;
+    pub const MODEL_ID_MC031MG_SY_FLEX: Type = 208;
+    pub const MODEL_ID_MC050CG_SY_FLEX: Type = 209;
+    pub const MODEL_ID_MC050MG_SY_FLEX: Type = 210;
+    pub const MODEL_ID_MC089CG_SY_FLEX: Type = 211;
+    pub const MODEL_ID_MC089MG_SY_FLEX: Type = 212;
+    pub const MODEL_ID_MC124CG_SY_FLEX: Type = 213;
+    pub const MODEL_ID_MC124MG_SY_FLEX: Type = 214;
+    pub const MODEL_ID_MQ013RG_ON: Type = 215;
+    pub const MODEL_ID_MJ042MC_TS_TC: Type = 216;
+    pub const MODEL_ID_MJ081XC_TS_TC: Type = 217;
+    pub const MODEL_ID_MJ081XC_TS_TP1_1_25: Type = 218;
+    pub const MODEL_ID_MJ150MR_GP: Type = 219;
+    pub const MODEL_ID_MX200CG_CM_X4G2: Type = 220;
+    pub const MODEL_ID_MX200MG_CM_X4G2: Type = 221;
+    pub const MODEL_ID_MX120CG_CM_X4G2: Type = 222;
+    pub const MODEL_ID_MX120MG_CM_X4G2: Type = 223;
+    pub const MODEL_ID_MX120RG_CM_X4G2: Type = 224;
+    pub const MODEL_ID_MJ160MU_TS_UB: Type = 225;
+    pub const MODEL_ID_MJ160MC_TS_UB: Type = 226;
+    pub const MODEL_ID_MQ022HG_IM_SM2X2_RGBNIR: Type = 227;
+    pub const MODEL_ID_CB019CG_LX_X8G3: Type = 228;
+    pub const MODEL_ID_CB019MG_LX_X8G3: Type = 229;
+    pub const MODEL_ID_CB160CG_LX_X8G3: Type = 230;
+    pub const MODEL_ID_CB160MG_LX_X8G3: Type = 231;
+    pub const MODEL_ID_MJ160XC_TS_UB: Type = 232;
+    pub const MODEL_ID_MX004MG_SY_X2G2: Type = 233;
+    pub const MODEL_ID_MX004CG_SY_X2G2: Type = 234;
+    pub const MODEL_ID_MX016MG_SY_X2G2: Type = 235;
+    pub const MODEL_ID_MX016CG_SY_X2G2: Type = 236;
+    pub const MODEL_ID_MJ290MC_TS_UB: Type = 237;
+    pub const MODEL_ID_MJ150XR_GP_TP2_1_GO: Type = 258;
+    pub const MODEL_ID_MJ042MU_TS_TC: Type = 252;
+    pub const MODEL_ID_MX022CG_CM_X2G2: Type = 248;
+    pub const MODEL_ID_MX022MG_CM_X2G2: Type = 249;
+    pub const MODEL_ID_MX022RG_CM_X2G2: Type = 250;
+    pub const MODEL_ID_MX019MM_PH_X2G2: Type = 251;
+    pub const MODEL_ID_MX500CG_CM_X4G2: Type = 253;
+    pub const MODEL_ID_MX500MG_CM_X4G2: Type = 256;
+    pub const MODEL_ID_MU181CR_ON: Type = 257;
+    pub const MODEL_ID_MQ022MG_CM_BRD: Type = 402;
+    pub const MODEL_ID_MJ042MR_GP_P11: Type = 499;
+    pub const MODEL_ID_MJ042MR_GP_P11_BSI: Type = 500;
+    pub const MODEL_ID_MQ022MG_CM_SL_BRD: Type = 633;
+    pub const MODEL_ID_MQ022MG_CM_FL_BRD: Type = 661;
+    pub const MODEL_ID_MQ022MG_CM_FL: Type = 685;
+    pub const MODEL_ID_MX377MR_GP_Fx_X4G3_MTP_W: Type = 701;
+    pub const MODEL_ID_MX377MR_GP_Bx_X4G3_MTP_W: Type = 703;
+    pub const MODEL_ID_CB262MG_GP_X8G3: Type = 706;
+    pub const MODEL_ID_CB262CG_GP_X8G3: Type = 707;
+    pub const MODEL_ID_MR655MU_BRD: Type = 1230;
+    pub const MODEL_ID_MX610CR_SY_X4G3_FF: Type = 1210;
+    pub const MODEL_ID_MQ022HG_IM_SM4X4_VIS3: Type = 1201;
+    pub const MODEL_ID_MX1510MR_SY_X4G3_FF: Type = 1205;
+    pub const MODEL_ID_MX1510CR_SY_X4G3_FF: Type = 1206;
+    pub const MODEL_ID_MX1018MR_SY_X4G3_FF: Type = 1207;
+    pub const MODEL_ID_MX1018CR_SY_X4G3_FF: Type = 1208;
+    pub const MODEL_ID_MX610MR_SY_X4G3_FF: Type = 1209;
+    pub const MODEL_ID_MX120CG_CM_X8G3_FF: Type = 1238;
+    pub const MODEL_ID_CB042MG_GP_BSI: Type = 843;
+    pub const MODEL_ID_CB042CG_GP_BSI: Type = 844;
+    pub const MODEL_ID_CB500MG_CM_X8G3_ELD: Type = 855;
+    pub const MODEL_ID_CB654CG_GP_X8G3: Type = 858;
+    pub const MODEL_ID_CB654MG_GP_X8G3: Type = 859;
+    pub const MODEL_ID_MC050YG_SY_UB: Type = 882;
+    pub const MODEL_ID_MC050ZG_SY_UB: Type = 883;
+    pub const MODEL_ID_MJ042MR_GP_P6: Type = 887;
+    pub const MODEL_ID_MJ042MR_GP_P6_BSI: Type = 888;
+    pub const MODEL_ID_MX377MR_GP_Fx_X4G3_MTP: Type = 900;
+    pub const MODEL_ID_MX377MR_GP_Bx_X4G3_MTP: Type = 901;
+    pub const MODEL_ID_MJ042MR_GP_P11_BSI_TVISB: Type = 902;
+    pub const MODEL_ID_MJ042MR_GP_P11_BSI_UV: Type = 903;
+    pub const MODEL_ID_MJ042MR_GP_P11_BSI_VIS: Type = 904;
+    pub const MODEL_ID_CB120CG_CM_X8G3_R2: Type = 905;
+    pub const MODEL_ID_CB120MG_CM_X8G3_R2: Type = 906;
+    pub const MODEL_ID_CB120RG_CM_X8G3_R2: Type = 907;
+    pub const MODEL_ID_CB013CG_LX_X8G3_R2: Type = 908;
+    pub const MODEL_ID_CB013MG_LX_X8G3_R2: Type = 909;
+    pub const MODEL_ID_CB019CG_LX_X8G3_R2: Type = 910;
+    pub const MODEL_ID_CB019MG_LX_X8G3_R2: Type = 911;
+    pub const MODEL_ID_CB160CG_LX_X8G3_R2: Type = 912;
+    pub const MODEL_ID_CB160MG_LX_X8G3_R2: Type = 913;
+    pub const MODEL_ID_MX1510MR_SY_X2G2_VXL: Type = 914;
+    pub const MODEL_ID_MX1510CR_SY_X2G2_VXL: Type = 915;
+    pub const MODEL_ID_MQ022HG_IM_SM4X4_REDNIR: Type = 919;
+    pub const MODEL_ID_MX200MG_CM_X4G2_TG_FL_EMS: Type = 920;
+    pub const MODEL_ID_MH160XC_KK_TP2_1: Type = 922;
+    pub const MODEL_ID_MU181CR_ON_R3: Type = 923;
+    pub const MODEL_ID_MX042MR_GP_X4G2_ARX: Type = 928;
+    pub const MODEL_ID_MX161CG_SY_X2G2: Type = 941;
+    pub const MODEL_ID_MX161MG_SY_X2G2: Type = 944;
+    pub const MODEL_ID_MX203CG_SY_X2G2: Type = 960;
+    pub const MODEL_ID_MX203MG_SY_X2G2: Type = 963;
+    pub const MODEL_ID_MX245CG_SY_X2G2: Type = 970;
+    pub const MODEL_ID_MX245MG_SY_X2G2: Type = 973;
+    pub const MODEL_ID_MX019MM_PH_X2G2_FV_FR: Type = 1004;
+    pub const MODEL_ID_MJ150CR_GP: Type = 993;
+    pub const MODEL_ID_MJ150XR_GP_FA_GO: Type = 996;
+    pub const MODEL_ID_MQ022HG_IM_LS100_NIR2: Type = 1005;
+    pub const MODEL_ID_MQ022HG_IM_LS150_VN2: Type = 1006;
+    pub const MODEL_ID_MQ022HG_IM_SM4X4_VIS2: Type = 1007;
+    pub const MODEL_ID_MQ022HG_IM_SM4X4_RN2: Type = 1008;
+    pub const MODEL_ID_MQ022HG_IM_SM5X5_NIR2: Type = 1009;
+    pub const MODEL_ID_MX022HG_IM_LS100_NIR2_FL: Type = 1010;
+    pub const MODEL_ID_MX022HG_IM_LS150_VN2_FL: Type = 1011;
+    pub const MODEL_ID_MX022HG_IM_SM4X4_VIS2_FL: Type = 1012;
+    pub const MODEL_ID_MX022HG_IM_SM4X4_RN2_FL: Type = 1013;
+    pub const MODEL_ID_MX022HG_IM_SM5X5_NIR2_FL: Type = 1014;
+    pub const MODEL_ID_MX022HG_IM_LS100_NIR2_FV: Type = 1015;
+    pub const MODEL_ID_MX022HG_IM_LS150_VN2_FV: Type = 1016;
+    pub const MODEL_ID_MX022HG_IM_SM4X4_VIS2_FV: Type = 1017;
+    pub const MODEL_ID_MX022HG_IM_SM4X4_RN2_FV: Type = 1018;
+    pub const MODEL_ID_MX022HG_IM_SM5X5_NIR2_FV: Type = 1019;
+    pub const MODEL_ID_MX022HG_IM_LS100_NIR2_FF: Type = 1020;
+    pub const MODEL_ID_MX022HG_IM_LS150_VN2_FF: Type = 1021;
+    pub const MODEL_ID_MX022HG_IM_SM4X4_VIS2_FF: Type = 1022;
+    pub const MODEL_ID_MX022HG_IM_SM4X4_RN2_FF: Type = 1023;
+    pub const MODEL_ID_MX022HG_IM_SM5X5_NIR2_FF: Type = 1024;
+    pub const MODEL_ID_MX022MG_CM_BARE_FL_IM: Type = 1025;
+    pub const MODEL_ID_MX022MG_CM_BARE_FL_BRD: Type = 1026;
+    pub const MODEL_ID_MQ022HG_IM_LS100_NIR2_FL: Type = 1027;
+    pub const MODEL_ID_MQ022HG_IM_LS150_VN2_FL: Type = 1028;
+    pub const MODEL_ID_MQ022HG_IM_SM4X4_VIS2_FL: Type = 1029;
+    pub const MODEL_ID_MQ022HG_IM_SM4X4_RN2_FL: Type = 1030;
+    pub const MODEL_ID_MQ022HG_IM_SM5X5_NIR2_FL: Type = 1031;
+    pub const MODEL_ID_MQ022MG_CM_BARE_IM: Type = 1032;
+    pub const MODEL_ID_MQ022MG_CM_BARE_FL_IM: Type = 1033;
+    pub const MODEL_ID_MQ022MG_CM_BARE_FL_BRD: Type = 1034;
+    pub const MODEL_ID_MX262CG_GP_X4G2_FF: Type = 1042;
+    pub const MODEL_ID_MX262MG_GP_X4G2_FF: Type = 1041;
+    pub const MODEL_ID_MX262RG_GP_X4G2_FF: Type = 1040;
+    pub const MODEL_ID_MX610CR_SY_X2G2_VXL: Type = 1043;
+    pub const MODEL_ID_MX610MR_SY_X2G2_VXL: Type = 1044;
+    pub const MODEL_ID_MX510XG_GP_FA_GO: Type = 1050;
+    pub const MODEL_ID_MC161CG_SY_FLEX: Type = 1081;
+    pub const MODEL_ID_MC161MG_SY_FLEX: Type = 1082;
+    pub const MODEL_ID_MC161CG_SY: Type = 1083;
+    pub const MODEL_ID_MC161MG_SY: Type = 1084;
+    pub const MODEL_ID_MC203CG_SY: Type = 1095;
+    pub const MODEL_ID_MC203CG_SY_FLEX: Type = 1097;
+    pub const MODEL_ID_MC203MG_SY: Type = 1100;
+    pub const MODEL_ID_MC203MG_SY_FLEX: Type = 1102;
+    pub const MODEL_ID_MC245CG_SY: Type = 1105;
+    pub const MODEL_ID_MC245CG_SY_FLEX: Type = 1107;
+    pub const MODEL_ID_MC245MG_SY: Type = 1110;
+    pub const MODEL_ID_MC245MG_SY_FLEX: Type = 1112;
+    pub const MODEL_ID_MX510XG_GP_TP2_1_GO: Type = 1119;
+    pub const MODEL_ID_MX161CG_SY_X2G2_HDR: Type = 1133;
+    pub const MODEL_ID_MX161MG_SY_X2G2_HDR: Type = 1137;
+    pub const MODEL_ID_MX203CG_SY_X2G2_HDR: Type = 1141;
+    pub const MODEL_ID_MX203MG_SY_X2G2_HDR: Type = 1145;
+    pub const MODEL_ID_MX245CG_SY_X2G2_HDR: Type = 1149;
+    pub const MODEL_ID_MX245MG_SY_X2G2_HDR: Type = 1153;
+    pub const MODEL_ID_MC161CG_SY_HDR: Type = 1157;
+    pub const MODEL_ID_MC161CG_SY_FLEX_HDR: Type = 1159;
+    pub const MODEL_ID_MC161MG_SY_HDR: Type = 1162;
+    pub const MODEL_ID_MC161MG_SY_FLEX_HDR: Type = 1164;
+    pub const MODEL_ID_MC203CG_SY_HDR: Type = 1167;
+    pub const MODEL_ID_MC203CG_SY_FLEX_HDR: Type = 1169;
+    pub const MODEL_ID_MC203MG_SY_HDR: Type = 1172;
+    pub const MODEL_ID_MC203MG_SY_FLEX_HDR: Type = 1174;
+    pub const MODEL_ID_MC245CG_SY_HDR: Type = 1177;
+    pub const MODEL_ID_MC245CG_SY_FLEX_HDR: Type = 1179;
+    pub const MODEL_ID_MC245MG_SY_HDR: Type = 1182;
+    pub const MODEL_ID_MC245MG_SY_FLEX_HDR: Type = 1184;
+    pub const MODEL_ID_MX120MG_CM_X8G3_FF: Type = 1239;
+    pub const MODEL_ID_MJ150XR_GP_TP2_6_1_GO: Type = 1251;
+    pub const MODEL_ID_MX245MG_SY_X4G3_FF: Type = 1281;
+    pub const MODEL_ID_MX245CG_SY_X4G3_FF: Type = 1282;
+    pub const MODEL_ID_MX203MG_SY_X4G3_FF: Type = 1283;
+    pub const MODEL_ID_MX203CG_SY_X4G3_FF: Type = 1284;
+    pub const MODEL_ID_MX161MG_SY_X4G3_FF: Type = 1285;
+    pub const MODEL_ID_MX161CG_SY_X4G3_FF: Type = 1286;
+    pub const MODEL_ID_MU181CR_ON_CZM: Type = 1288;
+    pub const MODEL_ID_MU181CR_ON_CZM_R3: Type = 1289;
+    pub const MODEL_ID_MX510XG_GP_FA_CSI: Type = 1290;
+    pub const MODEL_ID_MX510MG_GP: Type = 1291;
+    pub const MODEL_ID_MX124CG_SY_LT_X2G2: Type = 1320;
+    pub const MODEL_ID_MX124MG_SY_LT_X2G2: Type = 1324;
+    pub const MODEL_ID_MC031MG_SY_FL_PHO: Type = 1335;
+    pub const MODEL_ID_CB023MR_GP_X8G3: Type = 1344;
+    pub const MODEL_ID_MX031MG_SY_X2G2_CS_PHO: Type = 1351;
+    pub const MODEL_ID_MJ042MR_GP_P11_BSI_NEO: Type = 1353;
+    pub const MODEL_ID_MX610XR_SY_X4G3_FA_CSI: Type = 1362;
+    pub const MODEL_ID_MX610XR_SY_X4G3_FA_GO: Type = 1363;
+    pub const MODEL_ID_MX610XR_SY_X4G3_TP2_1_CSI: Type = 1364;
+    pub const MODEL_ID_MX610XR_SY_X4G3_TP2_1_GO: Type = 1365;
+    pub const MODEL_ID_MX262RG_GP_X8G3_MTP_LA: Type = 1366;
+    pub const MODEL_ID_MX081UG_SY_X2G2_HDR: Type = 1373;
+    pub const MODEL_ID_MJ042MR_GP_P6_BSI_XPL: Type = 1374;
+    pub const MODEL_ID_MX071CG_SY_X2G2: Type = 1378;
+    pub const MODEL_ID_MX071MG_SY_X2G2: Type = 1384;
+    pub const MODEL_ID_MX028CG_SY_X2G2: Type = 1407;
+    pub const MODEL_ID_MX028MG_SY_X2G2: Type = 1411;
+    pub const MODEL_ID_MX017CG_SY_X2G2: Type = 1415;
+    pub const MODEL_ID_MX017MG_SY_X2G2: Type = 1419;
+    pub const MODEL_ID_MX005CG_SY_X2G2: Type = 1423;
+    pub const MODEL_ID_MX005MG_SY_X2G2: Type = 1427;
+}
+pub mod XI_RET {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_OK: Type = 0;
+    pub const XI_INVALID_HANDLE: Type = 1;
+    pub const XI_READREG: Type = 2;
+    pub const XI_WRITEREG: Type = 3;
+    pub const XI_FREE_RESOURCES: Type = 4;
+    pub const XI_FREE_CHANNEL: Type = 5;
+    pub const XI_FREE_BANDWIDTH: Type = 6;
+    pub const XI_READBLK: Type = 7;
+    pub const XI_WRITEBLK: Type = 8;
+    pub const XI_NO_IMAGE: Type = 9;
+    pub const XI_TIMEOUT: Type = 10;
+    pub const XI_INVALID_ARG: Type = 11;
+    pub const XI_NOT_SUPPORTED: Type = 12;
+    pub const XI_ISOCH_ATTACH_BUFFERS: Type = 13;
+    pub const XI_GET_OVERLAPPED_RESULT: Type = 14;
+    pub const XI_MEMORY_ALLOCATION: Type = 15;
+    pub const XI_DLLCONTEXTISNULL: Type = 16;
+    pub const XI_DLLCONTEXTISNONZERO: Type = 17;
+    pub const XI_DLLCONTEXTEXIST: Type = 18;
+    pub const XI_TOOMANYDEVICES: Type = 19;
+    pub const XI_ERRORCAMCONTEXT: Type = 20;
+    pub const XI_UNKNOWN_HARDWARE: Type = 21;
+    pub const XI_INVALID_TM_FILE: Type = 22;
+    pub const XI_INVALID_TM_TAG: Type = 23;
+    pub const XI_INCOMPLETE_TM: Type = 24;
+    pub const XI_BUS_RESET_FAILED: Type = 25;
+    pub const XI_NOT_IMPLEMENTED: Type = 26;
+    pub const XI_SHADING_TOOBRIGHT: Type = 27;
+    pub const XI_SHADING_TOODARK: Type = 28;
+    pub const XI_TOO_LOW_GAIN: Type = 29;
+    pub const XI_INVALID_BPL: Type = 30;
+    pub const XI_BPL_REALLOC: Type = 31;
+    pub const XI_INVALID_PIXEL_LIST: Type = 32;
+    pub const XI_INVALID_FFS: Type = 33;
+    pub const XI_INVALID_PROFILE: Type = 34;
+    pub const XI_INVALID_CALIBRATION: Type = 35;
+    pub const XI_INVALID_BUFFER: Type = 36;
+    pub const XI_INVALID_DATA: Type = 38;
+    pub const XI_TGBUSY: Type = 39;
+    pub const XI_IO_WRONG: Type = 40;
+    pub const XI_ACQUISITION_ALREADY_UP: Type = 41;
+    pub const XI_OLD_DRIVER_VERSION: Type = 42;
+    pub const XI_GET_LAST_ERROR: Type = 43;
+    pub const XI_CANT_PROCESS: Type = 44;
+    pub const XI_ACQUISITION_STOPED: Type = 45;
+    pub const XI_ACQUISITION_STOPED_WERR: Type = 46;
+    pub const XI_INVALID_INPUT_ICC_PROFILE: Type = 47;
+    pub const XI_INVALID_OUTPUT_ICC_PROFILE: Type = 48;
+    pub const XI_DEVICE_NOT_READY: Type = 49;
+    pub const XI_SHADING_TOOCONTRAST: Type = 50;
+    pub const XI_ALREADY_INITIALIZED: Type = 51;
+    pub const XI_NOT_ENOUGH_PRIVILEGES: Type = 52;
+    pub const XI_NOT_COMPATIBLE_DRIVER: Type = 53;
+    pub const XI_TM_INVALID_RESOURCE: Type = 54;
+    pub const XI_DEVICE_HAS_BEEN_RESETED: Type = 55;
+    pub const XI_NO_DEVICES_FOUND: Type = 56;
+    pub const XI_RESOURCE_OR_FUNCTION_LOCKED: Type = 57;
+    pub const XI_BUFFER_SIZE_TOO_SMALL: Type = 58;
+    pub const XI_COULDNT_INIT_PROCESSOR: Type = 59;
+    pub const XI_NOT_INITIALIZED: Type = 60;
+    pub const XI_RESOURCE_NOT_FOUND: Type = 61;
+    pub const XI_UNKNOWN_PARAM: Type = 100;
+    pub const XI_WRONG_PARAM_VALUE: Type = 101;
+    pub const XI_WRONG_PARAM_TYPE: Type = 103;
+    pub const XI_WRONG_PARAM_SIZE: Type = 104;
+    pub const XI_BUFFER_TOO_SMALL: Type = 105;
+    pub const XI_NOT_SUPPORTED_PARAM: Type = 106;
+    pub const XI_NOT_SUPPORTED_PARAM_INFO: Type = 107;
+    pub const XI_NOT_SUPPORTED_DATA_FORMAT: Type = 108;
+    pub const XI_READ_ONLY_PARAM: Type = 109;
+    pub const XI_BANDWIDTH_NOT_SUPPORTED: Type = 111;
+    pub const XI_INVALID_FFS_FILE_NAME: Type = 112;
+    pub const XI_FFS_FILE_NOT_FOUND: Type = 113;
+    pub const XI_PARAM_NOT_SETTABLE: Type = 114;
+    pub const XI_SAFE_POLICY_NOT_SUPPORTED: Type = 115;
+    pub const XI_GPUDIRECT_NOT_AVAILABLE: Type = 116;
+    pub const XI_INCORRECT_SENS_ID_CHECK: Type = 117;
+    pub const XI_INCORRECT_FPGA_TYPE: Type = 118;
+    pub const XI_PARAM_CONDITIONALLY_NOT_AVAILABLE: Type = 119;
+    pub const XI_ERR_FRAME_BUFFER_RAM_INIT: Type = 120;
+    pub const XI_PROC_OTHER_ERROR: Type = 201;
+    pub const XI_PROC_PROCESSING_ERROR: Type = 202;
+    pub const XI_PROC_INPUT_FORMAT_UNSUPPORTED: Type = 203;
+    pub const XI_PROC_OUTPUT_FORMAT_UNSUPPORTED: Type = 204;
+    pub const XI_OUT_OF_RANGE: Type = 205;
+}
+pub mod XI_DOWNSAMPLING_VALUE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DWN_1x1: Type = 1;
+    pub const XI_DWN_2x2: Type = 2;
+    pub const XI_DWN_3x3: Type = 3;
+    pub const XI_DWN_4x4: Type = 4;
+    pub const XI_DWN_5x5: Type = 5;
+    pub const XI_DWN_6x6: Type = 6;
+    pub const XI_DWN_7x7: Type = 7;
+    pub const XI_DWN_8x8: Type = 8;
+    pub const XI_DWN_9x9: Type = 9;
+    pub const XI_DWN_10x10: Type = 10;
+    pub const XI_DWN_16x16: Type = 16;
+}
+pub mod XI_TEST_PATTERN_GENERATOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TESTPAT_GEN_SENSOR: Type = 0;
+    pub const XI_TESTPAT_GEN_FPGA: Type = 1;
+}
+pub mod XI_VERSION {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_VER_API: Type = 0;
+    pub const XI_VER_DRV: Type = 1;
+    pub const XI_VER_MCU1: Type = 2;
+    pub const XI_VER_MCU2: Type = 3;
+    pub const XI_VER_MCU3: Type = 4;
+    pub const XI_VER_FPGA1: Type = 5;
+    pub const XI_VER_XMLMAN: Type = 6;
+    pub const XI_VER_HW_REV: Type = 7;
+    pub const XI_VER_FACTORY_SET: Type = 8;
+}
+pub mod XI_TEST_PATTERN {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TESTPAT_OFF: Type = 0;
+    pub const XI_TESTPAT_BLACK: Type = 1;
+    pub const XI_TESTPAT_WHITE: Type = 2;
+    pub const XI_TESTPAT_GREY_HORIZ_RAMP: Type = 3;
+    pub const XI_TESTPAT_GREY_VERT_RAMP: Type = 4;
+    pub const XI_TESTPAT_GREY_HORIZ_RAMP_MOVING: Type = 5;
+    pub const XI_TESTPAT_GREY_VERT_RAMP_MOVING: Type = 6;
+    pub const XI_TESTPAT_HORIZ_LINE_MOVING: Type = 7;
+    pub const XI_TESTPAT_VERT_LINE_MOVING: Type = 8;
+    pub const XI_TESTPAT_COLOR_BAR: Type = 9;
+    pub const XI_TESTPAT_FRAME_COUNTER: Type = 10;
+    pub const XI_TESTPAT_DEVICE_SPEC_COUNTER: Type = 11;
+}
+pub mod XI_DEC_PATTERN {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DEC_MONO: Type = 1;
+    pub const XI_DEC_BAYER: Type = 2;
+}
+pub mod XI_BIN_PATTERN {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_BIN_MONO: Type = 1;
+    pub const XI_BIN_BAYER: Type = 2;
+}
+pub mod XI_BIN_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_BIN_SELECT_SENSOR: Type = 0;
+    pub const XI_BIN_SELECT_DEVICE_FPGA: Type = 1;
+    pub const XI_BIN_SELECT_HOST_CPU: Type = 2;
+}
+pub mod XI_BIN_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_BIN_MODE_SUM: Type = 0;
+    pub const XI_BIN_MODE_AVERAGE: Type = 1;
+}
+pub mod XI_DEC_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DEC_SELECT_SENSOR: Type = 0;
+    pub const XI_DEC_SELECT_DEVICE_FPGA: Type = 1;
+    pub const XI_DEC_SELECT_HOST_CPU: Type = 2;
+}
+pub mod XI_SENSOR_TAP_CNT {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TAP_CNT_1: Type = 1;
+    pub const XI_TAP_CNT_2: Type = 2;
+    pub const XI_TAP_CNT_4: Type = 4;
+}
+pub mod XI_BIT_DEPTH {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_BPP_8: Type = 8;
+    pub const XI_BPP_9: Type = 9;
+    pub const XI_BPP_10: Type = 10;
+    pub const XI_BPP_11: Type = 11;
+    pub const XI_BPP_12: Type = 12;
+    pub const XI_BPP_14: Type = 14;
+    pub const XI_BPP_16: Type = 16;
+    pub const XI_BPP_24: Type = 24;
+    pub const XI_BPP_32: Type = 32;
+}
+pub mod XI_DEBUG_LEVEL {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DL_DETAIL: Type = 0;
+    pub const XI_DL_TRACE: Type = 1;
+    pub const XI_DL_WARNING: Type = 2;
+    pub const XI_DL_ERROR: Type = 3;
+    pub const XI_DL_FATAL: Type = 4;
+    pub const XI_DL_DISABLED: Type = 100;
+}
+pub mod XI_IMG_FORMAT {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_MONO8: Type = 0;
+    pub const XI_MONO16: Type = 1;
+    pub const XI_RGB24: Type = 2;
+    pub const XI_RGB32: Type = 3;
+    pub const XI_RGB_PLANAR: Type = 4;
+    pub const XI_RAW8: Type = 5;
+    pub const XI_RAW16: Type = 6;
+    pub const XI_FRM_TRANSPORT_DATA: Type = 7;
+    pub const XI_RGB48: Type = 8;
+    pub const XI_RGB64: Type = 9;
+    pub const XI_RGB16_PLANAR: Type = 10;
+    pub const XI_RAW8X2: Type = 11;
+    pub const XI_RAW8X4: Type = 12;
+    pub const XI_RAW16X2: Type = 13;
+    pub const XI_RAW16X4: Type = 14;
+    pub const XI_RAW32: Type = 15;
+    pub const XI_RAW32FLOAT: Type = 16;
+}
+pub mod XI_COLOR_FILTER_ARRAY {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_CFA_NONE: Type = 0;
+    pub const XI_CFA_BAYER_RGGB: Type = 1;
+    pub const XI_CFA_CMYG: Type = 2;
+    pub const XI_CFA_RGR: Type = 3;
+    pub const XI_CFA_BAYER_BGGR: Type = 4;
+    pub const XI_CFA_BAYER_GRBG: Type = 5;
+    pub const XI_CFA_BAYER_GBRG: Type = 6;
+    pub const XI_CFA_POLAR_A_BAYER_BGGR: Type = 7;
+    pub const XI_CFA_POLAR_A: Type = 8;
+}
+pub mod XI_BP {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_BP_UNSAFE: Type = 0;
+    pub const XI_BP_SAFE: Type = 1;
+}
+pub mod XI_TRG_SOURCE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TRG_OFF: Type = 0;
+    pub const XI_TRG_EDGE_RISING: Type = 1;
+    pub const XI_TRG_EDGE_FALLING: Type = 2;
+    pub const XI_TRG_SOFTWARE: Type = 3;
+    pub const XI_TRG_LEVEL_HIGH: Type = 4;
+    pub const XI_TRG_LEVEL_LOW: Type = 5;
+}
+pub mod XI_TRG_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TRG_SEL_FRAME_START: Type = 0;
+    pub const XI_TRG_SEL_EXPOSURE_ACTIVE: Type = 1;
+    pub const XI_TRG_SEL_FRAME_BURST_START: Type = 2;
+    pub const XI_TRG_SEL_FRAME_BURST_ACTIVE: Type = 3;
+    pub const XI_TRG_SEL_MULTIPLE_EXPOSURES: Type = 4;
+    pub const XI_TRG_SEL_EXPOSURE_START: Type = 5;
+    pub const XI_TRG_SEL_MULTI_SLOPE_PHASE_CHANGE: Type = 6;
+    pub const XI_TRG_SEL_ACQUISITION_START: Type = 7;
+}
+pub mod XI_TRG_OVERLAP {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TRG_OVERLAP_OFF: Type = 0;
+    pub const XI_TRG_OVERLAP_READ_OUT: Type = 1;
+    pub const XI_TRG_OVERLAP_PREV_FRAME: Type = 2;
+}
+pub mod XI_ACQ_TIMING_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_ACQ_TIMING_MODE_FREE_RUN: Type = 0;
+    pub const XI_ACQ_TIMING_MODE_FRAME_RATE: Type = 1;
+    pub const XI_ACQ_TIMING_MODE_FRAME_RATE_LIMIT: Type = 2;
+}
+pub mod XI_TRANSPORT_DATA_TARGET_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TRANSPORT_DATA_TARGET_CPU_RAM: Type = 0;
+    pub const XI_TRANSPORT_DATA_TARGET_GPU_RAM: Type = 1;
+    pub const XI_TRANSPORT_DATA_TARGET_UNIFIED: Type = 2;
+    pub const XI_TRANSPORT_DATA_TARGET_ZEROCOPY: Type = 3;
+}
+pub mod XI_GPI_SEL_CB {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_GPI_SEL_CB_IN1: Type = 1;
+    pub const XI_GPI_SEL_CB_IN2: Type = 2;
+    pub const XI_GPI_SEL_CB_INOUT1: Type = 3;
+    pub const XI_GPI_SEL_CB_INOUT2: Type = 4;
+    pub const XI_GPI_SEL_CB_INOUT3: Type = 5;
+    pub const XI_GPI_SEL_CB_INOUT4: Type = 6;
+}
+pub mod XI_GPO_SEL_CB {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_GPO_SEL_CB_OUT1: Type = 1;
+    pub const XI_GPO_SEL_CB_OUT2: Type = 2;
+    pub const XI_GPO_SEL_CB_INOUT1: Type = 3;
+    pub const XI_GPO_SEL_CB_INOUT2: Type = 4;
+    pub const XI_GPO_SEL_CB_INOUT3: Type = 5;
+    pub const XI_GPO_SEL_CB_INOUT4: Type = 6;
+}
+pub mod XI_GPI_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_GPI_OFF: Type = 0;
+    pub const XI_GPI_TRIGGER: Type = 1;
+    pub const XI_GPI_EXT_EVENT: Type = 2;
+}
+pub mod XI_GPI_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_GPI_PORT1: Type = 1;
+    pub const XI_GPI_PORT2: Type = 2;
+    pub const XI_GPI_PORT3: Type = 3;
+    pub const XI_GPI_PORT4: Type = 4;
+    pub const XI_GPI_PORT5: Type = 5;
+    pub const XI_GPI_PORT6: Type = 6;
+    pub const XI_GPI_PORT7: Type = 7;
+    pub const XI_GPI_PORT8: Type = 8;
+    pub const XI_GPI_PORT9: Type = 9;
+    pub const XI_GPI_PORT10: Type = 10;
+    pub const XI_GPI_PORT11: Type = 11;
+    pub const XI_GPI_PORT12: Type = 12;
+}
+pub mod XI_GPO_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_GPO_OFF: Type = 0;
+    pub const XI_GPO_ON: Type = 1;
+    pub const XI_GPO_FRAME_ACTIVE: Type = 2;
+    pub const XI_GPO_FRAME_ACTIVE_NEG: Type = 3;
+    pub const XI_GPO_EXPOSURE_ACTIVE: Type = 4;
+    pub const XI_GPO_EXPOSURE_ACTIVE_NEG: Type = 5;
+    pub const XI_GPO_FRAME_TRIGGER_WAIT: Type = 6;
+    pub const XI_GPO_FRAME_TRIGGER_WAIT_NEG: Type = 7;
+    pub const XI_GPO_EXPOSURE_PULSE: Type = 8;
+    pub const XI_GPO_EXPOSURE_PULSE_NEG: Type = 9;
+    pub const XI_GPO_BUSY: Type = 10;
+    pub const XI_GPO_BUSY_NEG: Type = 11;
+    pub const XI_GPO_HIGH_IMPEDANCE: Type = 12;
+    pub const XI_GPO_FRAME_BUFFER_OVERFLOW: Type = 13;
+    pub const XI_GPO_EXPOSURE_ACTIVE_FIRST_ROW: Type = 14;
+    pub const XI_GPO_EXPOSURE_ACTIVE_FIRST_ROW_NEG: Type = 15;
+    pub const XI_GPO_EXPOSURE_ACTIVE_ALL_ROWS: Type = 16;
+    pub const XI_GPO_EXPOSURE_ACTIVE_ALL_ROWS_NEG: Type = 17;
+    pub const XI_GPO_TXD: Type = 18;
+}
+pub mod XI_GPO_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_GPO_PORT1: Type = 1;
+    pub const XI_GPO_PORT2: Type = 2;
+    pub const XI_GPO_PORT3: Type = 3;
+    pub const XI_GPO_PORT4: Type = 4;
+    pub const XI_GPO_PORT5: Type = 5;
+    pub const XI_GPO_PORT6: Type = 6;
+    pub const XI_GPO_PORT7: Type = 7;
+    pub const XI_GPO_PORT8: Type = 8;
+    pub const XI_GPO_PORT9: Type = 9;
+    pub const XI_GPO_PORT10: Type = 10;
+    pub const XI_GPO_PORT11: Type = 11;
+    pub const XI_GPO_PORT12: Type = 12;
+}
+pub mod XI_LED_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_LED_HEARTBEAT: Type = 0;
+    pub const XI_LED_TRIGGER_ACTIVE: Type = 1;
+    pub const XI_LED_EXT_EVENT_ACTIVE: Type = 2;
+    pub const XI_LED_LINK: Type = 3;
+    pub const XI_LED_ACQUISITION: Type = 4;
+    pub const XI_LED_EXPOSURE_ACTIVE: Type = 5;
+    pub const XI_LED_FRAME_ACTIVE: Type = 6;
+    pub const XI_LED_OFF: Type = 7;
+    pub const XI_LED_ON: Type = 8;
+    pub const XI_LED_BLINK: Type = 9;
+}
+pub mod XI_LED_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_LED_SEL1: Type = 1;
+    pub const XI_LED_SEL2: Type = 2;
+    pub const XI_LED_SEL3: Type = 3;
+    pub const XI_LED_SEL4: Type = 4;
+    pub const XI_LED_SEL5: Type = 5;
+}
+pub mod XI_COUNTER_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_CNT_SEL_TRANSPORT_SKIPPED_FRAMES: Type = 0;
+    pub const XI_CNT_SEL_API_SKIPPED_FRAMES: Type = 1;
+    pub const XI_CNT_SEL_TRANSPORT_TRANSFERRED_FRAMES: Type = 2;
+    pub const XI_CNT_SEL_FRAME_MISSED_TRIGGER_DUETO_OVERLAP: Type = 3;
+    pub const XI_CNT_SEL_FRAME_MISSED_TRIGGER_DUETO_FRAME_BUFFER_OVR: Type = 4;
+    pub const XI_CNT_SEL_FRAME_BUFFER_OVERFLOW: Type = 5;
+}
+pub mod XI_TS_RST_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TS_RST_ARM_ONCE: Type = 0;
+    pub const XI_TS_RST_ARM_PERSIST: Type = 1;
+}
+pub mod XI_TS_RST_SOURCE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TS_RST_OFF: Type = 0;
+    pub const XI_TS_RST_SRC_GPI_1: Type = 1;
+    pub const XI_TS_RST_SRC_GPI_2: Type = 2;
+    pub const XI_TS_RST_SRC_GPI_3: Type = 3;
+    pub const XI_TS_RST_SRC_GPI_4: Type = 4;
+    pub const XI_TS_RST_SRC_GPI_1_INV: Type = 5;
+    pub const XI_TS_RST_SRC_GPI_2_INV: Type = 6;
+    pub const XI_TS_RST_SRC_GPI_3_INV: Type = 7;
+    pub const XI_TS_RST_SRC_GPI_4_INV: Type = 8;
+    pub const XI_TS_RST_SRC_GPO_1: Type = 9;
+    pub const XI_TS_RST_SRC_GPO_2: Type = 10;
+    pub const XI_TS_RST_SRC_GPO_3: Type = 11;
+    pub const XI_TS_RST_SRC_GPO_4: Type = 12;
+    pub const XI_TS_RST_SRC_GPO_1_INV: Type = 13;
+    pub const XI_TS_RST_SRC_GPO_2_INV: Type = 14;
+    pub const XI_TS_RST_SRC_GPO_3_INV: Type = 15;
+    pub const XI_TS_RST_SRC_GPO_4_INV: Type = 16;
+    pub const XI_TS_RST_SRC_TRIGGER: Type = 17;
+    pub const XI_TS_RST_SRC_TRIGGER_INV: Type = 18;
+    pub const XI_TS_RST_SRC_SW: Type = 19;
+    pub const XI_TS_RST_SRC_EXPACTIVE: Type = 20;
+    pub const XI_TS_RST_SRC_EXPACTIVE_INV: Type = 21;
+    pub const XI_TS_RST_SRC_FVAL: Type = 22;
+    pub const XI_TS_RST_SRC_FVAL_INV: Type = 23;
+    pub const XI_TS_RST_SRC_GPI_5: Type = 24;
+    pub const XI_TS_RST_SRC_GPI_6: Type = 25;
+    pub const XI_TS_RST_SRC_GPI_5_INV: Type = 26;
+    pub const XI_TS_RST_SRC_GPI_6_INV: Type = 27;
+    pub const XI_TS_RST_SRC_GPI_7: Type = 28;
+    pub const XI_TS_RST_SRC_GPI_8: Type = 29;
+    pub const XI_TS_RST_SRC_GPI_9: Type = 30;
+    pub const XI_TS_RST_SRC_GPI_10: Type = 31;
+    pub const XI_TS_RST_SRC_GPI_11: Type = 32;
+    pub const XI_TS_RST_SRC_GPI_7_INV: Type = 33;
+    pub const XI_TS_RST_SRC_GPI_8_INV: Type = 34;
+    pub const XI_TS_RST_SRC_GPI_9_INV: Type = 35;
+    pub const XI_TS_RST_SRC_GPI_10_INV: Type = 36;
+    pub const XI_TS_RST_SRC_GPI_11_INV: Type = 37;
+}
+pub mod XI_PRM_TYPE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const xiTypeInteger: Type = 0;
+    pub const xiTypeFloat: Type = 1;
+    pub const xiTypeString: Type = 2;
+    pub const xiTypeEnum: Type = 3;
+    pub const xiTypeBoolean: Type = 4;
+    pub const xiTypeCommand: Type = 5;
+    pub const xiTypeInteger64: Type = 6;
+}
+pub mod XI_SWITCH {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_OFF: Type = 0;
+    pub const XI_ON: Type = 1;
+}
+pub mod XI_TEMP_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TEMP_IMAGE_SENSOR_DIE_RAW: Type = 0;
+    pub const XI_TEMP_IMAGE_SENSOR_DIE: Type = 1;
+    pub const XI_TEMP_SENSOR_BOARD: Type = 2;
+    pub const XI_TEMP_INTERFACE_BOARD: Type = 3;
+    pub const XI_TEMP_FRONT_HOUSING: Type = 4;
+    pub const XI_TEMP_REAR_HOUSING: Type = 5;
+    pub const XI_TEMP_TEC1_COLD: Type = 6;
+    pub const XI_TEMP_TEC1_HOT: Type = 7;
+}
+pub mod XI_TEMP_CTRL_MODE_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TEMP_CTRL_MODE_OFF: Type = 0;
+    pub const XI_TEMP_CTRL_MODE_AUTO: Type = 1;
+    pub const XI_TEMP_CTRL_MODE_MANUAL: Type = 2;
+}
+pub mod XI_TEMP_ELEMENT_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_TEMP_ELEM_TEC1: Type = 11;
+    pub const XI_TEMP_ELEM_TEC2: Type = 12;
+    pub const XI_TEMP_ELEM_FAN1: Type = 31;
+    pub const XI_TEMP_ELEM_FAN1_THRS_TEMP: Type = 32;
+}
+pub mod XI_OUTPUT_DATA_PACKING_TYPE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DATA_PACK_XI_GROUPING: Type = 0;
+    pub const XI_DATA_PACK_PFNC_LSB_PACKING: Type = 1;
+}
+pub mod XI_DOWNSAMPLING_TYPE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_BINNING: Type = 0;
+    pub const XI_SKIPPING: Type = 1;
+}
+pub mod XI_EXPOSURE_TIME_SELECTOR_TYPE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_EXPOSURE_TIME_SELECTOR_COMMON: Type = 0;
+    pub const XI_EXPOSURE_TIME_SELECTOR_GROUP1: Type = 1;
+    pub const XI_EXPOSURE_TIME_SELECTOR_GROUP2: Type = 2;
+}
+pub mod XI_INTERLINE_EXPOSURE_MODE_TYPE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_INTERLINE_EXPOSURE_MODE_OFF: Type = 0;
+    pub const XI_INTERLINE_EXPOSURE_MODE_ON: Type = 1;
+}
+pub mod XI_GAIN_SELECTOR_TYPE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_GAIN_SELECTOR_ALL: Type = 0;
+    pub const XI_GAIN_SELECTOR_ANALOG_ALL: Type = 1;
+    pub const XI_GAIN_SELECTOR_DIGITAL_ALL: Type = 2;
+    pub const XI_GAIN_SELECTOR_ANALOG_TAP1: Type = 3;
+    pub const XI_GAIN_SELECTOR_ANALOG_TAP2: Type = 4;
+    pub const XI_GAIN_SELECTOR_ANALOG_TAP3: Type = 5;
+    pub const XI_GAIN_SELECTOR_ANALOG_TAP4: Type = 6;
+    pub const XI_GAIN_SELECTOR_ANALOG_N: Type = 7;
+    pub const XI_GAIN_SELECTOR_ANALOG_S: Type = 8;
+}
+pub mod XI_SHUTTER_TYPE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_SHUTTER_GLOBAL: Type = 0;
+    pub const XI_SHUTTER_ROLLING: Type = 1;
+    pub const XI_SHUTTER_GLOBAL_RESET_RELEASE: Type = 2;
+}
+pub mod XI_CMS_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_CMS_DIS: Type = 0;
+    pub const XI_CMS_EN: Type = 1;
+    pub const XI_CMS_EN_FAST: Type = 2;
+}
+pub mod XI_CMS_INTENT {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_CMS_INTENT_PERCEPTUAL: Type = 0;
+    pub const XI_CMS_INTENT_RELATIVE_COLORIMETRIC: Type = 1;
+    pub const XI_CMS_INTENT_SATURATION: Type = 2;
+    pub const XI_CMS_INTENT_ABSOLUTE_COLORIMETRIC: Type = 3;
+}
+pub mod XI_OPEN_BY {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_OPEN_BY_INST_PATH: Type = 0;
+    pub const XI_OPEN_BY_SN: Type = 1;
+    pub const XI_OPEN_BY_USER_ID: Type = 2;
+    pub const XI_OPEN_BY_LOC_PATH: Type = 3;
+}
+pub mod XI_LENS_FEATURE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_LENS_FEATURE_MOTORIZED_FOCUS_SWITCH: Type = 1;
+    pub const XI_LENS_FEATURE_MOTORIZED_FOCUS_BOUNDED: Type = 2;
+    pub const XI_LENS_FEATURE_MOTORIZED_FOCUS_CALIBRATION: Type = 3;
+    pub const XI_LENS_FEATURE_IMAGE_STABILIZATION_ENABLED: Type = 4;
+    pub const XI_LENS_FEATURE_IMAGE_STABILIZATION_SWITCH_STATUS: Type = 5;
+    pub const XI_LENS_FEATURE_IMAGE_ZOOM_SUPPORTED: Type = 6;
+}
+pub mod XI_SENSOR_FEATURE_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_SENSOR_FEATURE_ZEROROT_ENABLE: Type = 0;
+    pub const XI_SENSOR_FEATURE_BLACK_LEVEL_CLAMP: Type = 1;
+    pub const XI_SENSOR_FEATURE_MD_FPGA_DIGITAL_GAIN_DISABLE: Type = 2;
+    pub const XI_SENSOR_FEATURE_ACQUISITION_RUNNING: Type = 3;
+    pub const XI_SENSOR_FEATURE_TIMING_MODE: Type = 4;
+    pub const XI_SENSOR_FEATURE_PARALLEL_ADC: Type = 5;
+    pub const XI_SENSOR_FEATURE_BLACK_LEVEL_OFFSET_RAW: Type = 6;
+    pub const XI_SENSOR_FEATURE_SHORT_INTERVAL_SHUTTER: Type = 7;
+    pub const XI_SENSOR_FEATURE_AUTO_LOW_POWER_MODE_AUTO: Type = 8;
+    pub const XI_SENSOR_FEATURE_HIGH_CONVERSION_GAIN: Type = 9;
+}
+pub mod XI_SENSOR_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_SENS_MD0: Type = 0;
+    pub const XI_SENS_MD1: Type = 1;
+    pub const XI_SENS_MD2: Type = 2;
+    pub const XI_SENS_MD3: Type = 3;
+    pub const XI_SENS_MD4: Type = 4;
+    pub const XI_SENS_MD5: Type = 5;
+    pub const XI_SENS_MD6: Type = 6;
+    pub const XI_SENS_MD7: Type = 7;
+    pub const XI_SENS_MD8: Type = 8;
+    pub const XI_SENS_MD9: Type = 9;
+    pub const XI_SENS_MD10: Type = 10;
+    pub const XI_SENS_MD11: Type = 11;
+    pub const XI_SENS_MD12: Type = 12;
+    pub const XI_SENS_MD13: Type = 13;
+    pub const XI_SENS_MD14: Type = 14;
+    pub const XI_SENS_MD15: Type = 15;
+}
+pub mod XI_IMAGE_AREA_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_IMAGE_AREA_ACTIVE: Type = 0;
+    pub const XI_IMAGE_AREA_ACTIVE_AND_MASKED: Type = 1;
+}
+pub mod XI_SENSOR_OUTPUT_CHANNEL_COUNT {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_CHANN_CNT2: Type = 2;
+    pub const XI_CHANN_CNT4: Type = 4;
+    pub const XI_CHANN_CNT8: Type = 8;
+    pub const XI_CHANN_CNT16: Type = 16;
+    pub const XI_CHANN_CNT24: Type = 24;
+    pub const XI_CHANN_CNT32: Type = 32;
+    pub const XI_CHANN_CNT48: Type = 48;
+}
+pub mod XI_SENS_DEFFECTS_CORR_LIST_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_SENS_DEFFECTS_CORR_LIST_SEL_FACTORY: Type = 0;
+    pub const XI_SENS_DEFFECTS_CORR_LIST_SEL_USER0: Type = 1;
+    pub const XI_SENS_DEFFECTS_CORR_LIST_SEL_IN_CAMERA: Type = 2;
+}
+pub mod XI_ACQUISITION_STATUS_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_ACQUISITION_STATUS_ACQ_ACTIVE: Type = 0;
+}
+pub mod XI_DP_UNIT_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DP_UNIT_SENSOR: Type = 0;
+    pub const XI_DP_UNIT_FPGA: Type = 1;
+}
+pub mod XI_DP_PROC_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DP_PROC_NONE: Type = 0;
+    pub const XI_DP_PROC_CHANNEL_MUXER: Type = 1;
+    pub const XI_DP_PROC_PIXEL_SEQUENCER: Type = 2;
+    pub const XI_DP_PROC_CHANNEL_1: Type = 3;
+    pub const XI_DP_PROC_CHANNEL_2: Type = 4;
+    pub const XI_DP_PROC_FRAME_BUFFER: Type = 5;
+}
+pub mod XI_DP_PARAM_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DP_PARAM_NONE: Type = 0;
+    pub const XI_DP_PARAM_CHMUX_CHANNEL_SELECTOR: Type = 1;
+    pub const XI_DP_PARAM_CHMUX_ALPHA: Type = 2;
+    pub const XI_DP_PARAM_CHMUX_BETA: Type = 3;
+    pub const XI_DP_PARAM_PIXSEQ_SELECTOR: Type = 4;
+    pub const XI_DP_PARAM_CHANNEL_TIMING: Type = 5;
+    pub const XI_DP_PARAM_FRAMEBUF_MODE: Type = 6;
+    pub const XI_DP_PARAM_FRAMEBUF_SIZE: Type = 7;
+}
+pub mod XI_DP_PARAM_VALUE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DP_PARAM_VALUE_CHMUX_CHANNEL_1: Type = 0;
+    pub const XI_DP_PARAM_VALUE_CHMUX_CHANNEL_2: Type = 1;
+    pub const XI_DP_PARAM_VALUE_CHMUX_CHANNEL_1_2: Type = 2;
+    pub const XI_DP_PARAM_VALUE_CHMUX_MERGED: Type = 3;
+    pub const XI_DP_PARAM_VALUE_CHMUX_CMS_S: Type = 4;
+    pub const XI_DP_PARAM_VALUE_PIXSEQ_ONE_VALUE: Type = 5;
+    pub const XI_DP_PARAM_VALUE_PIXSEQ_TWO_VALUES: Type = 6;
+    pub const XI_DP_PARAM_VALUE_CHTIM_HG: Type = 7;
+    pub const XI_DP_PARAM_VALUE_CHTIM_LG: Type = 8;
+    pub const XI_DP_PARAM_VALUE_FRAMEBUF_MODE_DISABLED: Type = 9;
+    pub const XI_DP_PARAM_VALUE_FRAMEBUF_MODE_ENABLED: Type = 10;
+    pub const XI_DP_PARAM_VALUE_PIXSEQ_FOUR_VALUES: Type = 11;
+    pub const XI_DP_PARAM_VALUE_CHMUX_CMS_A: Type = 12;
+}
+pub mod XI_USER_SET_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_US_12_STD_L: Type = 10;
+    pub const XI_US_12_STD_H: Type = 11;
+    pub const XI_US_14_STD_L: Type = 12;
+    pub const XI_US_NONE: Type = 999;
+    pub const XI_US_14_STD_H: Type = 13;
+    pub const XI_US_2_12_CMS_S_L: Type = 14;
+    pub const XI_US_2_12_CMS_S_H: Type = 15;
+    pub const XI_US_2_14_CMS_S_L: Type = 16;
+    pub const XI_US_2_14_CMS_S_H: Type = 17;
+    pub const XI_US_4_12_CMS_S_L: Type = 18;
+    pub const XI_US_4_12_CMS_S_H: Type = 19;
+    pub const XI_US_4_14_CMS_S_L: Type = 20;
+    pub const XI_US_4_14_CMS_S_H: Type = 21;
+    pub const XI_US_2_12_HDR_HL: Type = 22;
+    pub const XI_US_2_12_HDR_L: Type = 23;
+    pub const XI_US_2_12_HDR_H: Type = 24;
+    pub const XI_US_4_12_CMS_HDR_HL: Type = 25;
+    pub const XI_US_2_14_HDR_L: Type = 26;
+    pub const XI_US_2_14_HDR_H: Type = 27;
+    pub const XI_US_2_12_CMS_A_L: Type = 28;
+    pub const XI_US_2_12_CMS_A_H: Type = 29;
+}
+pub mod XI_DUAL_ADC_MODE {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_DUAL_ADC_MODE_OFF: Type = 0;
+    pub const XI_DUAL_ADC_MODE_COMBINED: Type = 1;
+    pub const XI_DUAL_ADC_MODE_NON_COMBINED: Type = 2;
+}
+pub mod XI_PROBE_SELECTOR {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_PROBE_SELECTOR_CURRENT_MAINBOARD_VCC_IN: Type = 0;
+    pub const XI_PROBE_SELECTOR_VOLTAGE_MAINBOARD_VCC_IN: Type = 1;
+    pub const XI_PROBE_SELECTOR_CURRENT_MAINBOARD_VCC_ADJ2: Type = 2;
+    pub const XI_PROBE_SELECTOR_VOLTAGE_MAINBOARD_VCC_ADJ2: Type = 3;
+    pub const XI_PROBE_SELECTOR_CURRENT_MAINBOARD_VCC_ADJ1: Type = 4;
+    pub const XI_PROBE_SELECTOR_VOLTAGE_MAINBOARD_VCC_ADJ1: Type = 5;
+    pub const XI_PROBE_SELECTOR_CURRENT_MAINBOARD_VCC_PLT: Type = 6;
+    pub const XI_PROBE_SELECTOR_VOLTAGE_MAINBOARD_VCC_PLT: Type = 7;
+    pub const XI_PROBE_SELECTOR_VOLTAGE_SENSORBOARD_VCC_ADJ1: Type = 8;
+    pub const XI_PROBE_SELECTOR_VOLTAGE_SENSORBOARD_VCC_ADJ2: Type = 9;
+    pub const XI_PROBE_SELECTOR_VOLTAGE_SENSORBOARD_VCC_5V0: Type = 10;
+    pub const XI_PROBE_SELECTOR_VOLTAGE_SENSORBOARD_VCC_3V3: Type = 11;
+}
+pub mod XI_GenTL_Image_Format_e {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const XI_GenTL_Image_Format_Mono8: Type = 17301505;
+    pub const XI_GenTL_Image_Format_BGRA8: Type = 35651607;
+    pub const XI_GenTL_Image_Format_RGB8Planar: Type = 35127329;
+    pub const XI_GenTL_Image_Format_BayerRG8: Type = 17301513;
+    pub const XI_GenTL_Image_Format_Mono10: Type = 17825795;
+    pub const XI_GenTL_Image_Format_Mono12: Type = 17825797;
+    pub const XI_GenTL_Image_Format_Mono14: Type = 17825829;
+    pub const XI_GenTL_Image_Format_BayerRG10: Type = 17825805;
+    pub const XI_GenTL_Image_Format_BayerRG12: Type = 17825809;
+    pub const XI_GenTL_Image_Format_BayerGR8: Type = 17301512;
+    pub const XI_GenTL_Image_Format_BayerGB8: Type = 17301514;
+    pub const XI_GenTL_Image_Format_BayerGR10: Type = 17825804;
+    pub const XI_GenTL_Image_Format_BayerGB10: Type = 17825806;
+    pub const XI_GenTL_Image_Format_BayerGR12: Type = 17825808;
+    pub const XI_GenTL_Image_Format_BayerBG8: Type = 17301515;
+    pub const XI_GenTL_Image_Format_BayerBG10: Type = 17825807;
+    pub const XI_GenTL_Image_Format_BayerBG12: Type = 17825811;
+    pub const XI_GenTL_Image_Format_BayerGB12: Type = 17825810;
+    pub const XI_GenTL_Image_Format_RGB8: Type = 35127316;
+    pub const XI_GenTL_Image_Format_BGR8: Type = 35127317;
+    pub const XI_GenTL_Image_Format_BayerRG14: Type = 17826058;
+    pub const XI_GenTL_Image_Format_BayerGR14: Type = 17826057;
+    pub const XI_GenTL_Image_Format_BayerBG14: Type = 17826060;
+    pub const XI_GenTL_Image_Format_BayerGB14: Type = 17826059;
+    pub const XI_GenTL_Image_Format_BayerBG10p: Type = 17432658;
+    pub const XI_GenTL_Image_Format_BayerGB10p: Type = 17432660;
+    pub const XI_GenTL_Image_Format_BayerGR10p: Type = 17432662;
+    pub const XI_GenTL_Image_Format_BayerRG10p: Type = 17432664;
+    pub const XI_GenTL_Image_Format_Mono10p: Type = 17432646;
+    pub const XI_GenTL_Image_Format_BayerBG12p: Type = 17563731;
+    pub const XI_GenTL_Image_Format_BayerGB12p: Type = 17563733;
+    pub const XI_GenTL_Image_Format_BayerGR12p: Type = 17563735;
+    pub const XI_GenTL_Image_Format_BayerRG12p: Type = 17563737;
+    pub const XI_GenTL_Image_Format_Mono12p: Type = 17563719;
+    pub const XI_GenTL_Image_Format_BayerBG14p: Type = 17694984;
+    pub const XI_GenTL_Image_Format_BayerGB14p: Type = 17694983;
+    pub const XI_GenTL_Image_Format_BayerGR14p: Type = 17694981;
+    pub const XI_GenTL_Image_Format_BayerRG14p: Type = 17694982;
+    pub const XI_GenTL_Image_Format_Mono14p: Type = 17694980;
+    pub const XI_GenTL_Image_Format_xiBayerBG10g160: Type = 2181038346;
+    pub const XI_GenTL_Image_Format_xiBayerGB10g160: Type = 2181038602;
+    pub const XI_GenTL_Image_Format_xiBayerGR10g160: Type = 2181038858;
+    pub const XI_GenTL_Image_Format_xiBayerRG10g160: Type = 2181039114;
+    pub const XI_GenTL_Image_Format_xiMono10g160: Type = 2181038090;
+    pub const XI_GenTL_Image_Format_xiBayerBG12g192: Type = 2181038348;
+    pub const XI_GenTL_Image_Format_xiBayerGB12g192: Type = 2181038604;
+    pub const XI_GenTL_Image_Format_xiBayerGR12g192: Type = 2181038860;
+    pub const XI_GenTL_Image_Format_xiBayerRG12g192: Type = 2181039116;
+    pub const XI_GenTL_Image_Format_xiMono12g192: Type = 2181038092;
+    pub const XI_GenTL_Image_Format_xiBayerBG14g224: Type = 2181038350;
+    pub const XI_GenTL_Image_Format_xiBayerGB14g224: Type = 2181038606;
+    pub const XI_GenTL_Image_Format_xiBayerGR14g224: Type = 2181038862;
+    pub const XI_GenTL_Image_Format_xiBayerRG14g224: Type = 2181039118;
+    pub const XI_GenTL_Image_Format_xiMono14g224: Type = 2181038094;
+    pub const XI_GenTL_Image_Format_xiMono8TS01: Type = 2147549192;
+    pub const XI_GenTL_Image_Format_xiMono10TS01: Type = 2147549194;
+    pub const XI_GenTL_Image_Format_xiMono12TS01: Type = 2147549196;
+    pub const XI_GenTL_Image_Format_xiMono14TS01: Type = 2147549198;
+    pub const XI_GenTL_Image_Format_xiBayerRG8TS01: Type = 2147550216;
+    pub const XI_GenTL_Image_Format_xiBayerRG10TS01: Type = 2147550218;
+    pub const XI_GenTL_Image_Format_xiBayerRG12TS01: Type = 2147550220;
+    pub const XI_GenTL_Image_Format_xiBayerRG14TS01: Type = 2147550222;
+    pub const XI_GenTL_Image_Format_xiBayerBG8TS01: Type = 2147549448;
+    pub const XI_GenTL_Image_Format_xiBayerBG10TS01: Type = 2147549450;
+    pub const XI_GenTL_Image_Format_xiBayerBG12TS01: Type = 2147549452;
+    pub const XI_GenTL_Image_Format_xiBayerBG14TS01: Type = 2147549454;
+    pub const XI_GenTL_Image_Format_xiBayerGB8TS01: Type = 2147549704;
+    pub const XI_GenTL_Image_Format_xiBayerGB10TS01: Type = 2147549706;
+    pub const XI_GenTL_Image_Format_xiBayerGB12TS01: Type = 2147549708;
+    pub const XI_GenTL_Image_Format_xiBayerGB14TS01: Type = 2147549710;
+    pub const XI_GenTL_Image_Format_xiBayerGR8TS01: Type = 2147549960;
+    pub const XI_GenTL_Image_Format_xiBayerGR10TS01: Type = 2147549962;
+    pub const XI_GenTL_Image_Format_xiBayerGR12TS01: Type = 2147549964;
+    pub const XI_GenTL_Image_Format_xiBayerGR14TS01: Type = 2147549966;
+    pub const XI_GenTL_Image_Format_xiMono8TS03: Type = 2147680264;
+    pub const XI_GenTL_Image_Format_xiMono10TS03: Type = 2147680266;
+    pub const XI_GenTL_Image_Format_xiMono12TS03: Type = 2147680268;
+    pub const XI_GenTL_Image_Format_xiMono14TS03: Type = 2147680270;
+    pub const XI_GenTL_Image_Format_xiBayerRG8TS03: Type = 2147681288;
+    pub const XI_GenTL_Image_Format_xiBayerRG10TS03: Type = 2147681290;
+    pub const XI_GenTL_Image_Format_xiBayerRG12TS03: Type = 2147681292;
+    pub const XI_GenTL_Image_Format_xiBayerRG14TS03: Type = 2147681294;
+    pub const XI_GenTL_Image_Format_xiBayerBG8TS03: Type = 2147680520;
+    pub const XI_GenTL_Image_Format_xiBayerBG10TS03: Type = 2147680522;
+    pub const XI_GenTL_Image_Format_xiBayerBG12TS03: Type = 2147680524;
+    pub const XI_GenTL_Image_Format_xiBayerBG14TS03: Type = 2147680526;
+    pub const XI_GenTL_Image_Format_xiBayerGB8TS03: Type = 2147680776;
+    pub const XI_GenTL_Image_Format_xiBayerGB10TS03: Type = 2147680778;
+    pub const XI_GenTL_Image_Format_xiBayerGB12TS03: Type = 2147680780;
+    pub const XI_GenTL_Image_Format_xiBayerGB14TS03: Type = 2147680782;
+    pub const XI_GenTL_Image_Format_xiBayerGR8TS03: Type = 2147681032;
+    pub const XI_GenTL_Image_Format_xiBayerGR10TS03: Type = 2147681034;
+    pub const XI_GenTL_Image_Format_xiBayerGR12TS03: Type = 2147681036;
+    pub const XI_GenTL_Image_Format_xiBayerGR14TS03: Type = 2147681038;
+    pub const XI_GenTL_Image_Format_Mono16: Type = 17825799;
+    pub const XI_GenTL_Image_Format_BayerGR16: Type = 17825838;
+    pub const XI_GenTL_Image_Format_BayerRG16: Type = 17825839;
+    pub const XI_GenTL_Image_Format_BayerGB16: Type = 17825840;
+    pub const XI_GenTL_Image_Format_BayerBG16: Type = 17825841;
+    pub const XI_GenTL_Image_Format_xiMono16TS03: Type = 2147680272;
+    pub const XI_GenTL_Image_Format_xiMono16TS01: Type = 2147549200;
+    pub const XI_GenTL_Image_Format_xiBayerRG16TS01: Type = 2147550224;
+    pub const XI_GenTL_Image_Format_xiBayerBG16TS01: Type = 2147549456;
+    pub const XI_GenTL_Image_Format_xiBayerGB16TS01: Type = 2147549712;
+    pub const XI_GenTL_Image_Format_xiBayerGR16TS01: Type = 2147549968;
+    pub const XI_GenTL_Image_Format_xiBayerRG16TS03: Type = 2147681296;
+    pub const XI_GenTL_Image_Format_xiBayerBG16TS03: Type = 2147680528;
+    pub const XI_GenTL_Image_Format_xiBayerGB16TS03: Type = 2147680784;
+    pub const XI_GenTL_Image_Format_xiBayerGR16TS03: Type = 2147681040;
+    pub const XI_GenTL_Image_Format_xiMono16TS04: Type = 2147745808;
+    pub const XI_GenTL_Image_Format_xiBayerRG16TS04: Type = 2147746832;
+    pub const XI_GenTL_Image_Format_xiBayerBG16TS04: Type = 2147746064;
+    pub const XI_GenTL_Image_Format_xiBayerGB16TS04: Type = 2147746320;
+    pub const XI_GenTL_Image_Format_xiBayerGR16TS04: Type = 2147746576;
+    pub const XI_GenTL_Image_Format_xiMono16TS02: Type = 2147614736;
+    pub const XI_GenTL_Image_Format_xiMono8TS02: Type = 2147614728;
+    pub const XI_GenTL_Image_Format_xiMono10TS02: Type = 2147614730;
+    pub const XI_GenTL_Image_Format_xiMono12TS02: Type = 2147614732;
+    pub const XI_GenTL_Image_Format_xiMono14TS02: Type = 2147614734;
+    pub const XI_GenTL_Image_Format_xiBayerRG8TS02: Type = 2147615752;
+    pub const XI_GenTL_Image_Format_xiBayerRG10TS02: Type = 2147615754;
+    pub const XI_GenTL_Image_Format_xiBayerRG12TS02: Type = 2147615756;
+    pub const XI_GenTL_Image_Format_xiBayerRG14TS02: Type = 2147615758;
+    pub const XI_GenTL_Image_Format_xiBayerRG16TS02: Type = 2147615760;
+    pub const XI_GenTL_Image_Format_xiBayerBG8TS02: Type = 2147614984;
+    pub const XI_GenTL_Image_Format_xiBayerBG10TS02: Type = 2147614986;
+    pub const XI_GenTL_Image_Format_xiBayerBG12TS02: Type = 2147614988;
+    pub const XI_GenTL_Image_Format_xiBayerBG14TS02: Type = 2147614990;
+    pub const XI_GenTL_Image_Format_xiBayerBG16TS02: Type = 2147614992;
+    pub const XI_GenTL_Image_Format_xiBayerGB8TS02: Type = 2147615240;
+    pub const XI_GenTL_Image_Format_xiBayerGB10TS02: Type = 2147615242;
+    pub const XI_GenTL_Image_Format_xiBayerGB12TS02: Type = 2147615244;
+    pub const XI_GenTL_Image_Format_xiBayerGB14TS02: Type = 2147615246;
+    pub const XI_GenTL_Image_Format_xiBayerGB16TS02: Type = 2147615248;
+    pub const XI_GenTL_Image_Format_xiBayerGR8TS02: Type = 2147615496;
+    pub const XI_GenTL_Image_Format_xiBayerGR10TS02: Type = 2147615498;
+    pub const XI_GenTL_Image_Format_xiBayerGR12TS02: Type = 2147615500;
+    pub const XI_GenTL_Image_Format_xiBayerGR14TS02: Type = 2147615502;
+    pub const XI_GenTL_Image_Format_xiBayerGR16TS02: Type = 2147615504;
+    pub const XI_GenTL_Image_Format_xiMono8TS04: Type = 2147745800;
+    pub const XI_GenTL_Image_Format_xiMono10TS04: Type = 2147745802;
+    pub const XI_GenTL_Image_Format_xiMono12TS04: Type = 2147745804;
+    pub const XI_GenTL_Image_Format_xiMono14TS04: Type = 2147745806;
+    pub const XI_GenTL_Image_Format_xiBayerRG8TS04: Type = 2147746824;
+    pub const XI_GenTL_Image_Format_xiBayerRG10TS04: Type = 2147746826;
+    pub const XI_GenTL_Image_Format_xiBayerRG12TS04: Type = 2147746828;
+    pub const XI_GenTL_Image_Format_xiBayerRG14TS04: Type = 2147746830;
+    pub const XI_GenTL_Image_Format_xiBayerBG8TS04: Type = 2147746056;
+    pub const XI_GenTL_Image_Format_xiBayerBG10TS04: Type = 2147746058;
+    pub const XI_GenTL_Image_Format_xiBayerBG12TS04: Type = 2147746060;
+    pub const XI_GenTL_Image_Format_xiBayerBG14TS04: Type = 2147746062;
+    pub const XI_GenTL_Image_Format_xiBayerGB8TS04: Type = 2147746312;
+    pub const XI_GenTL_Image_Format_xiBayerGB10TS04: Type = 2147746314;
+    pub const XI_GenTL_Image_Format_xiBayerGB12TS04: Type = 2147746316;
+    pub const XI_GenTL_Image_Format_xiBayerGB14TS04: Type = 2147746318;
+    pub const XI_GenTL_Image_Format_xiBayerGR8TS04: Type = 2147746568;
+    pub const XI_GenTL_Image_Format_xiBayerGR10TS04: Type = 2147746570;
+    pub const XI_GenTL_Image_Format_xiBayerGR12TS04: Type = 2147746572;
+    pub const XI_GenTL_Image_Format_xiBayerGR14TS04: Type = 2147746574;
+    pub const XI_GenTL_Image_Format_Mono9p: Type = 2164260873;
+    pub const XI_GenTL_Image_Format_BayerBG9p: Type = 2164261129;
+    pub const XI_GenTL_Image_Format_BayerGB9p: Type = 2164261385;
+    pub const XI_GenTL_Image_Format_BayerGR9p: Type = 2164261641;
+    pub const XI_GenTL_Image_Format_BayerRG9p: Type = 2164261897;
+    pub const XI_GenTL_Image_Format_Mono11p: Type = 2164260875;
+    pub const XI_GenTL_Image_Format_BayerBG11p: Type = 2164261131;
+    pub const XI_GenTL_Image_Format_BayerGB11p: Type = 2164261387;
+    pub const XI_GenTL_Image_Format_BayerGR11p: Type = 2164261643;
+    pub const XI_GenTL_Image_Format_BayerRG11p: Type = 2164261899;
+    pub const XI_GenTL_Image_Format_Mono9: Type = 2147483657;
+    pub const XI_GenTL_Image_Format_BayerBG9: Type = 2147483913;
+    pub const XI_GenTL_Image_Format_BayerGB9: Type = 2147484169;
+    pub const XI_GenTL_Image_Format_BayerGR9: Type = 2147484425;
+    pub const XI_GenTL_Image_Format_BayerRG9: Type = 2147484681;
+    pub const XI_GenTL_Image_Format_Mono11: Type = 2147483659;
+    pub const XI_GenTL_Image_Format_BayerBG11: Type = 2147483915;
+    pub const XI_GenTL_Image_Format_BayerGB11: Type = 2147484171;
+    pub const XI_GenTL_Image_Format_BayerGR11: Type = 2147484427;
+    pub const XI_GenTL_Image_Format_BayerRG11: Type = 2147484683;
+    pub const XI_GenTL_Image_Format_xiMono12g96l_m9e3: Type = 2197819404;
+    pub const XI_GenTL_Image_Format_xiBayerGB12pMS41: Type = 2168521228;
+    pub const XI_GenTL_Image_Format_xiMono12pMS41: Type = 2168520716;
+    pub const XI_GenTL_Image_Format_xiBayerGB10pMS41: Type = 2168521226;
+    pub const XI_GenTL_Image_Format_xiBayerGB12MS41: Type = 2151744012;
+    pub const XI_GenTL_Image_Format_xiBayerGB10MS41: Type = 2151744010;
+    pub const XI_GenTL_Image_Format_xiMono10pMS51: Type = 2169569290;
+    pub const XI_GenTL_Image_Format_xiMono10pMS41: Type = 2168520714;
+    pub const XI_GenTL_Image_Format_xiBayerGB10pMS51: Type = 2169569802;
+    pub const XI_GenTL_Image_Format_xiMono12MS41: Type = 2151743500;
+    pub const XI_GenTL_Image_Format_xiMono10MS51: Type = 2152792074;
+    pub const XI_GenTL_Image_Format_xiMono10MS41: Type = 2151743498;
+    pub const XI_GenTL_Image_Format_xiBayerGB10MS51: Type = 2152792586;
+    pub const XI_GenTL_Image_Format_xiBayerGB8MS41: Type = 2151744008;
+    pub const XI_GenTL_Image_Format_xiMono8MS51: Type = 2152792072;
+    pub const XI_GenTL_Image_Format_xiMono8MS41: Type = 2151743496;
+    pub const XI_GenTL_Image_Format_xiBayerGB8MS51: Type = 2152792584;
+    pub const XI_GenTL_Image_Format_xiMono_m9e3: Type = 2147487760;
+    pub const XI_GenTL_Image_Format_RGBA8: Type = 35651606;
+    pub const XI_GenTL_Image_Format_RGB16Planar: Type = 36700196;
+    pub const XI_GenTL_Image_Format_BGRA16: Type = 37748817;
+    pub const XI_GenTL_Image_Format_BGR16: Type = 36700235;
+    pub const XI_GenTL_Image_Format_RGBA16: Type = 37748836;
+    pub const XI_GenTL_Image_Format_RGB16: Type = 36700211;
+    pub const XI_GenTL_Image_Format_xiMono8MS52: Type = 2152857608;
+    pub const XI_GenTL_Image_Format_xiMono10pMS52: Type = 2169634826;
+    pub const XI_GenTL_Image_Format_xiMono10MS52: Type = 2152857610;
+    pub const XI_GenTL_Image_Format_xiMono12p_m9e3: Type = 2164264972;
+    pub const XI_GenTL_Image_Format_xiMono16LS31: Type = 2150694928;
+    pub const XI_GenTL_Image_Format_xiPaBayerBG8: Type = 2147485192;
+    pub const XI_GenTL_Image_Format_xiPaBayerBG10: Type = 2147485194;
+    pub const XI_GenTL_Image_Format_xiMono12pLS31: Type = 2167472140;
+    pub const XI_GenTL_Image_Format_xiMono12LS31: Type = 2150694924;
+    pub const XI_GenTL_Image_Format_xiMono16LS32: Type = 2150760464;
+    pub const XI_GenTL_Image_Format_xiMono12pLS32: Type = 2167537676;
+    pub const XI_GenTL_Image_Format_xiMono12LS32: Type = 2150760460;
+    pub const XI_GenTL_Image_Format_Mono32: Type = 18874641;
+    pub const XI_GenTL_Image_Format_BayerRG32: Type = 2147484704;
+    pub const XI_GenTL_Image_Format_BayerGR32: Type = 2147484448;
+    pub const XI_GenTL_Image_Format_BayerBG32: Type = 2147483936;
+    pub const XI_GenTL_Image_Format_BayerGB32: Type = 2147484192;
+    pub const XI_GenTL_Image_Format_Mono32f: Type = 2147495968;
+    pub const XI_GenTL_Image_Format_BayerBG32f: Type = 2147496224;
+    pub const XI_GenTL_Image_Format_BayerGB32f: Type = 2147496480;
+    pub const XI_GenTL_Image_Format_BayerGR32f: Type = 2147496736;
+    pub const XI_GenTL_Image_Format_BayerRG32f: Type = 2147496992;
+    pub const XI_GenTL_Image_Format_xiMono_m13e3: Type = 2147500048;
+    pub const XI_GenTL_Image_Format_BayerBG24: Type = 2147483928;
+    pub const XI_GenTL_Image_Format_BayerGB24: Type = 2147484184;
+    pub const XI_GenTL_Image_Format_BayerGR24: Type = 2147484440;
+    pub const XI_GenTL_Image_Format_BayerRG24: Type = 2147484696;
+    pub const XI_GenTL_Image_Format_Mono24: Type = 2147483672;
+    pub const XI_GenTL_Image_Format_xiBayerRG10MS41: Type = 2151744522;
+    pub const XI_GenTL_Image_Format_xiBayerBG10MS41: Type = 2151743754;
+    pub const XI_GenTL_Image_Format_xiBayerGR10MS41: Type = 2151744266;
+    pub const XI_GenTL_Image_Format_xiBayerBG10pMS41: Type = 2168520970;
+    pub const XI_GenTL_Image_Format_xiBayerGR10pMS41: Type = 2168521482;
+    pub const XI_GenTL_Image_Format_xiBayerRG10pMS41: Type = 2168521738;
+    pub const XI_GenTL_Image_Format_xiBayerBG12MS41: Type = 2151743756;
+    pub const XI_GenTL_Image_Format_xiBayerGR12MS41: Type = 2151744268;
+    pub const XI_GenTL_Image_Format_xiBayerRG12MS41: Type = 2151744524;
+    pub const XI_GenTL_Image_Format_xiBayerBG12pMS41: Type = 2168520972;
+    pub const XI_GenTL_Image_Format_xiBayerGR12pMS41: Type = 2168521484;
+    pub const XI_GenTL_Image_Format_xiBayerRG12pMS41: Type = 2168521740;
+    pub const XI_GenTL_Image_Format_xiBayerBG8MS41: Type = 2151743752;
+    pub const XI_GenTL_Image_Format_xiBayerGR8MS41: Type = 2151744264;
+    pub const XI_GenTL_Image_Format_xiBayerRG8MS41: Type = 2151744520;
+    pub const XI_GenTL_Image_Format_xiBayerBG11MS41: Type = 2151743755;
+    pub const XI_GenTL_Image_Format_xiBayerGB11MS41: Type = 2151744011;
+    pub const XI_GenTL_Image_Format_xiBayerGR11MS41: Type = 2151744267;
+    pub const XI_GenTL_Image_Format_xiBayerRG11MS41: Type = 2151744523;
+    pub const XI_GenTL_Image_Format_xiMono11MS41: Type = 2151743499;
+    pub const XI_GenTL_Image_Format_xiBayerBG11pMS41: Type = 2168520971;
+    pub const XI_GenTL_Image_Format_xiBayerGB11pMS41: Type = 2168521227;
+    pub const XI_GenTL_Image_Format_xiBayerGR11pMS41: Type = 2168521483;
+    pub const XI_GenTL_Image_Format_xiBayerRG11pMS41: Type = 2168521739;
+    pub const XI_GenTL_Image_Format_xiMono11pMS41: Type = 2168520715;
+    pub const XI_GenTL_Image_Format_xiMono8LS32: Type = 2150760456;
+    pub const XI_GenTL_Image_Format_xiMono8LS31: Type = 2150694920;
+    pub const XI_GenTL_Image_Format_xiPaBayerBG10p: Type = 2164262410;
+    pub const XI_GenTL_Image_Format_xiPaBayerBG12: Type = 2147485196;
+    pub const XI_GenTL_Image_Format_xiPaBayerBG12p: Type = 2164262412;
+    pub const XI_GenTL_Image_Format_xiPaMono8: Type = 2147484936;
+    pub const XI_GenTL_Image_Format_xiPaMono10: Type = 2147484938;
+    pub const XI_GenTL_Image_Format_xiPaMono10p: Type = 2164262154;
+    pub const XI_GenTL_Image_Format_xiPaMono12: Type = 2147484940;
+    pub const XI_GenTL_Image_Format_xiPaMono12p: Type = 2164262156;
+    pub const XI_GenTL_Image_Format_xiMono16MS41: Type = 2151743504;
+    pub const XI_GenTL_Image_Format_xiBayerBG16MS41: Type = 2151743760;
+    pub const XI_GenTL_Image_Format_xiBayerGB16MS41: Type = 2151744016;
+    pub const XI_GenTL_Image_Format_xiBayerGR16MS41: Type = 2151744272;
+    pub const XI_GenTL_Image_Format_xiBayerRG16MS41: Type = 2151744528;
+    pub const XI_GenTL_Image_Format_xiBayerBG14MS41: Type = 2151743758;
+    pub const XI_GenTL_Image_Format_xiBayerBG14pMS41: Type = 2168520974;
+    pub const XI_GenTL_Image_Format_xiBayerGB14MS41: Type = 2151744014;
+    pub const XI_GenTL_Image_Format_xiBayerGB14pMS41: Type = 2168521230;
+    pub const XI_GenTL_Image_Format_xiBayerGR14MS41: Type = 2151744270;
+    pub const XI_GenTL_Image_Format_xiBayerGR14pMS41: Type = 2168521486;
+    pub const XI_GenTL_Image_Format_xiBayerRG14MS41: Type = 2151744526;
+    pub const XI_GenTL_Image_Format_xiBayerRG14pMS41: Type = 2168521742;
+    pub const XI_GenTL_Image_Format_xiMono14MS41: Type = 2151743502;
+    pub const XI_GenTL_Image_Format_xiMono14pMS41: Type = 2168520718;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct XI_IMG_DESC {
+    pub Area0Left: DWORD,
+    pub Area1Left: DWORD,
+    pub Area2Left: DWORD,
+    pub Area3Left: DWORD,
+    pub Area4Left: DWORD,
+    pub Area5Left: DWORD,
+    pub ActiveAreaWidth: DWORD,
+    pub Area5Right: DWORD,
+    pub Area4Right: DWORD,
+    pub Area3Right: DWORD,
+    pub Area2Right: DWORD,
+    pub Area1Right: DWORD,
+    pub Area0Right: DWORD,
+    pub Area0Top: DWORD,
+    pub Area1Top: DWORD,
+    pub Area2Top: DWORD,
+    pub Area3Top: DWORD,
+    pub Area4Top: DWORD,
+    pub Area5Top: DWORD,
+    pub ActiveAreaHeight: DWORD,
+    pub Area5Bottom: DWORD,
+    pub Area4Bottom: DWORD,
+    pub Area3Bottom: DWORD,
+    pub Area2Bottom: DWORD,
+    pub Area1Bottom: DWORD,
+    pub Area0Bottom: DWORD,
+    pub format: DWORD,
+    pub flags: DWORD,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct XI_IMG {
+    pub size: DWORD,
+    pub bp: LPVOID,
+    pub bp_size: DWORD,
+    pub frm: XI_IMG_FORMAT::Type,
+    pub width: DWORD,
+    pub height: DWORD,
+    pub nframe: DWORD,
+    pub tsSec: DWORD,
+    pub tsUSec: DWORD,
+    pub GPI_level: DWORD,
+    pub black_level: DWORD,
+    pub padding_x: DWORD,
+    pub AbsoluteOffsetX: DWORD,
+    pub AbsoluteOffsetY: DWORD,
+    pub transport_frm: DWORD,
+    pub img_desc: XI_IMG_DESC,
+    pub DownsamplingX: DWORD,
+    pub DownsamplingY: DWORD,
+    pub flags: DWORD,
+    pub exposure_time_us: DWORD,
+    pub gain_db: f32,
+    pub acq_nframe: DWORD,
+    pub image_user_data: DWORD,
+    pub exposure_sub_times_us: [DWORD; 5],
+    pub data_saturation: f64,
+    pub wb_red: f32,
+    pub wb_green: f32,
+    pub wb_blue: f32,
+    pub lg_black_level: DWORD,
+    pub hg_black_level: DWORD,
+    pub lg_range: DWORD,
+    pub hg_range: DWORD,
+    pub gain_ratio: f32,
+    pub fDownsamplingX: f32,
+    pub fDownsamplingY: f32,
+    pub color_filter_array: XI_COLOR_FILTER_ARRAY::Type,
+}
+impl Default for XI_IMG {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+
+#[cfg(all(test, feature = "link"))]
+mod tests {
+    //! Check that the definitions in this file match the ones of the xiapi-sys crate.
+
+    use std::mem::{align_of, size_of, MaybeUninit};
+    use std::ptr::addr_of;
+
+    macro_rules! assert_same_consts {
+        ($($($segment:ident)::+,)*) => {
+            $(assert_eq!(
+                super::$($segment)::+,
+                xiapi_sys::$($segment)::+,
+                stringify!($($segment)::+)
+            );)*
+        };
+    }
+
+    macro_rules! assert_same_layout {
+        ($($type:ident { $($field:ident,)* })*) => {
+            $(
+                assert_eq!(size_of::<super::$type>(), size_of::<xiapi_sys::$type>());
+                assert_eq!(align_of::<super::$type>(), align_of::<xiapi_sys::$type>());
+                $(
+                    let vendored = MaybeUninit::<super::$type>::uninit();
+                    let vendored = vendored.as_ptr();
+                    let linked = MaybeUninit::<xiapi_sys::$type>::uninit();
+                    let linked = linked.as_ptr();
+                    // Safety: Only the addresses of the fields are computed, nothing is read
+                    let offsets = unsafe {
+                        (
+                            addr_of!((*vendored).$field) as usize - vendored as usize,
+                            addr_of!((*linked).$field) as usize - linked as usize,
+                        )
+                    };
+                    assert_eq!(offsets.0, offsets.1, stringify!($type::$field));
+                )*
+            )*
+        };
+    }
+
+    #[test]
+    fn constants() {
+        assert_same_consts! {
+            XI_PRM_EXPOSURE, XI_PRM_EXPOSURE_TIME_SELECTOR, XI_PRM_EXPOSURE_BURST_COUNT,
+            XI_PRM_GAIN_SELECTOR, XI_PRM_GAIN, XI_PRM_DOWNSAMPLING, XI_PRM_DOWNSAMPLING_TYPE,
+            XI_PRM_TEST_PATTERN_GENERATOR_SELECTOR, XI_PRM_TEST_PATTERN, XI_PRM_IMAGE_DATA_FORMAT,
+            XI_PRM_SHUTTER_TYPE, XI_PRM_SENSOR_TAPS, XI_PRM_AEAG, XI_PRM_AEAG_ROI_OFFSET_X,
+            XI_PRM_AEAG_ROI_OFFSET_Y, XI_PRM_AEAG_ROI_WIDTH, XI_PRM_AEAG_ROI_HEIGHT,
+            XI_PRM_SENS_DEFECTS_CORR_LIST_SELECTOR, XI_PRM_SENS_DEFECTS_CORR_LIST_CONTENT,
+            XI_PRM_SENS_DEFECTS_CORR, XI_PRM_AUTO_WB, XI_PRM_MANUAL_WB, XI_PRM_WB_ROI_OFFSET_X,
+            XI_PRM_WB_ROI_OFFSET_Y, XI_PRM_WB_ROI_WIDTH, XI_PRM_WB_ROI_HEIGHT, XI_PRM_WB_KR,
+            XI_PRM_WB_KG, XI_PRM_WB_KB, XI_PRM_WIDTH, XI_PRM_HEIGHT, XI_PRM_OFFSET_X,
+            XI_PRM_OFFSET_Y, XI_PRM_REGION_SELECTOR, XI_PRM_REGION_MODE, XI_PRM_HORIZONTAL_FLIP,
+            XI_PRM_VERTICAL_FLIP, XI_PRM_INTERLINE_EXPOSURE_MODE, XI_PRM_FFC,
+            XI_PRM_FFC_FLAT_FIELD_FILE_NAME, XI_PRM_FFC_DARK_FIELD_FILE_NAME,
+            XI_PRM_BINNING_SELECTOR, XI_PRM_BINNING_VERTICAL_MODE, XI_PRM_BINNING_VERTICAL,
+            XI_PRM_BINNING_HORIZONTAL_MODE, XI_PRM_BINNING_HORIZONTAL,
+            XI_PRM_BINNING_HORIZONTAL_PATTERN, XI_PRM_BINNING_VERTICAL_PATTERN,
+            XI_PRM_DECIMATION_SELECTOR, XI_PRM_DECIMATION_VERTICAL, XI_PRM_DECIMATION_HORIZONTAL,
+            XI_PRM_DECIMATION_HORIZONTAL_PATTERN, XI_PRM_DECIMATION_VERTICAL_PATTERN,
+            XI_PRM_EXP_PRIORITY, XI_PRM_AG_MAX_LIMIT, XI_PRM_AE_MAX_LIMIT, XI_PRM_AEAG_LEVEL,
+            XI_PRM_LIMIT_BANDWIDTH, XI_PRM_LIMIT_BANDWIDTH_MODE, XI_PRM_SENSOR_DATA_BIT_DEPTH,
+            XI_PRM_OUTPUT_DATA_BIT_DEPTH, XI_PRM_IMAGE_DATA_BIT_DEPTH, XI_PRM_OUTPUT_DATA_PACKING,
+            XI_PRM_OUTPUT_DATA_PACKING_TYPE, XI_PRM_IS_COOLED, XI_PRM_COOLING, XI_PRM_TARGET_TEMP,
+            XI_PRM_TEMP_SELECTOR, XI_PRM_TEMP, XI_PRM_TEMP_CONTROL_MODE, XI_PRM_CHIP_TEMP,
+            XI_PRM_HOUS_TEMP, XI_PRM_HOUS_BACK_SIDE_TEMP, XI_PRM_SENSOR_BOARD_TEMP,
+            XI_PRM_TEMP_ELEMENT_SEL, XI_PRM_TEMP_ELEMENT_VALUE, XI_PRM_CMS, XI_PRM_CMS_INTENT,
+            XI_PRM_APPLY_CMS, XI_PRM_INPUT_CMS_PROFILE, XI_PRM_OUTPUT_CMS_PROFILE,
+            XI_PRM_IMAGE_IS_COLOR, XI_PRM_COLOR_FILTER_ARRAY, XI_PRM_GAMMAY, XI_PRM_GAMMAC,
+            XI_PRM_SHARPNESS, XI_PRM_CC_MATRIX_00, XI_PRM_CC_MATRIX_01, XI_PRM_CC_MATRIX_02,
+            XI_PRM_CC_MATRIX_03, XI_PRM_CC_MATRIX_10, XI_PRM_CC_MATRIX_11, XI_PRM_CC_MATRIX_12,
+            XI_PRM_CC_MATRIX_13, XI_PRM_CC_MATRIX_20, XI_PRM_CC_MATRIX_21, XI_PRM_CC_MATRIX_22,
+            XI_PRM_CC_MATRIX_23, XI_PRM_CC_MATRIX_30, XI_PRM_CC_MATRIX_31, XI_PRM_CC_MATRIX_32,
+            XI_PRM_CC_MATRIX_33, XI_PRM_DEFAULT_CC_MATRIX, XI_PRM_CC_MATRIX_NORM,
+            XI_PRM_TRG_SOURCE, XI_PRM_TRG_SOFTWARE, XI_PRM_TRG_SELECTOR, XI_PRM_TRG_OVERLAP,
+            XI_PRM_ACQ_FRAME_BURST_COUNT, XI_PRM_TIMESTAMP, XI_PRM_GPI_SELECTOR, XI_PRM_GPI_MODE,
+            XI_PRM_GPI_LEVEL, XI_PRM_GPI_LEVEL_AT_IMAGE_EXP_START,
+            XI_PRM_GPI_LEVEL_AT_IMAGE_EXP_END, XI_PRM_GPO_SELECTOR, XI_PRM_GPO_MODE,
+            XI_PRM_LED_SELECTOR, XI_PRM_LED_MODE, XI_PRM_DEBOUNCE_EN, XI_PRM_DEBOUNCE_T0,
+            XI_PRM_DEBOUNCE_T1, XI_PRM_DEBOUNCE_POL, XI_PRM_LENS_MODE, XI_PRM_LENS_APERTURE_VALUE,
+            XI_PRM_LENS_APERTURE_INDEX, XI_PRM_LENS_FOCUS_MOVEMENT_VALUE, XI_PRM_LENS_FOCUS_MOVE,
+            XI_PRM_LENS_FOCAL_LENGTH, XI_PRM_LENS_FEATURE_SELECTOR, XI_PRM_LENS_FEATURE,
+            XI_PRM_DEVICE_NAME, XI_PRM_DEVICE_TYPE, XI_PRM_DEVICE_MODEL_ID, XI_PRM_SENSOR_MODEL_ID,
+            XI_PRM_DEVICE_SN, XI_PRM_DEVICE_SENS_SN, XI_PRM_DEVICE_INSTANCE_PATH,
+            XI_PRM_DEVICE_LOCATION_PATH, XI_PRM_DEVICE_USER_ID, XI_PRM_DEVICE_MANIFEST,
+            XI_PRM_IMAGE_USER_DATA, XI_PRM_IMAGE_DATA_FORMAT_RGB32_ALPHA,
+            XI_PRM_IMAGE_PAYLOAD_SIZE, XI_PRM_TRANSPORT_PIXEL_FORMAT, XI_PRM_TRANSPORT_DATA_TARGET,
+            XI_PRM_SENSOR_CLOCK_FREQ_HZ, XI_PRM_SENSOR_CLOCK_FREQ_INDEX,
+            XI_PRM_SENSOR_OUTPUT_CHANNEL_COUNT, XI_PRM_FRAMERATE, XI_PRM_COUNTER_SELECTOR,
+            XI_PRM_COUNTER_VALUE, XI_PRM_ACQ_TIMING_MODE, XI_PRM_AVAILABLE_BANDWIDTH,
+            XI_PRM_BUFFER_POLICY, XI_PRM_LUT_EN, XI_PRM_LUT_INDEX, XI_PRM_LUT_VALUE,
+            XI_PRM_TRG_DELAY, XI_PRM_TS_RST_MODE, XI_PRM_TS_RST_SOURCE, XI_PRM_IS_DEVICE_EXIST,
+            XI_PRM_ACQ_BUFFER_SIZE, XI_PRM_ACQ_BUFFER_SIZE_UNIT, XI_PRM_ACQ_TRANSPORT_BUFFER_SIZE,
+            XI_PRM_ACQ_TRANSPORT_PACKET_SIZE, XI_PRM_BUFFERS_QUEUE_SIZE,
+            XI_PRM_ACQ_TRANSPORT_BUFFER_COMMIT, XI_PRM_RECENT_FRAME, XI_PRM_DEVICE_RESET,
+            XI_PRM_CONCAT_IMG_MODE, XI_PRM_CONCAT_IMG_COUNT,
+            XI_PRM_CONCAT_IMG_TRANSPORT_IMG_OFFSET, XI_PRM_PROBE_SELECTOR, XI_PRM_PROBE_VALUE,
+            XI_PRM_COLUMN_FPN_CORRECTION, XI_PRM_ROW_FPN_CORRECTION,
+            XI_PRM_COLUMN_BLACK_OFFSET_CORRECTION, XI_PRM_ROW_BLACK_OFFSET_CORRECTION,
+            XI_PRM_SENSOR_MODE, XI_PRM_HDR, XI_PRM_HDR_KNEEPOINT_COUNT, XI_PRM_HDR_T1,
+            XI_PRM_HDR_T2, XI_PRM_KNEEPOINT1, XI_PRM_KNEEPOINT2, XI_PRM_IMAGE_BLACK_LEVEL,
+            XI_PRM_IMAGE_AREA, XI_PRM_DUAL_ADC_MODE, XI_PRM_DUAL_ADC_GAIN_RATIO,
+            XI_PRM_DUAL_ADC_THRESHOLD, XI_PRM_COMPRESSION_REGION_SELECTOR,
+            XI_PRM_COMPRESSION_REGION_START, XI_PRM_COMPRESSION_REGION_GAIN,
+            XI_PRM_VERSION_SELECTOR, XI_PRM_VERSION, XI_PRM_API_VERSION, XI_PRM_DRV_VERSION,
+            XI_PRM_MCU1_VERSION, XI_PRM_MCU2_VERSION, XI_PRM_MCU3_VERSION, XI_PRM_FPGA1_VERSION,
+            XI_PRM_XMLMAN_VERSION, XI_PRM_HW_REVISION, XI_PRM_FACTORY_SET_VERSION,
+            XI_PRM_DEBUG_LEVEL, XI_PRM_AUTO_BANDWIDTH_CALCULATION, XI_PRM_NEW_PROCESS_CHAIN_ENABLE,
+            XI_PRM_PROC_NUM_THREADS, XI_PRM_READ_FILE_FFS, XI_PRM_WRITE_FILE_FFS,
+            XI_PRM_FFS_FILE_NAME, XI_PRM_FFS_FILE_ID, XI_PRM_FFS_FILE_SIZE, XI_PRM_FREE_FFS_SIZE,
+            XI_PRM_USED_FFS_SIZE, XI_PRM_FFS_ACCESS_KEY, XI_PRM_API_CONTEXT_LIST,
+            XI_PRM_SENSOR_FEATURE_SELECTOR, XI_PRM_SENSOR_FEATURE_VALUE,
+            XI_PRM_ACQUISITION_STATUS_SELECTOR, XI_PRM_ACQUISITION_STATUS, XI_PRM_DP_UNIT_SELECTOR,
+            XI_PRM_DP_PROC_SELECTOR, XI_PRM_DP_PARAM_SELECTOR, XI_PRM_DP_PARAM_VALUE,
+            XI_PRM_GENTL_DATASTREAM_ENABLED, XI_PRM_GENTL_DATASTREAM_CONTEXT,
+            XI_PRM_USER_SET_SELECTOR, XI_PRM_USER_SET_LOAD, XI_PRM_USER_SET_DEFAULT,
+            XI_PRM_INFO_SETTABLE, XI_PRM_INFO_MIN, XI_PRM_INFO_MAX, XI_PRM_INFO_INCREMENT,
+            XI_PRM_INFO, XI_PRMM_REQ_VAL_BUFFER_SIZE, XI_PRMM_DIRECT_UPDATE, XI_PRM_BPC,
+            XI_MQ_LED_STATUS1, XI_MQ_LED_STATUS2, XI_MQ_LED_POWER, E_MODEL::MODEL_ID_UNKNOWN,
+            E_MODEL::MODEL_ID_MR274CU_BH, E_MODEL::MODEL_ID_MR16000MU,
+            E_MODEL::MODEL_ID_MR282CC_BH, E_MODEL::MODEL_ID_MR274MU_BH,
+            E_MODEL::MODEL_ID_MR456CU_BH, E_MODEL::MODEL_ID_MR252CC_BH,
+            E_MODEL::MODEL_ID_MR4021MU_BH, E_MODEL::MODEL_ID_MR4022MU_BH,
+            E_MODEL::MODEL_ID_MR655CU_BH, E_MODEL::MODEL_ID_MR11002M,
+            E_MODEL::MODEL_ID_MR4021CU_BH, E_MODEL::MODEL_ID_MR655MU_BH,
+            E_MODEL::MODEL_ID_MR282CU_BH, E_MODEL::MODEL_ID_MR252CU_BH,
+            E_MODEL::MODEL_ID_MR285MU_BH, E_MODEL::MODEL_ID_MR285CU_BH,
+            E_MODEL::MODEL_ID_MR285MC_BH, E_MODEL::MODEL_ID_MR285CC_BH,
+            E_MODEL::MODEL_ID_MH160MC_KK_FA, E_MODEL::MODEL_ID_MU9PC_BH,
+            E_MODEL::MODEL_ID_MR11002C, E_MODEL::MODEL_ID_MU9PM_MH, E_MODEL::MODEL_ID_MU9PC_MH,
+            E_MODEL::MODEL_ID_MU9PM_BH, E_MODEL::MODEL_ID_GENTLXIAPI_REFERENCE,
+            E_MODEL::MODEL_ID_MQ013CG_E2, E_MODEL::MODEL_ID_MQ013MG_E2,
+            E_MODEL::MODEL_ID_MQ003CG_CM, E_MODEL::MODEL_ID_MQ003MG_CM,
+            E_MODEL::MODEL_ID_MQ022CG_CM, E_MODEL::MODEL_ID_MQ022MG_CM,
+            E_MODEL::MODEL_ID_MQ042CG_CM, E_MODEL::MODEL_ID_MQ042MG_CM,
+            E_MODEL::MODEL_ID_MM282CU_BH, E_MODEL::MODEL_ID_MQ022MG_CM_SR2,
+            E_MODEL::MODEL_ID_MQ042CG_CM_TG, E_MODEL::MODEL_ID_MQ042MG_CM_TG,
+            E_MODEL::MODEL_ID_MQ_USB3LINK, E_MODEL::MODEL_ID_MU9PC_SLC5,
+            E_MODEL::MODEL_ID_MQ022CG_CM_TS, E_MODEL::MODEL_ID_MQ022MG_CM_TS,
+            E_MODEL::MODEL_ID_MQ042CG_CM_TS, E_MODEL::MODEL_ID_MQ042MG_CM_TS,
+            E_MODEL::MODEL_ID_MQ013CG_ONV, E_MODEL::MODEL_ID_MQ013MG_ONV,
+            E_MODEL::MODEL_ID_MQ013RG_E2, E_MODEL::MODEL_ID_MQ042RG_CM,
+            E_MODEL::MODEL_ID_MR11002XC_ICW, E_MODEL::MODEL_ID_MQ020CG_E2,
+            E_MODEL::MODEL_ID_MQ020MG_E2, E_MODEL::MODEL_ID_MQ022RG_CM,
+            E_MODEL::MODEL_ID_MR285CC_DP, E_MODEL::MODEL_ID_MR285MC_DP,
+            E_MODEL::MODEL_ID_MR252CU_BRD, E_MODEL::MODEL_ID_MH110MC_KK_FA,
+            E_MODEL::MODEL_ID_MR282CU_BRD, E_MODEL::MODEL_ID_MR282CC_DP,
+            E_MODEL::MODEL_ID_MR285MU_BH_IRE, E_MODEL::MODEL_ID_MR285MC_DP_IRE,
+            E_MODEL::MODEL_ID_MH110XC_KK_FA, E_MODEL::MODEL_ID_MH160XC_KK_FA,
+            E_MODEL::MODEL_ID_MR252CC_DP, E_MODEL::MODEL_ID_MR285MC_BH_IRE,
+            E_MODEL::MODEL_ID_MR456CC_BH, E_MODEL::MODEL_ID_MR282CU_DP,
+            E_MODEL::MODEL_ID_MQ022HG_IM_ST32_NIR, E_MODEL::MODEL_ID_MR282CC_BRD,
+            E_MODEL::MODEL_ID_MR252CC_BRD, E_MODEL::MODEL_ID_MQ022HG_IM_SM4X4_VIS,
+            E_MODEL::MODEL_ID_MR252CU_DP, E_MODEL::MODEL_ID_MR285MU_BRD,
+            E_MODEL::MODEL_ID_MR285CU_BRD, E_MODEL::MODEL_ID_MR285MC_BRD,
+            E_MODEL::MODEL_ID_MR285CC_BRD, E_MODEL::MODEL_ID_MR285CC_DP_IRE,
+            E_MODEL::MODEL_ID_MR285CC_BH_IRE, E_MODEL::MODEL_ID_MR285CU_BH_IRE,
+            E_MODEL::MODEL_ID_MX11002, E_MODEL::MODEL_ID_MH110CC_KK_FA,
+            E_MODEL::MODEL_ID_MR16000CU, E_MODEL::MODEL_ID_MH160CC_KK_FA,
+            E_MODEL::MODEL_ID_MR4022MC_VELETA, E_MODEL::MODEL_ID_MR4021MC_VELETA,
+            E_MODEL::MODEL_ID_MU9JC_BH, E_MODEL::MODEL_ID_MU9JM_BH,
+            E_MODEL::MODEL_ID_MQ022HG_IM_LS100_NIR, E_MODEL::MODEL_ID_CB120RG_CM_X8G3,
+            E_MODEL::MODEL_ID_MD091CC_SY, E_MODEL::MODEL_ID_CB120MG_CM_X8G3,
+            E_MODEL::MODEL_ID_MD028CU_SY, E_MODEL::MODEL_ID_MD061CU_SY,
+            E_MODEL::MODEL_ID_MD091CU_SY, E_MODEL::MODEL_ID_MD028MU_SY,
+            E_MODEL::MODEL_ID_MD061MU_SY, E_MODEL::MODEL_ID_MD091MU_SY,
+            E_MODEL::MODEL_ID_CB200CG_CM, E_MODEL::MODEL_ID_CB200MG_CM,
+            E_MODEL::MODEL_ID_CB120CG_CM_X8G3, E_MODEL::MODEL_ID_CB120RG_CM,
+            E_MODEL::MODEL_ID_MD120CU_SY, E_MODEL::MODEL_ID_MD120MU_SY,
+            E_MODEL::MODEL_ID_MQ022HG_IM_UN, E_MODEL::MODEL_ID_CAL_Simulator,
+            E_MODEL::MODEL_ID_MT031CG_SY, E_MODEL::MODEL_ID_MQ022HG_IM_LS150_VISNIR,
+            E_MODEL::MODEL_ID_MQ022HG_IM_SM5X5_NIR, E_MODEL::MODEL_ID_MQ022HG_IM_SM4X4_NIR,
+            E_MODEL::MODEL_ID_MQ022MG_CM_BARE_BRD, E_MODEL::MODEL_ID_MQ042MG_CM_BARE_BRD,
+            E_MODEL::MODEL_ID_MT023CG_SY, E_MODEL::MODEL_ID_MT023MG_SY,
+            E_MODEL::MODEL_ID_MT200CG_CM, E_MODEL::MODEL_ID_MT200MG_CM,
+            E_MODEL::MODEL_ID_CB120CG_CM, E_MODEL::MODEL_ID_CB120MG_CM,
+            E_MODEL::MODEL_ID_MT003CG_LX, E_MODEL::MODEL_ID_MT003MG_LX,
+            E_MODEL::MODEL_ID_MQ013CG_ON, E_MODEL::MODEL_ID_MQ013MG_ON,
+            E_MODEL::MODEL_ID_MT050CG_SY, E_MODEL::MODEL_ID_MT050MG_SY,
+            E_MODEL::MODEL_ID_MT120CG_CM, E_MODEL::MODEL_ID_MT031MG_SY,
+            E_MODEL::MODEL_ID_MT120MG_CM, E_MODEL::MODEL_ID_MJ042IC_TS_UB,
+            E_MODEL::MODEL_ID_MH110XC_KK_TP2_1, E_MODEL::MODEL_ID_MC023CG_SY,
+            E_MODEL::MODEL_ID_MC023MG_SY, E_MODEL::MODEL_ID_MC023CG_SY_FLEX,
+            E_MODEL::MODEL_ID_MX124CG_SY_X2G2, E_MODEL::MODEL_ID_MX124MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX089CG_SY_X2G2, E_MODEL::MODEL_ID_MX089MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MC031CG_SY, E_MODEL::MODEL_ID_MC031MG_SY,
+            E_MODEL::MODEL_ID_MC050CG_SY, E_MODEL::MODEL_ID_MC050MG_SY,
+            E_MODEL::MODEL_ID_MC089CG_SY, E_MODEL::MODEL_ID_MC124CG_SY,
+            E_MODEL::MODEL_ID_MC089MG_SY, E_MODEL::MODEL_ID_MC124MG_SY,
+            E_MODEL::MODEL_ID_MX023CG_SY_X2G2, E_MODEL::MODEL_ID_MX023MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX031CG_SY_X2G2, E_MODEL::MODEL_ID_MX031MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX050CG_SY_X2G2, E_MODEL::MODEL_ID_MX050MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX042CG_CM_X2G2, E_MODEL::MODEL_ID_MX042MG_CM_X2G2,
+            E_MODEL::MODEL_ID_MX042RG_CM_X2G2, E_MODEL::MODEL_ID_CB500CG_CM,
+            E_MODEL::MODEL_ID_CB500MG_CM, E_MODEL::MODEL_ID_CB042CG_GP,
+            E_MODEL::MODEL_ID_CB042MG_GP, E_MODEL::MODEL_ID_CB013CG_LX_X8G3,
+            E_MODEL::MODEL_ID_CB013MG_LX_X8G3, E_MODEL::MODEL_ID_MJ081MC_TS_TC,
+            E_MODEL::MODEL_ID_MC023MG_SY_FLEX, E_MODEL::MODEL_ID_MC031CG_SY_FLEX,
+            E_MODEL::MODEL_ID_MC031MG_SY_FLEX, E_MODEL::MODEL_ID_MC050CG_SY_FLEX,
+            E_MODEL::MODEL_ID_MC050MG_SY_FLEX, E_MODEL::MODEL_ID_MC089CG_SY_FLEX,
+            E_MODEL::MODEL_ID_MC089MG_SY_FLEX, E_MODEL::MODEL_ID_MC124CG_SY_FLEX,
+            E_MODEL::MODEL_ID_MC124MG_SY_FLEX, E_MODEL::MODEL_ID_MQ013RG_ON,
+            E_MODEL::MODEL_ID_MJ042MC_TS_TC, E_MODEL::MODEL_ID_MJ081XC_TS_TC,
+            E_MODEL::MODEL_ID_MJ081XC_TS_TP1_1_25, E_MODEL::MODEL_ID_MJ150MR_GP,
+            E_MODEL::MODEL_ID_MX200CG_CM_X4G2, E_MODEL::MODEL_ID_MX200MG_CM_X4G2,
+            E_MODEL::MODEL_ID_MX120CG_CM_X4G2, E_MODEL::MODEL_ID_MX120MG_CM_X4G2,
+            E_MODEL::MODEL_ID_MX120RG_CM_X4G2, E_MODEL::MODEL_ID_MJ160MU_TS_UB,
+            E_MODEL::MODEL_ID_MJ160MC_TS_UB, E_MODEL::MODEL_ID_MQ022HG_IM_SM2X2_RGBNIR,
+            E_MODEL::MODEL_ID_CB019CG_LX_X8G3, E_MODEL::MODEL_ID_CB019MG_LX_X8G3,
+            E_MODEL::MODEL_ID_CB160CG_LX_X8G3, E_MODEL::MODEL_ID_CB160MG_LX_X8G3,
+            E_MODEL::MODEL_ID_MJ160XC_TS_UB, E_MODEL::MODEL_ID_MX004MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX004CG_SY_X2G2, E_MODEL::MODEL_ID_MX016MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX016CG_SY_X2G2, E_MODEL::MODEL_ID_MJ290MC_TS_UB,
+            E_MODEL::MODEL_ID_MJ150XR_GP_TP2_1_GO, E_MODEL::MODEL_ID_MJ042MU_TS_TC,
+            E_MODEL::MODEL_ID_MX022CG_CM_X2G2, E_MODEL::MODEL_ID_MX022MG_CM_X2G2,
+            E_MODEL::MODEL_ID_MX022RG_CM_X2G2, E_MODEL::MODEL_ID_MX019MM_PH_X2G2,
+            E_MODEL::MODEL_ID_MX500CG_CM_X4G2, E_MODEL::MODEL_ID_MX500MG_CM_X4G2,
+            E_MODEL::MODEL_ID_MU181CR_ON, E_MODEL::MODEL_ID_MQ022MG_CM_BRD,
+            E_MODEL::MODEL_ID_MJ042MR_GP_P11, E_MODEL::MODEL_ID_MJ042MR_GP_P11_BSI,
+            E_MODEL::MODEL_ID_MQ022MG_CM_SL_BRD, E_MODEL::MODEL_ID_MQ022MG_CM_FL_BRD,
+            E_MODEL::MODEL_ID_MQ022MG_CM_FL, E_MODEL::MODEL_ID_MX377MR_GP_Fx_X4G3_MTP_W,
+            E_MODEL::MODEL_ID_MX377MR_GP_Bx_X4G3_MTP_W, E_MODEL::MODEL_ID_CB262MG_GP_X8G3,
+            E_MODEL::MODEL_ID_CB262CG_GP_X8G3, E_MODEL::MODEL_ID_MR655MU_BRD,
+            E_MODEL::MODEL_ID_MX610CR_SY_X4G3_FF, E_MODEL::MODEL_ID_MQ022HG_IM_SM4X4_VIS3,
+            E_MODEL::MODEL_ID_MX1510MR_SY_X4G3_FF, E_MODEL::MODEL_ID_MX1510CR_SY_X4G3_FF,
+            E_MODEL::MODEL_ID_MX1018MR_SY_X4G3_FF, E_MODEL::MODEL_ID_MX1018CR_SY_X4G3_FF,
+            E_MODEL::MODEL_ID_MX610MR_SY_X4G3_FF, E_MODEL::MODEL_ID_MX120CG_CM_X8G3_FF,
+            E_MODEL::MODEL_ID_CB042MG_GP_BSI, E_MODEL::MODEL_ID_CB042CG_GP_BSI,
+            E_MODEL::MODEL_ID_CB500MG_CM_X8G3_ELD, E_MODEL::MODEL_ID_CB654CG_GP_X8G3,
+            E_MODEL::MODEL_ID_CB654MG_GP_X8G3, E_MODEL::MODEL_ID_MC050YG_SY_UB,
+            E_MODEL::MODEL_ID_MC050ZG_SY_UB, E_MODEL::MODEL_ID_MJ042MR_GP_P6,
+            E_MODEL::MODEL_ID_MJ042MR_GP_P6_BSI, E_MODEL::MODEL_ID_MX377MR_GP_Fx_X4G3_MTP,
+            E_MODEL::MODEL_ID_MX377MR_GP_Bx_X4G3_MTP, E_MODEL::MODEL_ID_MJ042MR_GP_P11_BSI_TVISB,
+            E_MODEL::MODEL_ID_MJ042MR_GP_P11_BSI_UV, E_MODEL::MODEL_ID_MJ042MR_GP_P11_BSI_VIS,
+            E_MODEL::MODEL_ID_CB120CG_CM_X8G3_R2, E_MODEL::MODEL_ID_CB120MG_CM_X8G3_R2,
+            E_MODEL::MODEL_ID_CB120RG_CM_X8G3_R2, E_MODEL::MODEL_ID_CB013CG_LX_X8G3_R2,
+            E_MODEL::MODEL_ID_CB013MG_LX_X8G3_R2, E_MODEL::MODEL_ID_CB019CG_LX_X8G3_R2,
+            E_MODEL::MODEL_ID_CB019MG_LX_X8G3_R2, E_MODEL::MODEL_ID_CB160CG_LX_X8G3_R2,
+            E_MODEL::MODEL_ID_CB160MG_LX_X8G3_R2, E_MODEL::MODEL_ID_MX1510MR_SY_X2G2_VXL,
+            E_MODEL::MODEL_ID_MX1510CR_SY_X2G2_VXL, E_MODEL::MODEL_ID_MQ022HG_IM_SM4X4_REDNIR,
+            E_MODEL::MODEL_ID_MX200MG_CM_X4G2_TG_FL_EMS, E_MODEL::MODEL_ID_MH160XC_KK_TP2_1,
+            E_MODEL::MODEL_ID_MU181CR_ON_R3, E_MODEL::MODEL_ID_MX042MR_GP_X4G2_ARX,
+            E_MODEL::MODEL_ID_MX161CG_SY_X2G2, E_MODEL::MODEL_ID_MX161MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX203CG_SY_X2G2, E_MODEL::MODEL_ID_MX203MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX245CG_SY_X2G2, E_MODEL::MODEL_ID_MX245MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX019MM_PH_X2G2_FV_FR, E_MODEL::MODEL_ID_MJ150CR_GP,
+            E_MODEL::MODEL_ID_MJ150XR_GP_FA_GO, E_MODEL::MODEL_ID_MQ022HG_IM_LS100_NIR2,
+            E_MODEL::MODEL_ID_MQ022HG_IM_LS150_VN2, E_MODEL::MODEL_ID_MQ022HG_IM_SM4X4_VIS2,
+            E_MODEL::MODEL_ID_MQ022HG_IM_SM4X4_RN2, E_MODEL::MODEL_ID_MQ022HG_IM_SM5X5_NIR2,
+            E_MODEL::MODEL_ID_MX022HG_IM_LS100_NIR2_FL, E_MODEL::MODEL_ID_MX022HG_IM_LS150_VN2_FL,
+            E_MODEL::MODEL_ID_MX022HG_IM_SM4X4_VIS2_FL, E_MODEL::MODEL_ID_MX022HG_IM_SM4X4_RN2_FL,
+            E_MODEL::MODEL_ID_MX022HG_IM_SM5X5_NIR2_FL, E_MODEL::MODEL_ID_MX022HG_IM_LS100_NIR2_FV,
+            E_MODEL::MODEL_ID_MX022HG_IM_LS150_VN2_FV, E_MODEL::MODEL_ID_MX022HG_IM_SM4X4_VIS2_FV,
+            E_MODEL::MODEL_ID_MX022HG_IM_SM4X4_RN2_FV, E_MODEL::MODEL_ID_MX022HG_IM_SM5X5_NIR2_FV,
+            E_MODEL::MODEL_ID_MX022HG_IM_LS100_NIR2_FF, E_MODEL::MODEL_ID_MX022HG_IM_LS150_VN2_FF,
+            E_MODEL::MODEL_ID_MX022HG_IM_SM4X4_VIS2_FF, E_MODEL::MODEL_ID_MX022HG_IM_SM4X4_RN2_FF,
+            E_MODEL::MODEL_ID_MX022HG_IM_SM5X5_NIR2_FF, E_MODEL::MODEL_ID_MX022MG_CM_BARE_FL_IM,
+            E_MODEL::MODEL_ID_MX022MG_CM_BARE_FL_BRD, E_MODEL::MODEL_ID_MQ022HG_IM_LS100_NIR2_FL,
+            E_MODEL::MODEL_ID_MQ022HG_IM_LS150_VN2_FL, E_MODEL::MODEL_ID_MQ022HG_IM_SM4X4_VIS2_FL,
+            E_MODEL::MODEL_ID_MQ022HG_IM_SM4X4_RN2_FL, E_MODEL::MODEL_ID_MQ022HG_IM_SM5X5_NIR2_FL,
+            E_MODEL::MODEL_ID_MQ022MG_CM_BARE_IM, E_MODEL::MODEL_ID_MQ022MG_CM_BARE_FL_IM,
+            E_MODEL::MODEL_ID_MQ022MG_CM_BARE_FL_BRD, E_MODEL::MODEL_ID_MX262CG_GP_X4G2_FF,
+            E_MODEL::MODEL_ID_MX262MG_GP_X4G2_FF, E_MODEL::MODEL_ID_MX262RG_GP_X4G2_FF,
+            E_MODEL::MODEL_ID_MX610CR_SY_X2G2_VXL, E_MODEL::MODEL_ID_MX610MR_SY_X2G2_VXL,
+            E_MODEL::MODEL_ID_MX510XG_GP_FA_GO, E_MODEL::MODEL_ID_MC161CG_SY_FLEX,
+            E_MODEL::MODEL_ID_MC161MG_SY_FLEX, E_MODEL::MODEL_ID_MC161CG_SY,
+            E_MODEL::MODEL_ID_MC161MG_SY, E_MODEL::MODEL_ID_MC203CG_SY,
+            E_MODEL::MODEL_ID_MC203CG_SY_FLEX, E_MODEL::MODEL_ID_MC203MG_SY,
+            E_MODEL::MODEL_ID_MC203MG_SY_FLEX, E_MODEL::MODEL_ID_MC245CG_SY,
+            E_MODEL::MODEL_ID_MC245CG_SY_FLEX, E_MODEL::MODEL_ID_MC245MG_SY,
+            E_MODEL::MODEL_ID_MC245MG_SY_FLEX, E_MODEL::MODEL_ID_MX510XG_GP_TP2_1_GO,
+            E_MODEL::MODEL_ID_MX161CG_SY_X2G2_HDR, E_MODEL::MODEL_ID_MX161MG_SY_X2G2_HDR,
+            E_MODEL::MODEL_ID_MX203CG_SY_X2G2_HDR, E_MODEL::MODEL_ID_MX203MG_SY_X2G2_HDR,
+            E_MODEL::MODEL_ID_MX245CG_SY_X2G2_HDR, E_MODEL::MODEL_ID_MX245MG_SY_X2G2_HDR,
+            E_MODEL::MODEL_ID_MC161CG_SY_HDR, E_MODEL::MODEL_ID_MC161CG_SY_FLEX_HDR,
+            E_MODEL::MODEL_ID_MC161MG_SY_HDR, E_MODEL::MODEL_ID_MC161MG_SY_FLEX_HDR,
+            E_MODEL::MODEL_ID_MC203CG_SY_HDR, E_MODEL::MODEL_ID_MC203CG_SY_FLEX_HDR,
+            E_MODEL::MODEL_ID_MC203MG_SY_HDR, E_MODEL::MODEL_ID_MC203MG_SY_FLEX_HDR,
+            E_MODEL::MODEL_ID_MC245CG_SY_HDR, E_MODEL::MODEL_ID_MC245CG_SY_FLEX_HDR,
+            E_MODEL::MODEL_ID_MC245MG_SY_HDR, E_MODEL::MODEL_ID_MC245MG_SY_FLEX_HDR,
+            E_MODEL::MODEL_ID_MX120MG_CM_X8G3_FF, E_MODEL::MODEL_ID_MJ150XR_GP_TP2_6_1_GO,
+            E_MODEL::MODEL_ID_MX245MG_SY_X4G3_FF, E_MODEL::MODEL_ID_MX245CG_SY_X4G3_FF,
+            E_MODEL::MODEL_ID_MX203MG_SY_X4G3_FF, E_MODEL::MODEL_ID_MX203CG_SY_X4G3_FF,
+            E_MODEL::MODEL_ID_MX161MG_SY_X4G3_FF, E_MODEL::MODEL_ID_MX161CG_SY_X4G3_FF,
+            E_MODEL::MODEL_ID_MU181CR_ON_CZM, E_MODEL::MODEL_ID_MU181CR_ON_CZM_R3,
+            E_MODEL::MODEL_ID_MX510XG_GP_FA_CSI, E_MODEL::MODEL_ID_MX510MG_GP,
+            E_MODEL::MODEL_ID_MX124CG_SY_LT_X2G2, E_MODEL::MODEL_ID_MX124MG_SY_LT_X2G2,
+            E_MODEL::MODEL_ID_MC031MG_SY_FL_PHO, E_MODEL::MODEL_ID_CB023MR_GP_X8G3,
+            E_MODEL::MODEL_ID_MX031MG_SY_X2G2_CS_PHO, E_MODEL::MODEL_ID_MJ042MR_GP_P11_BSI_NEO,
+            E_MODEL::MODEL_ID_MX610XR_SY_X4G3_FA_CSI, E_MODEL::MODEL_ID_MX610XR_SY_X4G3_FA_GO,
+            E_MODEL::MODEL_ID_MX610XR_SY_X4G3_TP2_1_CSI,
+            E_MODEL::MODEL_ID_MX610XR_SY_X4G3_TP2_1_GO, E_MODEL::MODEL_ID_MX262RG_GP_X8G3_MTP_LA,
+            E_MODEL::MODEL_ID_MX081UG_SY_X2G2_HDR, E_MODEL::MODEL_ID_MJ042MR_GP_P6_BSI_XPL,
+            E_MODEL::MODEL_ID_MX071CG_SY_X2G2, E_MODEL::MODEL_ID_MX071MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX028CG_SY_X2G2, E_MODEL::MODEL_ID_MX028MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX017CG_SY_X2G2, E_MODEL::MODEL_ID_MX017MG_SY_X2G2,
+            E_MODEL::MODEL_ID_MX005CG_SY_X2G2, E_MODEL::MODEL_ID_MX005MG_SY_X2G2, XI_RET::XI_OK,
+            XI_RET::XI_INVALID_HANDLE, XI_RET::XI_READREG, XI_RET::XI_WRITEREG,
+            XI_RET::XI_FREE_RESOURCES, XI_RET::XI_FREE_CHANNEL, XI_RET::XI_FREE_BANDWIDTH,
+            XI_RET::XI_READBLK, XI_RET::XI_WRITEBLK, XI_RET::XI_NO_IMAGE, XI_RET::XI_TIMEOUT,
+            XI_RET::XI_INVALID_ARG, XI_RET::XI_NOT_SUPPORTED, XI_RET::XI_ISOCH_ATTACH_BUFFERS,
+            XI_RET::XI_GET_OVERLAPPED_RESULT, XI_RET::XI_MEMORY_ALLOCATION,
+            XI_RET::XI_DLLCONTEXTISNULL, XI_RET::XI_DLLCONTEXTISNONZERO,
+            XI_RET::XI_DLLCONTEXTEXIST, XI_RET::XI_TOOMANYDEVICES, XI_RET::XI_ERRORCAMCONTEXT,
+            XI_RET::XI_UNKNOWN_HARDWARE, XI_RET::XI_INVALID_TM_FILE, XI_RET::XI_INVALID_TM_TAG,
+            XI_RET::XI_INCOMPLETE_TM, XI_RET::XI_BUS_RESET_FAILED, XI_RET::XI_NOT_IMPLEMENTED,
+            XI_RET::XI_SHADING_TOOBRIGHT, XI_RET::XI_SHADING_TOODARK, XI_RET::XI_TOO_LOW_GAIN,
+            XI_RET::XI_INVALID_BPL, XI_RET::XI_BPL_REALLOC, XI_RET::XI_INVALID_PIXEL_LIST,
+            XI_RET::XI_INVALID_FFS, XI_RET::XI_INVALID_PROFILE, XI_RET::XI_INVALID_CALIBRATION,
+            XI_RET::XI_INVALID_BUFFER, XI_RET::XI_INVALID_DATA, XI_RET::XI_TGBUSY,
+            XI_RET::XI_IO_WRONG, XI_RET::XI_ACQUISITION_ALREADY_UP, XI_RET::XI_OLD_DRIVER_VERSION,
+            XI_RET::XI_GET_LAST_ERROR, XI_RET::XI_CANT_PROCESS, XI_RET::XI_ACQUISITION_STOPED,
+            XI_RET::XI_ACQUISITION_STOPED_WERR, XI_RET::XI_INVALID_INPUT_ICC_PROFILE,
+            XI_RET::XI_INVALID_OUTPUT_ICC_PROFILE, XI_RET::XI_DEVICE_NOT_READY,
+            XI_RET::XI_SHADING_TOOCONTRAST, XI_RET::XI_ALREADY_INITIALIZED,
+            XI_RET::XI_NOT_ENOUGH_PRIVILEGES, XI_RET::XI_NOT_COMPATIBLE_DRIVER,
+            XI_RET::XI_TM_INVALID_RESOURCE, XI_RET::XI_DEVICE_HAS_BEEN_RESETED,
+            XI_RET::XI_NO_DEVICES_FOUND, XI_RET::XI_RESOURCE_OR_FUNCTION_LOCKED,
+            XI_RET::XI_BUFFER_SIZE_TOO_SMALL, XI_RET::XI_COULDNT_INIT_PROCESSOR,
+            XI_RET::XI_NOT_INITIALIZED, XI_RET::XI_RESOURCE_NOT_FOUND, XI_RET::XI_UNKNOWN_PARAM,
+            XI_RET::XI_WRONG_PARAM_VALUE, XI_RET::XI_WRONG_PARAM_TYPE, XI_RET::XI_WRONG_PARAM_SIZE,
+            XI_RET::XI_BUFFER_TOO_SMALL, XI_RET::XI_NOT_SUPPORTED_PARAM,
+            XI_RET::XI_NOT_SUPPORTED_PARAM_INFO, XI_RET::XI_NOT_SUPPORTED_DATA_FORMAT,
+            XI_RET::XI_READ_ONLY_PARAM, XI_RET::XI_BANDWIDTH_NOT_SUPPORTED,
+            XI_RET::XI_INVALID_FFS_FILE_NAME, XI_RET::XI_FFS_FILE_NOT_FOUND,
+            XI_RET::XI_PARAM_NOT_SETTABLE, XI_RET::XI_SAFE_POLICY_NOT_SUPPORTED,
+            XI_RET::XI_GPUDIRECT_NOT_AVAILABLE, XI_RET::XI_INCORRECT_SENS_ID_CHECK,
+            XI_RET::XI_INCORRECT_FPGA_TYPE, XI_RET::XI_PARAM_CONDITIONALLY_NOT_AVAILABLE,
+            XI_RET::XI_ERR_FRAME_BUFFER_RAM_INIT, XI_RET::XI_PROC_OTHER_ERROR,
+            XI_RET::XI_PROC_PROCESSING_ERROR, XI_RET::XI_PROC_INPUT_FORMAT_UNSUPPORTED,
+            XI_RET::XI_PROC_OUTPUT_FORMAT_UNSUPPORTED, XI_RET::XI_OUT_OF_RANGE,
+            XI_DOWNSAMPLING_VALUE::XI_DWN_1x1, XI_DOWNSAMPLING_VALUE::XI_DWN_2x2,
+            XI_DOWNSAMPLING_VALUE::XI_DWN_3x3, XI_DOWNSAMPLING_VALUE::XI_DWN_4x4,
+            XI_DOWNSAMPLING_VALUE::XI_DWN_5x5, XI_DOWNSAMPLING_VALUE::XI_DWN_6x6,
+            XI_DOWNSAMPLING_VALUE::XI_DWN_7x7, XI_DOWNSAMPLING_VALUE::XI_DWN_8x8,
+            XI_DOWNSAMPLING_VALUE::XI_DWN_9x9, XI_DOWNSAMPLING_VALUE::XI_DWN_10x10,
+            XI_DOWNSAMPLING_VALUE::XI_DWN_16x16, XI_TEST_PATTERN_GENERATOR::XI_TESTPAT_GEN_SENSOR,
+            XI_TEST_PATTERN_GENERATOR::XI_TESTPAT_GEN_FPGA, XI_VERSION::XI_VER_API,
+            XI_VERSION::XI_VER_DRV, XI_VERSION::XI_VER_MCU1, XI_VERSION::XI_VER_MCU2,
+            XI_VERSION::XI_VER_MCU3, XI_VERSION::XI_VER_FPGA1, XI_VERSION::XI_VER_XMLMAN,
+            XI_VERSION::XI_VER_HW_REV, XI_VERSION::XI_VER_FACTORY_SET,
+            XI_TEST_PATTERN::XI_TESTPAT_OFF, XI_TEST_PATTERN::XI_TESTPAT_BLACK,
+            XI_TEST_PATTERN::XI_TESTPAT_WHITE, XI_TEST_PATTERN::XI_TESTPAT_GREY_HORIZ_RAMP,
+            XI_TEST_PATTERN::XI_TESTPAT_GREY_VERT_RAMP,
+            XI_TEST_PATTERN::XI_TESTPAT_GREY_HORIZ_RAMP_MOVING,
+            XI_TEST_PATTERN::XI_TESTPAT_GREY_VERT_RAMP_MOVING,
+            XI_TEST_PATTERN::XI_TESTPAT_HORIZ_LINE_MOVING,
+            XI_TEST_PATTERN::XI_TESTPAT_VERT_LINE_MOVING, XI_TEST_PATTERN::XI_TESTPAT_COLOR_BAR,
+            XI_TEST_PATTERN::XI_TESTPAT_FRAME_COUNTER,
+            XI_TEST_PATTERN::XI_TESTPAT_DEVICE_SPEC_COUNTER, XI_DEC_PATTERN::XI_DEC_MONO,
+            XI_DEC_PATTERN::XI_DEC_BAYER, XI_BIN_PATTERN::XI_BIN_MONO,
+            XI_BIN_PATTERN::XI_BIN_BAYER, XI_BIN_SELECTOR::XI_BIN_SELECT_SENSOR,
+            XI_BIN_SELECTOR::XI_BIN_SELECT_DEVICE_FPGA, XI_BIN_SELECTOR::XI_BIN_SELECT_HOST_CPU,
+            XI_BIN_MODE::XI_BIN_MODE_SUM, XI_BIN_MODE::XI_BIN_MODE_AVERAGE,
+            XI_DEC_SELECTOR::XI_DEC_SELECT_SENSOR, XI_DEC_SELECTOR::XI_DEC_SELECT_DEVICE_FPGA,
+            XI_DEC_SELECTOR::XI_DEC_SELECT_HOST_CPU, XI_SENSOR_TAP_CNT::XI_TAP_CNT_1,
+            XI_SENSOR_TAP_CNT::XI_TAP_CNT_2, XI_SENSOR_TAP_CNT::XI_TAP_CNT_4,
+            XI_BIT_DEPTH::XI_BPP_8, XI_BIT_DEPTH::XI_BPP_9, XI_BIT_DEPTH::XI_BPP_10,
+            XI_BIT_DEPTH::XI_BPP_11, XI_BIT_DEPTH::XI_BPP_12, XI_BIT_DEPTH::XI_BPP_14,
+            XI_BIT_DEPTH::XI_BPP_16, XI_BIT_DEPTH::XI_BPP_24, XI_BIT_DEPTH::XI_BPP_32,
+            XI_DEBUG_LEVEL::XI_DL_DETAIL, XI_DEBUG_LEVEL::XI_DL_TRACE,
+            XI_DEBUG_LEVEL::XI_DL_WARNING, XI_DEBUG_LEVEL::XI_DL_ERROR,
+            XI_DEBUG_LEVEL::XI_DL_FATAL, XI_DEBUG_LEVEL::XI_DL_DISABLED, XI_IMG_FORMAT::XI_MONO8,
+            XI_IMG_FORMAT::XI_MONO16, XI_IMG_FORMAT::XI_RGB24, XI_IMG_FORMAT::XI_RGB32,
+            XI_IMG_FORMAT::XI_RGB_PLANAR, XI_IMG_FORMAT::XI_RAW8, XI_IMG_FORMAT::XI_RAW16,
+            XI_IMG_FORMAT::XI_FRM_TRANSPORT_DATA, XI_IMG_FORMAT::XI_RGB48, XI_IMG_FORMAT::XI_RGB64,
+            XI_IMG_FORMAT::XI_RGB16_PLANAR, XI_IMG_FORMAT::XI_RAW8X2, XI_IMG_FORMAT::XI_RAW8X4,
+            XI_IMG_FORMAT::XI_RAW16X2, XI_IMG_FORMAT::XI_RAW16X4, XI_IMG_FORMAT::XI_RAW32,
+            XI_IMG_FORMAT::XI_RAW32FLOAT, XI_COLOR_FILTER_ARRAY::XI_CFA_NONE,
+            XI_COLOR_FILTER_ARRAY::XI_CFA_BAYER_RGGB, XI_COLOR_FILTER_ARRAY::XI_CFA_CMYG,
+            XI_COLOR_FILTER_ARRAY::XI_CFA_RGR, XI_COLOR_FILTER_ARRAY::XI_CFA_BAYER_BGGR,
+            XI_COLOR_FILTER_ARRAY::XI_CFA_BAYER_GRBG, XI_COLOR_FILTER_ARRAY::XI_CFA_BAYER_GBRG,
+            XI_COLOR_FILTER_ARRAY::XI_CFA_POLAR_A_BAYER_BGGR,
+            XI_COLOR_FILTER_ARRAY::XI_CFA_POLAR_A, XI_BP::XI_BP_UNSAFE, XI_BP::XI_BP_SAFE,
+            XI_TRG_SOURCE::XI_TRG_OFF, XI_TRG_SOURCE::XI_TRG_EDGE_RISING,
+            XI_TRG_SOURCE::XI_TRG_EDGE_FALLING, XI_TRG_SOURCE::XI_TRG_SOFTWARE,
+            XI_TRG_SOURCE::XI_TRG_LEVEL_HIGH, XI_TRG_SOURCE::XI_TRG_LEVEL_LOW,
+            XI_TRG_SELECTOR::XI_TRG_SEL_FRAME_START, XI_TRG_SELECTOR::XI_TRG_SEL_EXPOSURE_ACTIVE,
+            XI_TRG_SELECTOR::XI_TRG_SEL_FRAME_BURST_START,
+            XI_TRG_SELECTOR::XI_TRG_SEL_FRAME_BURST_ACTIVE,
+            XI_TRG_SELECTOR::XI_TRG_SEL_MULTIPLE_EXPOSURES,
+            XI_TRG_SELECTOR::XI_TRG_SEL_EXPOSURE_START,
+            XI_TRG_SELECTOR::XI_TRG_SEL_MULTI_SLOPE_PHASE_CHANGE,
+            XI_TRG_SELECTOR::XI_TRG_SEL_ACQUISITION_START, XI_TRG_OVERLAP::XI_TRG_OVERLAP_OFF,
+            XI_TRG_OVERLAP::XI_TRG_OVERLAP_READ_OUT, XI_TRG_OVERLAP::XI_TRG_OVERLAP_PREV_FRAME,
+            XI_ACQ_TIMING_MODE::XI_ACQ_TIMING_MODE_FREE_RUN,
+            XI_ACQ_TIMING_MODE::XI_ACQ_TIMING_MODE_FRAME_RATE,
+            XI_ACQ_TIMING_MODE::XI_ACQ_TIMING_MODE_FRAME_RATE_LIMIT,
+            XI_TRANSPORT_DATA_TARGET_MODE::XI_TRANSPORT_DATA_TARGET_CPU_RAM,
+            XI_TRANSPORT_DATA_TARGET_MODE::XI_TRANSPORT_DATA_TARGET_GPU_RAM,
+            XI_TRANSPORT_DATA_TARGET_MODE::XI_TRANSPORT_DATA_TARGET_UNIFIED,
+            XI_TRANSPORT_DATA_TARGET_MODE::XI_TRANSPORT_DATA_TARGET_ZEROCOPY,
+            XI_GPI_SEL_CB::XI_GPI_SEL_CB_IN1, XI_GPI_SEL_CB::XI_GPI_SEL_CB_IN2,
+            XI_GPI_SEL_CB::XI_GPI_SEL_CB_INOUT1, XI_GPI_SEL_CB::XI_GPI_SEL_CB_INOUT2,
+            XI_GPI_SEL_CB::XI_GPI_SEL_CB_INOUT3, XI_GPI_SEL_CB::XI_GPI_SEL_CB_INOUT4,
+            XI_GPO_SEL_CB::XI_GPO_SEL_CB_OUT1, XI_GPO_SEL_CB::XI_GPO_SEL_CB_OUT2,
+            XI_GPO_SEL_CB::XI_GPO_SEL_CB_INOUT1, XI_GPO_SEL_CB::XI_GPO_SEL_CB_INOUT2,
+            XI_GPO_SEL_CB::XI_GPO_SEL_CB_INOUT3, XI_GPO_SEL_CB::XI_GPO_SEL_CB_INOUT4,
+            XI_GPI_MODE::XI_GPI_OFF, XI_GPI_MODE::XI_GPI_TRIGGER, XI_GPI_MODE::XI_GPI_EXT_EVENT,
+            XI_GPI_SELECTOR::XI_GPI_PORT1, XI_GPI_SELECTOR::XI_GPI_PORT2,
+            XI_GPI_SELECTOR::XI_GPI_PORT3, XI_GPI_SELECTOR::XI_GPI_PORT4,
+            XI_GPI_SELECTOR::XI_GPI_PORT5, XI_GPI_SELECTOR::XI_GPI_PORT6,
+            XI_GPI_SELECTOR::XI_GPI_PORT7, XI_GPI_SELECTOR::XI_GPI_PORT8,
+            XI_GPI_SELECTOR::XI_GPI_PORT9, XI_GPI_SELECTOR::XI_GPI_PORT10,
+            XI_GPI_SELECTOR::XI_GPI_PORT11, XI_GPI_SELECTOR::XI_GPI_PORT12,
+            XI_GPO_MODE::XI_GPO_OFF, XI_GPO_MODE::XI_GPO_ON, XI_GPO_MODE::XI_GPO_FRAME_ACTIVE,
+            XI_GPO_MODE::XI_GPO_FRAME_ACTIVE_NEG, XI_GPO_MODE::XI_GPO_EXPOSURE_ACTIVE,
+            XI_GPO_MODE::XI_GPO_EXPOSURE_ACTIVE_NEG, XI_GPO_MODE::XI_GPO_FRAME_TRIGGER_WAIT,
+            XI_GPO_MODE::XI_GPO_FRAME_TRIGGER_WAIT_NEG, XI_GPO_MODE::XI_GPO_EXPOSURE_PULSE,
+            XI_GPO_MODE::XI_GPO_EXPOSURE_PULSE_NEG, XI_GPO_MODE::XI_GPO_BUSY,
+            XI_GPO_MODE::XI_GPO_BUSY_NEG, XI_GPO_MODE::XI_GPO_HIGH_IMPEDANCE,
+            XI_GPO_MODE::XI_GPO_FRAME_BUFFER_OVERFLOW,
+            XI_GPO_MODE::XI_GPO_EXPOSURE_ACTIVE_FIRST_ROW,
+            XI_GPO_MODE::XI_GPO_EXPOSURE_ACTIVE_FIRST_ROW_NEG,
+            XI_GPO_MODE::XI_GPO_EXPOSURE_ACTIVE_ALL_ROWS,
+            XI_GPO_MODE::XI_GPO_EXPOSURE_ACTIVE_ALL_ROWS_NEG, XI_GPO_MODE::XI_GPO_TXD,
+            XI_GPO_SELECTOR::XI_GPO_PORT1, XI_GPO_SELECTOR::XI_GPO_PORT2,
+            XI_GPO_SELECTOR::XI_GPO_PORT3, XI_GPO_SELECTOR::XI_GPO_PORT4,
+            XI_GPO_SELECTOR::XI_GPO_PORT5, XI_GPO_SELECTOR::XI_GPO_PORT6,
+            XI_GPO_SELECTOR::XI_GPO_PORT7, XI_GPO_SELECTOR::XI_GPO_PORT8,
+            XI_GPO_SELECTOR::XI_GPO_PORT9, XI_GPO_SELECTOR::XI_GPO_PORT10,
+            XI_GPO_SELECTOR::XI_GPO_PORT11, XI_GPO_SELECTOR::XI_GPO_PORT12,
+            XI_LED_MODE::XI_LED_HEARTBEAT, XI_LED_MODE::XI_LED_TRIGGER_ACTIVE,
+            XI_LED_MODE::XI_LED_EXT_EVENT_ACTIVE, XI_LED_MODE::XI_LED_LINK,
+            XI_LED_MODE::XI_LED_ACQUISITION, XI_LED_MODE::XI_LED_EXPOSURE_ACTIVE,
+            XI_LED_MODE::XI_LED_FRAME_ACTIVE, XI_LED_MODE::XI_LED_OFF, XI_LED_MODE::XI_LED_ON,
+            XI_LED_MODE::XI_LED_BLINK, XI_LED_SELECTOR::XI_LED_SEL1, XI_LED_SELECTOR::XI_LED_SEL2,
+            XI_LED_SELECTOR::XI_LED_SEL3, XI_LED_SELECTOR::XI_LED_SEL4,
+            XI_LED_SELECTOR::XI_LED_SEL5, XI_COUNTER_SELECTOR::XI_CNT_SEL_TRANSPORT_SKIPPED_FRAMES,
+            XI_COUNTER_SELECTOR::XI_CNT_SEL_API_SKIPPED_FRAMES,
+            XI_COUNTER_SELECTOR::XI_CNT_SEL_TRANSPORT_TRANSFERRED_FRAMES,
+            XI_COUNTER_SELECTOR::XI_CNT_SEL_FRAME_MISSED_TRIGGER_DUETO_OVERLAP,
+            XI_COUNTER_SELECTOR::XI_CNT_SEL_FRAME_MISSED_TRIGGER_DUETO_FRAME_BUFFER_OVR,
+            XI_COUNTER_SELECTOR::XI_CNT_SEL_FRAME_BUFFER_OVERFLOW,
+            XI_TS_RST_MODE::XI_TS_RST_ARM_ONCE, XI_TS_RST_MODE::XI_TS_RST_ARM_PERSIST,
+            XI_TS_RST_SOURCE::XI_TS_RST_OFF, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_1,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_2, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_3,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_4, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_1_INV,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_2_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_3_INV,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_4_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPO_1,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPO_2, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPO_3,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPO_4, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPO_1_INV,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPO_2_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPO_3_INV,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPO_4_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_TRIGGER,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_TRIGGER_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_SW,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_EXPACTIVE,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_EXPACTIVE_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_FVAL,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_FVAL_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_5,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_6, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_5_INV,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_6_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_7,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_8, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_9,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_10, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_11,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_7_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_8_INV,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_9_INV, XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_10_INV,
+            XI_TS_RST_SOURCE::XI_TS_RST_SRC_GPI_11_INV, XI_PRM_TYPE::xiTypeInteger,
+            XI_PRM_TYPE::xiTypeFloat, XI_PRM_TYPE::xiTypeString, XI_PRM_TYPE::xiTypeEnum,
+            XI_PRM_TYPE::xiTypeBoolean, XI_PRM_TYPE::xiTypeCommand, XI_PRM_TYPE::xiTypeInteger64,
+            XI_SWITCH::XI_OFF, XI_SWITCH::XI_ON, XI_TEMP_SELECTOR::XI_TEMP_IMAGE_SENSOR_DIE_RAW,
+            XI_TEMP_SELECTOR::XI_TEMP_IMAGE_SENSOR_DIE, XI_TEMP_SELECTOR::XI_TEMP_SENSOR_BOARD,
+            XI_TEMP_SELECTOR::XI_TEMP_INTERFACE_BOARD, XI_TEMP_SELECTOR::XI_TEMP_FRONT_HOUSING,
+            XI_TEMP_SELECTOR::XI_TEMP_REAR_HOUSING, XI_TEMP_SELECTOR::XI_TEMP_TEC1_COLD,
+            XI_TEMP_SELECTOR::XI_TEMP_TEC1_HOT, XI_TEMP_CTRL_MODE_SELECTOR::XI_TEMP_CTRL_MODE_OFF,
+            XI_TEMP_CTRL_MODE_SELECTOR::XI_TEMP_CTRL_MODE_AUTO,
+            XI_TEMP_CTRL_MODE_SELECTOR::XI_TEMP_CTRL_MODE_MANUAL,
+            XI_TEMP_ELEMENT_SELECTOR::XI_TEMP_ELEM_TEC1,
+            XI_TEMP_ELEMENT_SELECTOR::XI_TEMP_ELEM_TEC2,
+            XI_TEMP_ELEMENT_SELECTOR::XI_TEMP_ELEM_FAN1,
+            XI_TEMP_ELEMENT_SELECTOR::XI_TEMP_ELEM_FAN1_THRS_TEMP,
+            XI_OUTPUT_DATA_PACKING_TYPE::XI_DATA_PACK_XI_GROUPING,
+            XI_OUTPUT_DATA_PACKING_TYPE::XI_DATA_PACK_PFNC_LSB_PACKING,
+            XI_DOWNSAMPLING_TYPE::XI_BINNING, XI_DOWNSAMPLING_TYPE::XI_SKIPPING,
+            XI_EXPOSURE_TIME_SELECTOR_TYPE::XI_EXPOSURE_TIME_SELECTOR_COMMON,
+            XI_EXPOSURE_TIME_SELECTOR_TYPE::XI_EXPOSURE_TIME_SELECTOR_GROUP1,
+            XI_EXPOSURE_TIME_SELECTOR_TYPE::XI_EXPOSURE_TIME_SELECTOR_GROUP2,
+            XI_INTERLINE_EXPOSURE_MODE_TYPE::XI_INTERLINE_EXPOSURE_MODE_OFF,
+            XI_INTERLINE_EXPOSURE_MODE_TYPE::XI_INTERLINE_EXPOSURE_MODE_ON,
+            XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ALL,
+            XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ANALOG_ALL,
+            XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_DIGITAL_ALL,
+            XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ANALOG_TAP1,
+            XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ANALOG_TAP2,
+            XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ANALOG_TAP3,
+            XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ANALOG_TAP4,
+            XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ANALOG_N,
+            XI_GAIN_SELECTOR_TYPE::XI_GAIN_SELECTOR_ANALOG_S, XI_SHUTTER_TYPE::XI_SHUTTER_GLOBAL,
+            XI_SHUTTER_TYPE::XI_SHUTTER_ROLLING, XI_SHUTTER_TYPE::XI_SHUTTER_GLOBAL_RESET_RELEASE,
+            XI_CMS_MODE::XI_CMS_DIS, XI_CMS_MODE::XI_CMS_EN, XI_CMS_MODE::XI_CMS_EN_FAST,
+            XI_CMS_INTENT::XI_CMS_INTENT_PERCEPTUAL,
+            XI_CMS_INTENT::XI_CMS_INTENT_RELATIVE_COLORIMETRIC,
+            XI_CMS_INTENT::XI_CMS_INTENT_SATURATION,
+            XI_CMS_INTENT::XI_CMS_INTENT_ABSOLUTE_COLORIMETRIC, XI_OPEN_BY::XI_OPEN_BY_INST_PATH,
+            XI_OPEN_BY::XI_OPEN_BY_SN, XI_OPEN_BY::XI_OPEN_BY_USER_ID,
+            XI_OPEN_BY::XI_OPEN_BY_LOC_PATH,
+            XI_LENS_FEATURE::XI_LENS_FEATURE_MOTORIZED_FOCUS_SWITCH,
+            XI_LENS_FEATURE::XI_LENS_FEATURE_MOTORIZED_FOCUS_BOUNDED,
+            XI_LENS_FEATURE::XI_LENS_FEATURE_MOTORIZED_FOCUS_CALIBRATION,
+            XI_LENS_FEATURE::XI_LENS_FEATURE_IMAGE_STABILIZATION_ENABLED,
+            XI_LENS_FEATURE::XI_LENS_FEATURE_IMAGE_STABILIZATION_SWITCH_STATUS,
+            XI_LENS_FEATURE::XI_LENS_FEATURE_IMAGE_ZOOM_SUPPORTED,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_ZEROROT_ENABLE,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_BLACK_LEVEL_CLAMP,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_MD_FPGA_DIGITAL_GAIN_DISABLE,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_ACQUISITION_RUNNING,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_TIMING_MODE,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_PARALLEL_ADC,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_BLACK_LEVEL_OFFSET_RAW,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_SHORT_INTERVAL_SHUTTER,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_AUTO_LOW_POWER_MODE_AUTO,
+            XI_SENSOR_FEATURE_SELECTOR::XI_SENSOR_FEATURE_HIGH_CONVERSION_GAIN,
+            XI_SENSOR_MODE::XI_SENS_MD0, XI_SENSOR_MODE::XI_SENS_MD1, XI_SENSOR_MODE::XI_SENS_MD2,
+            XI_SENSOR_MODE::XI_SENS_MD3, XI_SENSOR_MODE::XI_SENS_MD4, XI_SENSOR_MODE::XI_SENS_MD5,
+            XI_SENSOR_MODE::XI_SENS_MD6, XI_SENSOR_MODE::XI_SENS_MD7, XI_SENSOR_MODE::XI_SENS_MD8,
+            XI_SENSOR_MODE::XI_SENS_MD9, XI_SENSOR_MODE::XI_SENS_MD10,
+            XI_SENSOR_MODE::XI_SENS_MD11, XI_SENSOR_MODE::XI_SENS_MD12,
+            XI_SENSOR_MODE::XI_SENS_MD13, XI_SENSOR_MODE::XI_SENS_MD14,
+            XI_SENSOR_MODE::XI_SENS_MD15, XI_IMAGE_AREA_SELECTOR::XI_IMAGE_AREA_ACTIVE,
+            XI_IMAGE_AREA_SELECTOR::XI_IMAGE_AREA_ACTIVE_AND_MASKED,
+            XI_SENSOR_OUTPUT_CHANNEL_COUNT::XI_CHANN_CNT2,
+            XI_SENSOR_OUTPUT_CHANNEL_COUNT::XI_CHANN_CNT4,
+            XI_SENSOR_OUTPUT_CHANNEL_COUNT::XI_CHANN_CNT8,
+            XI_SENSOR_OUTPUT_CHANNEL_COUNT::XI_CHANN_CNT16,
+            XI_SENSOR_OUTPUT_CHANNEL_COUNT::XI_CHANN_CNT24,
+            XI_SENSOR_OUTPUT_CHANNEL_COUNT::XI_CHANN_CNT32,
+            XI_SENSOR_OUTPUT_CHANNEL_COUNT::XI_CHANN_CNT48,
+            XI_SENS_DEFFECTS_CORR_LIST_SELECTOR::XI_SENS_DEFFECTS_CORR_LIST_SEL_FACTORY,
+            XI_SENS_DEFFECTS_CORR_LIST_SELECTOR::XI_SENS_DEFFECTS_CORR_LIST_SEL_USER0,
+            XI_SENS_DEFFECTS_CORR_LIST_SELECTOR::XI_SENS_DEFFECTS_CORR_LIST_SEL_IN_CAMERA,
+            XI_ACQUISITION_STATUS_SELECTOR::XI_ACQUISITION_STATUS_ACQ_ACTIVE,
+            XI_DP_UNIT_SELECTOR::XI_DP_UNIT_SENSOR, XI_DP_UNIT_SELECTOR::XI_DP_UNIT_FPGA,
+            XI_DP_PROC_SELECTOR::XI_DP_PROC_NONE, XI_DP_PROC_SELECTOR::XI_DP_PROC_CHANNEL_MUXER,
+            XI_DP_PROC_SELECTOR::XI_DP_PROC_PIXEL_SEQUENCER,
+            XI_DP_PROC_SELECTOR::XI_DP_PROC_CHANNEL_1, XI_DP_PROC_SELECTOR::XI_DP_PROC_CHANNEL_2,
+            XI_DP_PROC_SELECTOR::XI_DP_PROC_FRAME_BUFFER, XI_DP_PARAM_SELECTOR::XI_DP_PARAM_NONE,
+            XI_DP_PARAM_SELECTOR::XI_DP_PARAM_CHMUX_CHANNEL_SELECTOR,
+            XI_DP_PARAM_SELECTOR::XI_DP_PARAM_CHMUX_ALPHA,
+            XI_DP_PARAM_SELECTOR::XI_DP_PARAM_CHMUX_BETA,
+            XI_DP_PARAM_SELECTOR::XI_DP_PARAM_PIXSEQ_SELECTOR,
+            XI_DP_PARAM_SELECTOR::XI_DP_PARAM_CHANNEL_TIMING,
+            XI_DP_PARAM_SELECTOR::XI_DP_PARAM_FRAMEBUF_MODE,
+            XI_DP_PARAM_SELECTOR::XI_DP_PARAM_FRAMEBUF_SIZE,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_CHMUX_CHANNEL_1,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_CHMUX_CHANNEL_2,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_CHMUX_CHANNEL_1_2,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_CHMUX_MERGED,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_CHMUX_CMS_S,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_PIXSEQ_ONE_VALUE,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_PIXSEQ_TWO_VALUES,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_CHTIM_HG,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_CHTIM_LG,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_FRAMEBUF_MODE_DISABLED,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_FRAMEBUF_MODE_ENABLED,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_PIXSEQ_FOUR_VALUES,
+            XI_DP_PARAM_VALUE::XI_DP_PARAM_VALUE_CHMUX_CMS_A, XI_USER_SET_SELECTOR::XI_US_12_STD_L,
+            XI_USER_SET_SELECTOR::XI_US_12_STD_H, XI_USER_SET_SELECTOR::XI_US_14_STD_L,
+            XI_USER_SET_SELECTOR::XI_US_NONE, XI_USER_SET_SELECTOR::XI_US_14_STD_H,
+            XI_USER_SET_SELECTOR::XI_US_2_12_CMS_S_L, XI_USER_SET_SELECTOR::XI_US_2_12_CMS_S_H,
+            XI_USER_SET_SELECTOR::XI_US_2_14_CMS_S_L, XI_USER_SET_SELECTOR::XI_US_2_14_CMS_S_H,
+            XI_USER_SET_SELECTOR::XI_US_4_12_CMS_S_L, XI_USER_SET_SELECTOR::XI_US_4_12_CMS_S_H,
+            XI_USER_SET_SELECTOR::XI_US_4_14_CMS_S_L, XI_USER_SET_SELECTOR::XI_US_4_14_CMS_S_H,
+            XI_USER_SET_SELECTOR::XI_US_2_12_HDR_HL, XI_USER_SET_SELECTOR::XI_US_2_12_HDR_L,
+            XI_USER_SET_SELECTOR::XI_US_2_12_HDR_H, XI_USER_SET_SELECTOR::XI_US_4_12_CMS_HDR_HL,
+            XI_USER_SET_SELECTOR::XI_US_2_14_HDR_L, XI_USER_SET_SELECTOR::XI_US_2_14_HDR_H,
+            XI_USER_SET_SELECTOR::XI_US_2_12_CMS_A_L, XI_USER_SET_SELECTOR::XI_US_2_12_CMS_A_H,
+            XI_DUAL_ADC_MODE::XI_DUAL_ADC_MODE_OFF, XI_DUAL_ADC_MODE::XI_DUAL_ADC_MODE_COMBINED,
+            XI_DUAL_ADC_MODE::XI_DUAL_ADC_MODE_NON_COMBINED,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_CURRENT_MAINBOARD_VCC_IN,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_VOLTAGE_MAINBOARD_VCC_IN,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_CURRENT_MAINBOARD_VCC_ADJ2,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_VOLTAGE_MAINBOARD_VCC_ADJ2,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_CURRENT_MAINBOARD_VCC_ADJ1,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_VOLTAGE_MAINBOARD_VCC_ADJ1,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_CURRENT_MAINBOARD_VCC_PLT,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_VOLTAGE_MAINBOARD_VCC_PLT,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_VOLTAGE_SENSORBOARD_VCC_ADJ1,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_VOLTAGE_SENSORBOARD_VCC_ADJ2,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_VOLTAGE_SENSORBOARD_VCC_5V0,
+            XI_PROBE_SELECTOR::XI_PROBE_SELECTOR_VOLTAGE_SENSORBOARD_VCC_3V3,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BGRA8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_RGB8Planar,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono10,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono12,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono14,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG10,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG12,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR10,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB10,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR12,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG10,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG12,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB12,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_RGB8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BGR8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG14,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR14,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG14,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB14,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG10p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB10p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR10p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG10p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono10p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG12p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB12p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR12p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG12p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono12p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG14p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB14p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR14p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG14p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono14p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG10g160,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB10g160,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR10g160,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG10g160,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10g160,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG12g192,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB12g192,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR12g192,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG12g192,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12g192,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG14g224,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB14g224,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR14g224,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG14g224,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono14g224,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono8TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono14TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG8TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG10TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG12TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG14TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG8TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG10TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG12TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG14TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB8TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB10TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB12TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB14TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR8TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR10TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR12TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR14TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono8TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono14TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG8TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG10TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG12TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG14TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG8TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG10TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG12TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG14TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB8TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB10TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB12TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB14TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR8TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR10TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR12TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR14TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono16,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR16,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG16,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB16,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG16,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono16TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono16TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG16TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG16TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB16TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR16TS01,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG16TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG16TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB16TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR16TS03,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono16TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG16TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG16TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB16TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR16TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono16TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono8TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono14TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG8TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG10TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG12TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG14TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG16TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG8TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG10TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG12TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG14TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG16TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB8TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB10TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB12TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB14TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB16TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR8TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR10TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR12TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR14TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR16TS02,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono8TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono14TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG8TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG10TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG12TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG14TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG8TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG10TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG12TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG14TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB8TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB10TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB12TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB14TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR8TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR10TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR12TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR14TS04,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono9p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG9p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB9p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR9p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG9p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono11p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG11p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB11p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR11p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG11p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono9,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG9,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB9,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR9,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG9,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono11,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG11,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB11,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR11,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG11,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12g96l_m9e3,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB12pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB10pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB12MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB10MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10pMS51,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB10pMS51,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10MS51,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB10MS51,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB8MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono8MS51,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono8MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB8MS51,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono_m9e3,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_RGBA8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_RGB16Planar,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BGRA16,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BGR16,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_RGBA16,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_RGB16,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono8MS52,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10pMS52,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono10MS52,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12p_m9e3,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono16LS31,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaBayerBG8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaBayerBG10,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12pLS31,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12LS31,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono16LS32,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12pLS32,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono12LS32,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono32,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG32,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR32,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG32,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB32,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono32f,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG32f,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB32f,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR32f,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG32f,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono_m13e3,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerBG24,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGB24,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerGR24,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_BayerRG24,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_Mono24,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG10MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG10MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR10MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG10pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR10pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG10pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG12MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR12MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG12MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG12pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR12pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG12pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG8MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR8MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG8MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG11MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB11MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR11MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG11MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono11MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG11pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB11pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR11pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG11pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono11pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono8LS32,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono8LS31,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaBayerBG10p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaBayerBG12,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaBayerBG12p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaMono8,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaMono10,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaMono10p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaMono12,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiPaMono12p,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono16MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG16MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB16MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR16MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG16MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG14MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerBG14pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB14MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGB14pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR14MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerGR14pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG14MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiBayerRG14pMS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono14MS41,
+            XI_GenTL_Image_Format_e::XI_GenTL_Image_Format_xiMono14pMS41,
+        }
+    }
+
+    #[test]
+    fn struct_layout() {
+        assert_same_layout! {
+            XI_IMG_DESC {
+                Area0Left, Area1Left, Area2Left, Area3Left, Area4Left, Area5Left, ActiveAreaWidth,
+                Area5Right, Area4Right, Area3Right, Area2Right, Area1Right, Area0Right, Area0Top,
+                Area1Top, Area2Top, Area3Top, Area4Top, Area5Top, ActiveAreaHeight, Area5Bottom,
+                Area4Bottom, Area3Bottom, Area2Bottom, Area1Bottom, Area0Bottom, format, flags,
+            }
+            XI_IMG {
+                size, bp, bp_size, frm, width, height, nframe, tsSec, tsUSec, GPI_level,
+                black_level, padding_x, AbsoluteOffsetX, AbsoluteOffsetY, transport_frm, img_desc,
+                DownsamplingX, DownsamplingY, flags, exposure_time_us, gain_db, acq_nframe,
+                image_user_data, exposure_sub_times_us, data_saturation, wb_red, wb_green, wb_blue,
+                lg_black_level, hg_black_level, lg_range, hg_range, gain_ratio, fDownsamplingX,
+                fDownsamplingY, color_filter_array,
+            }
+        }
+    }
+}
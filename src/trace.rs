@@ -0,0 +1,749 @@
+/*
+ * Copyright (c) 2022. XIMEA GmbH - All Rights Reserved
+ */
+
+use std::collections::{BTreeMap, VecDeque};
+use std::ffi::CStr;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::mem::size_of;
+use std::os::raw::c_void;
+use std::path::Path;
+use std::slice::from_raw_parts;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::sys::XI_RET::*;
+use crate::sys::*;
+
+use crate::Backend;
+
+/// First line of every trace file
+const TRACE_HEADER: &str = "# xiapi trace v1";
+
+/// A single xiAPI call with its arguments and results.
+///
+/// In a trace file, every record is stored as a single line consisting of the name of the call
+/// followed by `key=value` pairs separated by spaces.
+#[derive(Debug, Clone, PartialEq)]
+struct Record {
+    call: String,
+    fields: BTreeMap<String, String>,
+}
+
+impl Record {
+    fn new(call: &str) -> Self {
+        Record {
+            call: call.to_string(),
+            fields: BTreeMap::new(),
+        }
+    }
+
+    fn with(mut self, key: &str, value: impl ToString) -> Self {
+        self.fields.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    fn with_handle(self, handle: HANDLE) -> Self {
+        self.with("handle", handle as usize)
+    }
+
+    fn with_prm(self, prm: &CStr) -> Self {
+        self.with("prm", prm.to_string_lossy())
+    }
+
+    fn get<T: FromStr>(&self, key: &str) -> Result<T, String> {
+        let value = self
+            .fields
+            .get(key)
+            .ok_or_else(|| format!("`{}` is missing in `{}`", key, self))?;
+        value
+            .parse()
+            .map_err(|_| format!("Invalid value for `{}` in `{}`", key, self))
+    }
+
+    fn ret(&self) -> Result<XI_RETURN, String> {
+        self.get("ret")
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut tokens = line.split_whitespace();
+        let mut record = Record::new(tokens.next()?);
+        for token in tokens {
+            let (key, value) = token.split_once('=')?;
+            record = record.with(key, value);
+        }
+        Some(record)
+    }
+
+    /// Check if all fields of `expected` have the same value in this record
+    fn matches(&self, expected: &Record) -> bool {
+        self.call == expected.call
+            && expected
+                .fields
+                .iter()
+                .all(|(key, value)| self.fields.get(key) == Some(value))
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.call)?;
+        for (key, value) in &self.fields {
+            write!(f, " {}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, String> {
+    if hex.len() % 2 != 0 {
+        return Err(format!("Odd number of digits in hex string {}", hex));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|err| err.to_string()))
+        .collect()
+}
+
+/// Generate functions to store and restore the metadata of an image in a record
+macro_rules! image_fields {
+    ($($field:ident),* $(,)?) => {
+        fn with_image_fields(mut record: Record, img: &XI_IMG) -> Record {
+            $(record = record.with(stringify!($field), img.$field);)*
+            record
+        }
+
+        fn restore_image_fields(record: &Record, img: &mut XI_IMG) -> Result<(), String> {
+            $(img.$field = record.get(stringify!($field))?;)*
+            Ok(())
+        }
+    };
+}
+
+image_fields!(
+    bp_size,
+    frm,
+    width,
+    height,
+    nframe,
+    tsSec,
+    tsUSec,
+    GPI_level,
+    black_level,
+    padding_x,
+    AbsoluteOffsetX,
+    AbsoluteOffsetY,
+    transport_frm,
+    DownsamplingX,
+    DownsamplingY,
+    flags,
+    exposure_time_us,
+    gain_db,
+    acq_nframe,
+    image_user_data,
+    color_filter_array,
+);
+
+/// Copy the recorded string `out_value` into `value` as null terminated string
+fn copy_string(record: &Record, value: &mut [u8]) -> Result<(), String> {
+    let data = from_hex(&record.get::<String>("out_value")?)?;
+    let length = data.len().min(value.len().saturating_sub(1));
+    value[..length].copy_from_slice(&data[..length]);
+    if let Some(end) = value.get_mut(length) {
+        *end = 0;
+    }
+    Ok(())
+}
+
+/// Size of the image data of `img` in bytes
+fn payload_size(img: &XI_IMG) -> usize {
+    use crate::sys::XI_IMG_FORMAT::*;
+
+    if img.bp_size != 0 {
+        return img.bp_size as usize;
+    }
+    let bytes_per_pixel = match img.frm {
+        XI_MONO8 | XI_RAW8 => 1,
+        XI_MONO16 | XI_RAW16 | XI_RAW8X2 => 2,
+        XI_RGB24 | XI_RGB_PLANAR => 3,
+        XI_RGB32 | XI_RAW8X4 | XI_RAW16X2 | XI_RAW32 | XI_RAW32FLOAT => 4,
+        XI_RGB48 | XI_RGB16_PLANAR => 6,
+        XI_RGB64 | XI_RAW16X4 => 8,
+        _ => 1,
+    };
+    (img.width as usize * bytes_per_pixel + img.padding_x as usize) * img.height as usize
+}
+
+/// Backend that records all calls to another backend in a trace.
+///
+/// Every call is forwarded to the wrapped backend and written to the trace together with its
+/// arguments and results, including the data of every image.
+/// The trace can be served back later by a [ReplayBackend], which makes it possible to reproduce
+/// a problem without access to the camera on which it occurred.
+///
+/// Errors while writing the trace do not affect the calls to the wrapped backend. The first error
+/// can be retrieved with [RecordingBackend::take_error].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>>{
+/// let recorder = xiapi::RecordingBackend::create("camera.trace", xiapi::default_backend())?;
+/// xiapi::set_default_backend(Arc::new(recorder));
+/// let mut cam = xiapi::open_device(None)?;
+/// cam.set_exposure(10_000.0)?;
+/// // All calls are recorded to camera.trace
+/// # Ok(())
+/// # }
+/// ```
+pub struct RecordingBackend<W: Write + Send = BufWriter<File>> {
+    inner: Arc<dyn Backend>,
+    writer: Mutex<W>,
+    error: Mutex<Option<io::Error>>,
+}
+
+impl RecordingBackend<BufWriter<File>> {
+    /// Record all calls to `inner` into a newly created trace file at `path`.
+    pub fn create(path: impl AsRef<Path>, inner: Arc<dyn Backend>) -> io::Result<Self> {
+        Self::new(inner, BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write + Send> RecordingBackend<W> {
+    /// Record all calls to `inner` into `writer`.
+    pub fn new(inner: Arc<dyn Backend>, mut writer: W) -> io::Result<Self> {
+        writeln!(writer, "{}", TRACE_HEADER)?;
+        writer.flush()?;
+        Ok(RecordingBackend {
+            inner,
+            writer: Mutex::new(writer),
+            error: Mutex::new(None),
+        })
+    }
+
+    /// Take the first error that occurred while writing the trace.
+    pub fn take_error(&self) -> Option<io::Error> {
+        lock(&self.error).take()
+    }
+
+    /// Consume the backend and return the writer containing the trace.
+    pub fn into_writer(self) -> W {
+        self.writer
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self, record: Record) {
+        let mut writer = lock(&self.writer);
+        let result = writeln!(writer, "{}", record).and_then(|_| writer.flush());
+        if let Err(err) = result {
+            lock(&self.error).get_or_insert(err);
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+unsafe impl<W: Write + Send> Backend for RecordingBackend<W> {
+    fn get_number_devices(&self, count: &mut u32) -> XI_RETURN {
+        let ret = self.inner.get_number_devices(count);
+        self.write(
+            Record::new("get_number_devices")
+                .with("ret", ret)
+                .with("count", count),
+        );
+        ret
+    }
+
+    fn get_device_info_string(&self, dev_id: u32, prm: &CStr, value: &mut [u8]) -> XI_RETURN {
+        let ret = self.inner.get_device_info_string(dev_id, prm, value);
+        let length = value.iter().position(|c| *c == 0).unwrap_or(value.len());
+        self.write(
+            Record::new("get_device_info_string")
+                .with("dev_id", dev_id)
+                .with_prm(prm)
+                .with("ret", ret)
+                .with("out_value", to_hex(&value[..length])),
+        );
+        ret
+    }
+
+    fn open_device(&self, dev_id: u32, handle: &mut HANDLE) -> XI_RETURN {
+        let ret = self.inner.open_device(dev_id, handle);
+        self.write(
+            Record::new("open_device")
+                .with("dev_id", dev_id)
+                .with("ret", ret)
+                .with("out_handle", *handle as usize),
+        );
+        ret
+    }
+
+    fn open_device_by(&self, sel: XI_OPEN_BY::Type, prm: &CStr, handle: &mut HANDLE) -> XI_RETURN {
+        let ret = self.inner.open_device_by(sel, prm, handle);
+        self.write(
+            Record::new("open_device_by")
+                .with("sel", sel)
+                .with("value", to_hex(prm.to_bytes()))
+                .with("ret", ret)
+                .with("out_handle", *handle as usize),
+        );
+        ret
+    }
+
+    unsafe fn close_device(&self, handle: HANDLE) -> XI_RETURN {
+        let ret = self.inner.close_device(handle);
+        self.write(
+            Record::new("close_device")
+                .with_handle(handle)
+                .with("ret", ret),
+        );
+        ret
+    }
+
+    unsafe fn start_acquisition(&self, handle: HANDLE) -> XI_RETURN {
+        let ret = self.inner.start_acquisition(handle);
+        self.write(
+            Record::new("start_acquisition")
+                .with_handle(handle)
+                .with("ret", ret),
+        );
+        ret
+    }
+
+    unsafe fn stop_acquisition(&self, handle: HANDLE) -> XI_RETURN {
+        let ret = self.inner.stop_acquisition(handle);
+        self.write(
+            Record::new("stop_acquisition")
+                .with_handle(handle)
+                .with("ret", ret),
+        );
+        ret
+    }
+
+    unsafe fn get_image(&self, handle: HANDLE, timeout: u32, img: &mut XI_IMG) -> XI_RETURN {
+        let ret = self.inner.get_image(handle, timeout, img);
+        let mut record = Record::new("get_image")
+            .with_handle(handle)
+            .with("ret", ret);
+        if ret == XI_OK as XI_RETURN {
+            // The Backend contract guarantees that the image buffer is valid at this point
+            let data = from_raw_parts(img.bp as *const u8, payload_size(img));
+            record = with_image_fields(record, img).with("data", to_hex(data));
+        }
+        self.write(record);
+        ret
+    }
+
+    unsafe fn get_param_int(&self, handle: HANDLE, prm: &CStr, value: &mut i32) -> XI_RETURN {
+        let ret = self.inner.get_param_int(handle, prm, value);
+        self.write(
+            Record::new("get_param_int")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("ret", ret)
+                .with("out_value", value),
+        );
+        ret
+    }
+
+    unsafe fn set_param_int(&self, handle: HANDLE, prm: &CStr, value: i32) -> XI_RETURN {
+        let ret = self.inner.set_param_int(handle, prm, value);
+        self.write(
+            Record::new("set_param_int")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("value", value)
+                .with("ret", ret),
+        );
+        ret
+    }
+
+    unsafe fn get_param_float(&self, handle: HANDLE, prm: &CStr, value: &mut f32) -> XI_RETURN {
+        let ret = self.inner.get_param_float(handle, prm, value);
+        self.write(
+            Record::new("get_param_float")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("ret", ret)
+                .with("out_value", value),
+        );
+        ret
+    }
+
+    unsafe fn set_param_float(&self, handle: HANDLE, prm: &CStr, value: f32) -> XI_RETURN {
+        let ret = self.inner.set_param_float(handle, prm, value);
+        self.write(
+            Record::new("set_param_float")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("value", value)
+                .with("ret", ret),
+        );
+        ret
+    }
+
+    unsafe fn get_param_string(&self, handle: HANDLE, prm: &CStr, value: &mut [u8]) -> XI_RETURN {
+        let ret = self.inner.get_param_string(handle, prm, value);
+        self.write(
+            Record::new("get_param_string")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("size", value.len())
+                .with("ret", ret)
+                .with("out_value", to_hex(value)),
+        );
+        ret
+    }
+
+    unsafe fn set_param_string(&self, handle: HANDLE, prm: &CStr, value: &[u8]) -> XI_RETURN {
+        let ret = self.inner.set_param_string(handle, prm, value);
+        self.write(
+            Record::new("set_param_string")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("value", to_hex(value))
+                .with("ret", ret),
+        );
+        ret
+    }
+
+    unsafe fn get_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &mut [u8],
+        size: &mut u32,
+        prm_type: &mut XI_PRM_TYPE::Type,
+    ) -> XI_RETURN {
+        let requested_type = *prm_type;
+        let ret = self.inner.get_param(handle, prm, value, size, prm_type);
+        let written = value.len().min(*size as usize);
+        self.write(
+            Record::new("get_param")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("type", requested_type)
+                .with("ret", ret)
+                .with("out_value", to_hex(&value[..written]))
+                .with("out_size", size)
+                .with("out_type", prm_type),
+        );
+        ret
+    }
+
+    unsafe fn set_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &[u8],
+        prm_type: XI_PRM_TYPE::Type,
+    ) -> XI_RETURN {
+        let ret = self.inner.set_param(handle, prm, value, prm_type);
+        self.write(
+            Record::new("set_param")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("value", to_hex(value))
+                .with("type", prm_type)
+                .with("ret", ret),
+        );
+        ret
+    }
+}
+
+/// Backend that serves the answers from a trace recorded by a [RecordingBackend].
+///
+/// The calls have to be made in the same order and with the same arguments as during the
+/// recording. Every call is answered with the recorded return code and output values, images
+/// contain the recorded data, which stays valid until the acquisition is stopped or the device is
+/// closed.
+/// If a call does not match the next call in the trace, it fails with `XI_INVALID_ARG` and the
+/// trace is not advanced. The first mismatch can be inspected with [ReplayBackend::mismatch].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>>{
+/// let replay = xiapi::ReplayBackend::open("camera.trace")?;
+/// xiapi::set_default_backend(Arc::new(replay));
+/// let mut cam = xiapi::open_device(None)?;
+/// cam.set_exposure(10_000.0)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReplayBackend {
+    state: Mutex<ReplayState>,
+}
+
+struct ReplayState {
+    records: VecDeque<Record>,
+    /// Buffers of replayed images for every handle, kept until the acquisition is stopped or the
+    /// device is closed because images may still refer to them
+    frames: BTreeMap<usize, Vec<Box<[u64]>>>,
+    mismatch: Option<String>,
+}
+
+impl ReplayBackend {
+    /// Load the trace file at `path`.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Load a trace from `reader`.
+    pub fn from_reader(reader: impl BufRead) -> io::Result<Self> {
+        let mut records = VecDeque::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let record = Record::parse(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Invalid record in line {} of trace", number + 1),
+                )
+            })?;
+            records.push_back(record);
+        }
+        Ok(ReplayBackend {
+            state: Mutex::new(ReplayState {
+                records,
+                frames: BTreeMap::new(),
+                mismatch: None,
+            }),
+        })
+    }
+
+    /// Number of recorded calls that have not been replayed yet.
+    pub fn remaining(&self) -> usize {
+        lock(&self.state).records.len()
+    }
+
+    /// Description of the first call that did not match the trace.
+    pub fn mismatch(&self) -> Option<String> {
+        lock(&self.state).mismatch.clone()
+    }
+
+    /// Take the next record if it matches `expected` and apply its results using `f`
+    fn replay(
+        &self,
+        expected: Record,
+        f: impl FnOnce(&Record, &mut ReplayState) -> Result<(), String>,
+    ) -> XI_RETURN {
+        let mut state = lock(&self.state);
+        let result = match state.records.front() {
+            Some(record) if record.matches(&expected) => {
+                let record = state.records.pop_front().unwrap();
+                record
+                    .ret()
+                    .and_then(|ret| f(&record, &mut state).map(|_| ret))
+            }
+            Some(record) => Err(format!("Expected `{}`, got `{}`", record, expected)),
+            None => Err(format!("Trace has ended, got `{}`", expected)),
+        };
+        match result {
+            Ok(ret) => ret,
+            Err(message) => {
+                state.mismatch.get_or_insert(message);
+                XI_INVALID_ARG as XI_RETURN
+            }
+        }
+    }
+
+    /// Replay a call that has no outputs except the return code
+    fn replay_call(&self, expected: Record) -> XI_RETURN {
+        self.replay(expected, |_, _| Ok(()))
+    }
+
+    /// Replay a call that returns a single value
+    fn replay_value<T: FromStr>(&self, expected: Record, value: &mut T) -> XI_RETURN {
+        self.replay(expected, |record, _| {
+            if record.ret()? == XI_OK as XI_RETURN {
+                *value = record.get("out_value")?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Release the images of `handle`
+    fn release_frames(&self, expected: Record, handle: HANDLE) -> XI_RETURN {
+        self.replay(expected, |_, state| {
+            state.frames.remove(&(handle as usize));
+            Ok(())
+        })
+    }
+}
+
+unsafe impl Backend for ReplayBackend {
+    fn get_number_devices(&self, count: &mut u32) -> XI_RETURN {
+        self.replay(Record::new("get_number_devices"), |record, _| {
+            *count = record.get("count")?;
+            Ok(())
+        })
+    }
+
+    fn get_device_info_string(&self, dev_id: u32, prm: &CStr, value: &mut [u8]) -> XI_RETURN {
+        let expected = Record::new("get_device_info_string")
+            .with("dev_id", dev_id)
+            .with_prm(prm);
+        self.replay(expected, |record, _| copy_string(record, value))
+    }
+
+    fn open_device(&self, dev_id: u32, handle: &mut HANDLE) -> XI_RETURN {
+        let expected = Record::new("open_device").with("dev_id", dev_id);
+        self.replay(expected, |record, _| {
+            *handle = record.get::<usize>("out_handle")? as HANDLE;
+            Ok(())
+        })
+    }
+
+    fn open_device_by(&self, sel: XI_OPEN_BY::Type, prm: &CStr, handle: &mut HANDLE) -> XI_RETURN {
+        let expected = Record::new("open_device_by")
+            .with("sel", sel)
+            .with("value", to_hex(prm.to_bytes()));
+        self.replay(expected, |record, _| {
+            *handle = record.get::<usize>("out_handle")? as HANDLE;
+            Ok(())
+        })
+    }
+
+    unsafe fn close_device(&self, handle: HANDLE) -> XI_RETURN {
+        self.release_frames(Record::new("close_device").with_handle(handle), handle)
+    }
+
+    unsafe fn start_acquisition(&self, handle: HANDLE) -> XI_RETURN {
+        self.replay_call(Record::new("start_acquisition").with_handle(handle))
+    }
+
+    unsafe fn stop_acquisition(&self, handle: HANDLE) -> XI_RETURN {
+        self.release_frames(Record::new("stop_acquisition").with_handle(handle), handle)
+    }
+
+    unsafe fn get_image(&self, handle: HANDLE, _timeout: u32, img: &mut XI_IMG) -> XI_RETURN {
+        let expected = Record::new("get_image").with_handle(handle);
+        self.replay(expected, |record, state| {
+            if record.ret()? != XI_OK as XI_RETURN {
+                return Ok(());
+            }
+            let data = from_hex(&record.get::<String>("data")?)?;
+            restore_image_fields(record, img)?;
+            // Use u64 as storage to guarantee the alignment for all pixel types
+            let mut buffer = vec![0u64; (data.len() + size_of::<u64>() - 1) / size_of::<u64>()];
+            let bytes = std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, data.len());
+            bytes.copy_from_slice(&data);
+            img.bp = buffer.as_mut_ptr() as *mut c_void;
+            state
+                .frames
+                .entry(handle as usize)
+                .or_default()
+                .push(buffer.into_boxed_slice());
+            Ok(())
+        })
+    }
+
+    unsafe fn get_param_int(&self, handle: HANDLE, prm: &CStr, value: &mut i32) -> XI_RETURN {
+        let expected = Record::new("get_param_int")
+            .with_handle(handle)
+            .with_prm(prm);
+        self.replay_value(expected, value)
+    }
+
+    unsafe fn set_param_int(&self, handle: HANDLE, prm: &CStr, value: i32) -> XI_RETURN {
+        self.replay_call(
+            Record::new("set_param_int")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("value", value),
+        )
+    }
+
+    unsafe fn get_param_float(&self, handle: HANDLE, prm: &CStr, value: &mut f32) -> XI_RETURN {
+        let expected = Record::new("get_param_float")
+            .with_handle(handle)
+            .with_prm(prm);
+        self.replay_value(expected, value)
+    }
+
+    unsafe fn set_param_float(&self, handle: HANDLE, prm: &CStr, value: f32) -> XI_RETURN {
+        self.replay_call(
+            Record::new("set_param_float")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("value", value),
+        )
+    }
+
+    unsafe fn get_param_string(&self, handle: HANDLE, prm: &CStr, value: &mut [u8]) -> XI_RETURN {
+        let expected = Record::new("get_param_string")
+            .with_handle(handle)
+            .with_prm(prm)
+            .with("size", value.len());
+        self.replay(expected, |record, _| {
+            let data = from_hex(&record.get::<String>("out_value")?)?;
+            let written = data.len().min(value.len());
+            value[..written].copy_from_slice(&data[..written]);
+            Ok(())
+        })
+    }
+
+    unsafe fn set_param_string(&self, handle: HANDLE, prm: &CStr, value: &[u8]) -> XI_RETURN {
+        self.replay_call(
+            Record::new("set_param_string")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("value", to_hex(value)),
+        )
+    }
+
+    unsafe fn get_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &mut [u8],
+        size: &mut u32,
+        prm_type: &mut XI_PRM_TYPE::Type,
+    ) -> XI_RETURN {
+        let expected = Record::new("get_param")
+            .with_handle(handle)
+            .with_prm(prm)
+            .with("type", *prm_type);
+        self.replay(expected, |record, _| {
+            let data = from_hex(&record.get::<String>("out_value")?)?;
+            let written = data.len().min(value.len());
+            value[..written].copy_from_slice(&data[..written]);
+            *size = record.get("out_size")?;
+            *prm_type = record.get("out_type")?;
+            Ok(())
+        })
+    }
+
+    unsafe fn set_param(
+        &self,
+        handle: HANDLE,
+        prm: &CStr,
+        value: &[u8],
+        prm_type: XI_PRM_TYPE::Type,
+    ) -> XI_RETURN {
+        self.replay_call(
+            Record::new("set_param")
+                .with_handle(handle)
+                .with_prm(prm)
+                .with("value", to_hex(value))
+                .with("type", prm_type),
+        )
+    }
+}